@@ -23,6 +23,12 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("Parse context", |p| {
         p.iter(|| Vrt::try_from(black_box(&context_vec[..])).unwrap())
     });
+    c.bench_function("Peek signal data prologue", |p| {
+        p.iter(|| PacketHeader::peek_prologue(black_box(&data_vec[..])).unwrap())
+    });
+    c.bench_function("Peek context prologue", |p| {
+        p.iter(|| PacketHeader::peek_prologue(black_box(&context_vec[..])).unwrap())
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
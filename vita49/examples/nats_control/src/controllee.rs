@@ -15,6 +15,7 @@ use futures::StreamExt;
 use log::{debug, error, info};
 use vita49::command_prelude::*;
 use vita49::prelude::*;
+use vita49::{CifField, Controllee, ControlleeHandler, FieldValue};
 
 /// Dummy SDR simulation structure for holding "device" state.
 #[derive(Copy, Clone, Debug, Default)]
@@ -33,118 +34,69 @@ impl Sdr {
             Ok(())
         }
     }
+}
 
-    /// Perform validation of the bandwidth parameter and return and
-    /// `Err(AckResponse)` if there are any problems.
-    fn set_bandwidth(&mut self, bw_hz: f64) -> Result<(), AckResponse> {
+impl ControlleeHandler for Sdr {
+    /// Perform validation of the bandwidth and frequency parameters and
+    /// return an `Err(AckResponse)` if there are any problems.
+    fn validate(&self, field: CifField, value: &FieldValue) -> Result<(), AckResponse> {
         let mut ack_response = AckResponse::default();
-        if !(0.0..=100e6).contains(&bw_hz) {
-            ack_response.set_param_out_of_range();
-        }
-        if let Err(_e) = self.send_to_device(bw_hz) {
-            ack_response.set_device_failure()
-        } else {
-            self.bandwidth_hz = bw_hz;
+        match (field, value) {
+            (CifField::Bandwidth, FieldValue::F64(bw_hz)) => {
+                if !(0.0..=100e6).contains(bw_hz) {
+                    ack_response.set_param_out_of_range();
+                }
+            }
+            (CifField::RfRefFreq, FieldValue::F64(freq_hz)) => {
+                if !(0.0..=6e9).contains(freq_hz) {
+                    ack_response.set_param_out_of_range();
+                }
+            }
+            // We don't support any other fields.
+            _ => ack_response.set_field_value_invalid(),
         }
         if ack_response.empty() {
             Ok(())
         } else {
+            error!("Got error in validating {field}:\n{ack_response}");
             Err(ack_response)
         }
     }
 
-    /// Perform validation of the frequency parameter and return and
-    /// `Err(AckResponse)` if there are any problems.
-    fn set_freq(&mut self, freq_hz: f64) -> Result<(), AckResponse> {
+    /// Send a parameter to the "device". If the controller permits errors,
+    /// fields that failed validation get here too, so anything unsupported
+    /// is rejected again.
+    fn execute(&mut self, field: CifField, value: &FieldValue) -> Result<(), AckResponse> {
         let mut ack_response = AckResponse::default();
-        if !(0.0..=6e9).contains(&freq_hz) {
-            ack_response.set_param_out_of_range();
-        }
-        if let Err(_e) = self.send_to_device(freq_hz) {
-            ack_response.set_device_failure()
-        } else {
-            self.tune_freq_hz = freq_hz;
-        }
-        if ack_response.empty() {
-            Ok(())
-        } else {
-            Err(ack_response)
-        }
-    }
-
-    /// Process an individual command packet and return an ACK VRT
-    /// packet if one is requested.
-    fn process_command(&mut self, packet: &Vrt) -> Option<Vrt> {
-        let command = packet.payload().command().unwrap();
-        let control = command.payload().control().unwrap();
-        info!("Processing control packet...");
-        let mut bw_err = None;
-        let mut freq_err = None;
-        if let Some(bw_hz) = control.bandwidth_hz() {
-            info!("Setting radio BW to {bw_hz}");
-            if let Err(e) = self.set_bandwidth(bw_hz) {
-                error!("Got error in setting bandwidth:\n{e}");
-                bw_err = Some(e);
-            }
-        }
-        if let Some(freq_hz) = control.rf_ref_freq_hz() {
-            info!("Setting radio FC to {freq_hz}");
-            if let Err(e) = self.set_freq(freq_hz) {
-                error!("Got error in setting frequency\n{e}");
-                freq_err = Some(e);
+        let val = match (field, value) {
+            (CifField::Bandwidth | CifField::RfRefFreq, FieldValue::F64(val)) => *val,
+            _ => {
+                ack_response.set_field_value_invalid();
+                error!("Can't set {field}:\n{ack_response}");
+                return Err(ack_response);
             }
-        }
-
-        // Create an ACK packet based on what the control packet
-        // requested (or just return here if no ACK is requested).
-        let mut reply = if command.cam().execution() {
-            Vrt::new_exec_ack_packet()
-        } else if command.cam().validation() {
-            Vrt::new_validation_ack_packet()
-        } else if command.cam().state() {
-            Vrt::new_query_ack_packet()
-        } else {
-            return None;
         };
-
-        // Mirror some of the header values from the command packet to make
-        // sure the controller knows which command we're replying to.
-        reply.set_stream_id(packet.stream_id());
-
-        let ack = reply.payload_mut().command_mut().unwrap();
-        let mut cam = command.cam();
-        cam.unset_error();
-        cam.unset_warning();
-        if bw_err.is_some() || freq_err.is_some() {
-            cam.set_error();
+        info!("Setting radio {field} to {val}");
+        if let Err(_e) = self.send_to_device(val) {
+            ack_response.set_device_failure();
+            error!("Got error in setting {field}:\n{ack_response}");
+            return Err(ack_response);
+        }
+        if field == CifField::Bandwidth {
+            self.bandwidth_hz = val;
         } else {
-            cam.set_action_scheduled_or_executed();
+            self.tune_freq_hz = val;
         }
-        ack.set_cam(cam);
-        ack.set_message_id(command.message_id());
-        ack.set_controllee_id(command.controllee_id()).unwrap();
-        ack.set_controllee_uuid(command.controllee_uuid()).unwrap();
-        ack.set_controller_id(command.controller_id()).unwrap();
-        ack.set_controller_uuid(command.controller_uuid()).unwrap();
+        Ok(())
+    }
 
-        match ack.payload_mut() {
-            // If we're sending a validation or exec ACK, fill in the fields with
-            // any errors. If `bw_err` and `freq_err` are `None` here, the function
-            // calls are effectively no-ops.
-            CommandPayload::ValidationAck(a) | CommandPayload::ExecAck(a) => {
-                a.set_bandwidth(AckLevel::Error, bw_err);
-                a.set_rf_ref_freq(AckLevel::Error, freq_err);
-            }
-            // If we're sending a query ACK, just get the current values from our
-            // "SDR" and send them back to the controller.
-            CommandPayload::QueryAck(a) => {
-                a.set_bandwidth_hz(Some(self.bandwidth_hz));
-                a.set_rf_ref_freq_hz(Some(self.tune_freq_hz));
-            }
-            _ => unimplemented!(),
+    /// Get the current values from our "SDR" to send back to the controller.
+    fn query(&self, field: CifField) -> Option<FieldValue> {
+        match field {
+            CifField::Bandwidth => Some(self.bandwidth_hz.into()),
+            CifField::RfRefFreq => Some(self.tune_freq_hz.into()),
+            _ => None,
         }
-        reply.update_packet_size();
-        Some(reply)
     }
 }
 
@@ -157,7 +109,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let nats_client = async_nats::connect(&nats_url).await?;
     let mut sub = nats_client.subscribe(nats_subject).await.unwrap();
 
-    let mut sdr = Sdr::default();
+    // The controllee takes care of building validation, execution, and
+    // query ACKs as requested by each command packet.
+    let mut controllee = Controllee::new(Sdr::default());
 
     info!("Awaiting commands");
     while let Some(message) = sub.next().await {
@@ -170,13 +124,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             return Err(Error::new(ErrorKind::InvalidInput, "invalid VRT packet").into());
         }
 
-        // Process the command packet and get a constructed ACK back (if requested).
-        if let (Some(re_subj), Some(ack)) = (message.reply, sdr.process_command(&packet)) {
-            // If NATS provided a reply subject and the VRT packet requested an
-            // ACK, send the ACK back through NATS.
-            info!("Publishing ACK");
-            debug!("{}", ack.payload().command().unwrap());
-            nats_client.publish(re_subj, ack.to_bytes()?.into()).await?;
+        // Process the command packet and get the constructed ACKs back (if requested).
+        info!("Processing control packet...");
+        let acks = controllee.process(&packet)?;
+        if let Some(re_subj) = message.reply {
+            // If NATS provided a reply subject, send the ACKs back through NATS.
+            for ack in acks {
+                info!("Publishing ACK");
+                debug!("{}", ack.payload().command().unwrap());
+                nats_client
+                    .publish(re_subj.clone(), ack.to_bytes()?.into())
+                    .await?;
+            }
         }
     }
     Ok(())
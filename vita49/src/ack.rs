@@ -3,8 +3,9 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    cif7::Cif7Opts, prelude::*, Cif0AckFields, Cif0AckManipulators, Cif1AckFields, Cif2AckFields,
-    Cif3AckFields, ControlAckMode,
+    cif7::Cif7Opts, prelude::*, Cif0AckFields, Cif0AckManipulators, Cif1AckFields,
+    Cif1AckManipulators, Cif2AckFields, Cif2AckManipulators, Cif3AckFields, Cif3AckManipulators,
+    ControlAckMode,
 };
 use deku::prelude::*;
 use std::fmt;
@@ -132,36 +133,122 @@ impl Ack {
         }
         ret
     }
-}
 
-impl Cif0AckManipulators for Ack {
-    fn wif0(&self) -> Option<&Cif0> {
-        self.wif0.as_ref()
-    }
-    fn wif0_mut(&mut self) -> &mut Option<Cif0> {
-        &mut self.wif0
-    }
-    fn wif0_fields(&self) -> Option<&Cif0AckFields> {
-        self.wif0_fields.as_ref()
-    }
-    fn wif0_fields_mut(&mut self) -> &mut Option<Cif0AckFields> {
-        &mut self.wif0_fields
+    /// Returns true if the ACK reports any warnings.
+    pub fn has_warnings(&self) -> bool {
+        self.wif0.is_some()
     }
 
-    fn eif0(&self) -> Option<&Cif0> {
-        self.eif0.as_ref()
-    }
-    fn eif0_mut(&mut self) -> &mut Option<Cif0> {
-        &mut self.eif0
-    }
-    fn eif0_fields(&self) -> Option<&Cif0AckFields> {
-        self.eif0_fields.as_ref()
-    }
-    fn eif0_fields_mut(&mut self) -> &mut Option<Cif0AckFields> {
-        &mut self.eif0_fields
+    /// Returns true if the ACK reports any errors.
+    pub fn has_errors(&self) -> bool {
+        self.eif0.is_some()
     }
 }
 
+/// Implements the indicator and data field accessors for one WIF or EIF.
+macro_rules! ack_accessors {
+    ($cif:ident, $cif_mut:ident, $fields:ident, $fields_mut:ident, $cif_ty:ty, $fields_ty:ty) => {
+        fn $cif(&self) -> Option<&$cif_ty> {
+            self.$cif.as_ref()
+        }
+        fn $cif_mut(&mut self) -> &mut Option<$cif_ty> {
+            &mut self.$cif
+        }
+        fn $fields(&self) -> Option<&$fields_ty> {
+            self.$fields.as_ref()
+        }
+        fn $fields_mut(&mut self) -> &mut Option<$fields_ty> {
+            &mut self.$fields
+        }
+    };
+}
+
+/// Implements the WIF0/EIF0 accessors every CIF ACK manipulator needs.
+macro_rules! ack0_accessors {
+    () => {
+        ack_accessors!(
+            wif0,
+            wif0_mut,
+            wif0_fields,
+            wif0_fields_mut,
+            Cif0,
+            Cif0AckFields
+        );
+        ack_accessors!(
+            eif0,
+            eif0_mut,
+            eif0_fields,
+            eif0_fields_mut,
+            Cif0,
+            Cif0AckFields
+        );
+    };
+}
+
+impl Cif0AckManipulators for Ack {
+    ack0_accessors!();
+}
+
+impl Cif1AckManipulators for Ack {
+    ack0_accessors!();
+    ack_accessors!(
+        wif1,
+        wif1_mut,
+        wif1_fields,
+        wif1_fields_mut,
+        Cif1,
+        Cif1AckFields
+    );
+    ack_accessors!(
+        eif1,
+        eif1_mut,
+        eif1_fields,
+        eif1_fields_mut,
+        Cif1,
+        Cif1AckFields
+    );
+}
+
+impl Cif2AckManipulators for Ack {
+    ack0_accessors!();
+    ack_accessors!(
+        wif2,
+        wif2_mut,
+        wif2_fields,
+        wif2_fields_mut,
+        Cif2,
+        Cif2AckFields
+    );
+    ack_accessors!(
+        eif2,
+        eif2_mut,
+        eif2_fields,
+        eif2_fields_mut,
+        Cif2,
+        Cif2AckFields
+    );
+}
+
+impl Cif3AckManipulators for Ack {
+    ack0_accessors!();
+    ack_accessors!(
+        wif3,
+        wif3_mut,
+        wif3_fields,
+        wif3_fields_mut,
+        Cif3,
+        Cif3AckFields
+    );
+    ack_accessors!(
+        eif3,
+        eif3_mut,
+        eif3_fields,
+        eif3_fields_mut,
+        Cif3,
+        Cif3AckFields
+    );
+}
+
 impl fmt::Display for Ack {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "ACK")?;
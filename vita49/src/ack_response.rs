@@ -232,6 +232,11 @@ impl AckResponse {
     pub fn empty(&self) -> bool {
         self.0 == 0
     }
+
+    /// Sets every bit set in `other`.
+    pub(crate) fn merge(&mut self, other: AckResponse) {
+        self.0 |= other.0;
+    }
 }
 
 impl fmt::Display for AckResponse {
@@ -0,0 +1,511 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the array of CIFs field
+(ANSI/VITA-49.2-2017 section 9.13.1).
+
+The array of CIFs lets a single context packet carry a set of context
+fields per record (e.g. per channel of a channelizer). It uses the
+array-of-records format: the header's bit-mapped indicator word holds
+the CIF0 word describing every record, and the application-specific
+header holds the CIF1, CIF2, and CIF3 words (in that order) when CIF0
+enables them. Each record then carries the data fields for those CIFs,
+encoded exactly as they would be in a context packet.
+
+Records implement the same CIF manipulator traits as [`Context`](crate::Context),
+so any context field accessor can be used on them. Records can't carry
+an array of CIFs themselves; nested arrays are rejected both when
+parsing and when setting records.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::array_of_records::ArrayOfRecordsHeader;
+use crate::cif0::{Cif0, Cif0Fields, Cif0Manipulators};
+use crate::cif1::{Cif1, Cif1Fields, Cif1Manipulators};
+use crate::cif2::{Cif2, Cif2Fields, Cif2Manipulators};
+use crate::cif3::{Cif3, Cif3Fields, Cif3Manipulators};
+use crate::cif7::Cif7Manipulators;
+use crate::cif7::Cif7Opts;
+use crate::VitaError;
+
+/// CIF words shared by every record in the array.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+struct RecordCifs {
+    cif0: Cif0,
+    cif1: Option<Cif1>,
+    cif2: Option<Cif2>,
+    cif3: Option<Cif3>,
+}
+
+impl RecordCifs {
+    /// Recovers the record CIF words from an array-of-records header.
+    fn from_header(header: &ArrayOfRecordsHeader) -> RecordCifs {
+        let cif0 = Cif0::from_u32(header.indicators());
+        let mut words = header.app_specific_header().iter().copied();
+        let mut next = |enabled: bool| if enabled { words.next() } else { None };
+        let cif1 = next(cif0.cif1_enabled()).map(Cif1::from_u32);
+        let cif2 = next(cif0.cif2_enabled()).map(Cif2::from_u32);
+        let cif3 = next(cif0.cif3_enabled()).map(Cif3::from_u32);
+        RecordCifs {
+            cif0,
+            cif1,
+            cif2,
+            cif3,
+        }
+    }
+
+    /// Returns true if the records would carry a nested array of CIFs.
+    fn nests_array_of_cifs(&self) -> bool {
+        self.cif1.is_some_and(|c| c.array_of_cifs())
+    }
+
+    /// Gets the CIF1-CIF3 words to place in the application-specific header.
+    fn app_specific_header(&self) -> Vec<u32> {
+        [
+            self.cif1.map(|c| c.as_u32()),
+            self.cif2.map(|c| c.as_u32()),
+            self.cif3.map(|c| c.as_u32()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Number of words taken by the CIF data fields of a record.
+fn data_words(
+    cif0_fields: &Cif0Fields,
+    cif1_fields: &Option<Cif1Fields>,
+    cif2_fields: &Option<Cif2Fields>,
+    cif3_fields: &Option<Cif3Fields>,
+) -> usize {
+    let mut ret = cif0_fields.size_words() as usize;
+    if let Some(f) = cif1_fields {
+        ret += f.size_words() as usize;
+    }
+    if let Some(f) = cif2_fields {
+        ret += f.size_words() as usize;
+    }
+    if let Some(f) = cif3_fields {
+        ret += f.size_words() as usize;
+    }
+    ret
+}
+
+/// A single record in an array of CIFs.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
+#[deku(
+    endian = "endian",
+    ctx = "endian: deku::ctx::Endian, cifs: RecordCifs, words_per_record: u16"
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CifRecord {
+    /// CIF0 indicator fields (carried in the array header).
+    #[deku(skip, default = "cifs.cif0")]
+    cif0: Cif0,
+    /// CIF1 indicator fields (carried in the array header).
+    #[deku(skip, default = "cifs.cif1")]
+    cif1: Option<Cif1>,
+    /// CIF2 indicator fields (carried in the array header).
+    #[deku(skip, default = "cifs.cif2")]
+    cif2: Option<Cif2>,
+    /// CIF3 indicator fields (carried in the array header).
+    #[deku(skip, default = "cifs.cif3")]
+    cif3: Option<Cif3>,
+
+    /// CIF0 data fields.
+    #[deku(ctx = "cif0, Cif7Opts::from(None)")]
+    cif0_fields: Cif0Fields,
+    /// CIF1 data fields.
+    #[deku(
+        cond = "cif0.cif1_enabled()",
+        ctx = "cif1.as_ref(), Cif7Opts::from(None)"
+    )]
+    cif1_fields: Option<Cif1Fields>,
+    /// CIF2 data fields.
+    #[deku(
+        cond = "cif0.cif2_enabled()",
+        ctx = "cif2.as_ref(), Cif7Opts::from(None)"
+    )]
+    cif2_fields: Option<Cif2Fields>,
+    /// CIF3 data fields.
+    #[deku(
+        cond = "cif0.cif3_enabled()",
+        ctx = "cif3.as_ref(), Cif7Opts::from(None)"
+    )]
+    cif3_fields: Option<Cif3Fields>,
+    /// Trailing record words not covered by the CIF data fields.
+    #[deku(
+        assert = "data_words(cif0_fields, cif1_fields, cif2_fields, cif3_fields) <= words_per_record as usize",
+        count = "(words_per_record as usize).saturating_sub(data_words(cif0_fields, cif1_fields, cif2_fields, cif3_fields))"
+    )]
+    extra_words: Vec<u32>,
+}
+
+impl CifRecord {
+    /// Create a new record with no CIF bits or fields set.
+    pub fn new() -> CifRecord {
+        CifRecord::default()
+    }
+
+    /// Gets the size of the record in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        data_words(
+            &self.cif0_fields,
+            &self.cif1_fields,
+            &self.cif2_fields,
+            &self.cif3_fields,
+        ) as u16
+            + self.extra_words.len() as u16
+    }
+
+    /// Gets any trailing record words this crate doesn't interpret.
+    pub fn extra_words(&self) -> &[u32] {
+        &self.extra_words
+    }
+
+    fn cifs(&self) -> RecordCifs {
+        RecordCifs {
+            cif0: self.cif0,
+            cif1: self.cif1,
+            cif2: self.cif2,
+            cif3: self.cif3,
+        }
+    }
+}
+
+// Records don't carry CIF7, so only the current value is available
+impl Cif7Manipulators for CifRecord {}
+
+impl Cif0Manipulators for CifRecord {
+    fn cif0(&self) -> &Cif0 {
+        &self.cif0
+    }
+    fn cif0_mut(&mut self) -> &mut Cif0 {
+        &mut self.cif0
+    }
+    fn cif0_fields(&self) -> &Cif0Fields {
+        &self.cif0_fields
+    }
+    fn cif0_fields_mut(&mut self) -> &mut Cif0Fields {
+        &mut self.cif0_fields
+    }
+}
+
+impl Cif1Manipulators for CifRecord {
+    fn cif0(&self) -> &Cif0 {
+        &self.cif0
+    }
+    fn cif0_mut(&mut self) -> &mut Cif0 {
+        &mut self.cif0
+    }
+    fn cif1(&self) -> Option<&Cif1> {
+        self.cif1.as_ref()
+    }
+    fn cif1_mut(&mut self) -> &mut Option<Cif1> {
+        &mut self.cif1
+    }
+    fn cif1_fields(&self) -> Option<&Cif1Fields> {
+        self.cif1_fields.as_ref()
+    }
+    fn cif1_fields_mut(&mut self) -> &mut Option<Cif1Fields> {
+        &mut self.cif1_fields
+    }
+}
+
+impl Cif2Manipulators for CifRecord {
+    fn cif0(&self) -> &Cif0 {
+        &self.cif0
+    }
+    fn cif0_mut(&mut self) -> &mut Cif0 {
+        &mut self.cif0
+    }
+    fn cif2(&self) -> Option<&Cif2> {
+        self.cif2.as_ref()
+    }
+    fn cif2_mut(&mut self) -> &mut Option<Cif2> {
+        &mut self.cif2
+    }
+    fn cif2_fields(&self) -> Option<&Cif2Fields> {
+        self.cif2_fields.as_ref()
+    }
+    fn cif2_fields_mut(&mut self) -> &mut Option<Cif2Fields> {
+        &mut self.cif2_fields
+    }
+}
+
+impl Cif3Manipulators for CifRecord {
+    fn cif0(&self) -> &Cif0 {
+        &self.cif0
+    }
+    fn cif0_mut(&mut self) -> &mut Cif0 {
+        &mut self.cif0
+    }
+    fn cif3(&self) -> Option<&Cif3> {
+        self.cif3.as_ref()
+    }
+    fn cif3_mut(&mut self) -> &mut Option<Cif3> {
+        &mut self.cif3
+    }
+    fn cif3_fields(&self) -> Option<&Cif3Fields> {
+        self.cif3_fields.as_ref()
+    }
+    fn cif3_fields_mut(&mut self) -> &mut Option<Cif3Fields> {
+        &mut self.cif3_fields
+    }
+}
+
+impl fmt::Display for CifRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cif0)?;
+        if let Some(cif1) = self.cif1 {
+            write!(f, "{cif1}")?;
+        }
+        if let Some(cif2) = self.cif2 {
+            write!(f, "{cif2}")?;
+        }
+        if let Some(cif3) = self.cif3 {
+            write!(f, "{cif3}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Base array of CIFs structure.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArrayOfCifs {
+    #[deku(assert = "!RecordCifs::from_header(header).nests_array_of_cifs()")]
+    header: ArrayOfRecordsHeader,
+    #[deku(
+        count = "header.num_records()",
+        ctx = "RecordCifs::from_header(header), header.words_per_record()"
+    )]
+    records: Vec<CifRecord>,
+}
+
+impl ArrayOfCifs {
+    /// Create a new array of CIFs from a set of records.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::MismatchedRecords`] if the records don't all
+    /// carry the same CIF fields (and sizes), [`VitaError::NestedArrayOfCifs`]
+    /// if they carry an array of CIFs, or [`VitaError::OutOfRange`] if
+    /// there are more than 4095 records.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{ArrayOfCifs, CifRecord};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let records = (0..4)
+    ///     .map(|i| {
+    ///         let mut record = CifRecord::new();
+    ///         record.set_bandwidth_hz(Some(1e6));
+    ///         record.set_rf_ref_freq_hz(Some(100e6 + i as f64 * 1e6));
+    ///         record
+    ///     })
+    ///     .collect();
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_array_of_cifs(Some(ArrayOfCifs::new(records)?));
+    /// packet.update_packet_size();
+    ///
+    /// let parsed = Vrt::try_from(&packet.to_bytes()?[..])?;
+    /// let array = parsed.payload().context().unwrap().array_of_cifs().unwrap();
+    /// assert_eq!(array.len(), 4);
+    /// assert_eq!(array.record(2).unwrap().bandwidth_hz(), Some(1e6));
+    /// assert_eq!(array.record(2).unwrap().rf_ref_freq_hz(), Some(102e6));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(records: Vec<CifRecord>) -> Result<ArrayOfCifs, VitaError> {
+        let mut ret = ArrayOfCifs::default();
+        ret.set_records(records)?;
+        Ok(ret)
+    }
+
+    /// Gets the size of the array in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        self.header.size_words() + self.records.iter().map(|r| r.size_words()).sum::<u16>()
+    }
+
+    /// Gets the array-of-records header.
+    pub fn header(&self) -> &ArrayOfRecordsHeader {
+        &self.header
+    }
+
+    /// Gets the number of records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if the array holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Gets the record at `index`, if there is one.
+    pub fn record(&self, index: usize) -> Option<&CifRecord> {
+        self.records.get(index)
+    }
+
+    /// Gets all records.
+    pub fn records(&self) -> &[CifRecord] {
+        &self.records
+    }
+
+    /// Sets the records, updating the header to match.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::MismatchedRecords`] if the records don't all
+    /// carry the same CIF fields (and sizes), [`VitaError::NestedArrayOfCifs`]
+    /// if they carry an array of CIFs, or [`VitaError::OutOfRange`] if
+    /// there are more than 4095 records.
+    pub fn set_records(&mut self, records: Vec<CifRecord>) -> Result<(), VitaError> {
+        let (cifs, words_per_record) = match records.first() {
+            Some(first) => {
+                if records
+                    .iter()
+                    .any(|r| r.cifs() != first.cifs() || r.size_words() != first.size_words())
+                {
+                    return Err(VitaError::MismatchedRecords);
+                }
+                if first.cifs().nests_array_of_cifs() {
+                    return Err(VitaError::NestedArrayOfCifs);
+                }
+                (first.cifs(), first.size_words() as usize)
+            }
+            None => (RecordCifs::default(), 0),
+        };
+        self.header = ArrayOfRecordsHeader::new(
+            cifs.cif0.as_u32(),
+            words_per_record,
+            records.len(),
+            cifs.app_specific_header(),
+        )?;
+        self.records = records;
+        Ok(())
+    }
+}
+
+impl fmt::Display for ArrayOfCifs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn mismatched_records_rejected() {
+        let mut a = CifRecord::new();
+        a.set_bandwidth_hz(Some(1e6));
+        let mut b = CifRecord::new();
+        b.set_sample_rate_sps(Some(1e6));
+        assert!(matches!(
+            ArrayOfCifs::new(vec![a, b]),
+            Err(VitaError::MismatchedRecords)
+        ));
+    }
+
+    #[test]
+    fn cif1_records_round_trip() {
+        let records: Vec<CifRecord> = (0..64)
+            .map(|i| {
+                let mut record = CifRecord::new();
+                record.set_bandwidth_hz(Some(250e3));
+                record.set_rf_ref_freq_hz(Some(1e9 + i as f64 * 250e3));
+                record
+                    .set_phase_offset_radians(Some(i as f32 / 64.0))
+                    .unwrap();
+                record
+            })
+            .collect();
+        let array = ArrayOfCifs::new(records).unwrap();
+        assert!(array.header().indicators() & (1 << 1) != 0);
+        assert_eq!(array.header().app_specific_header().len(), 1);
+        assert_eq!(array.header().words_per_record(), 5);
+
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_array_of_cifs(Some(array.clone()));
+        packet.update_packet_size();
+        let parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+        let parsed_array = parsed.payload().context().unwrap().array_of_cifs().unwrap();
+        assert_eq!(parsed_array, &array);
+        let record = parsed_array.record(63).unwrap();
+        assert_eq!(record.rf_ref_freq_hz(), Some(1e9 + 63.0 * 250e3));
+        assert_eq!(record.phase_offset_radians(), Some(63.0 / 64.0));
+    }
+
+    #[test]
+    fn records_too_small_rejected() {
+        // Bandwidth takes 2 words, but the header says each record is
+        // only 1 word. The trailing words keep the read itself from
+        // running short.
+        let words: [u32; 7] = [5, (3 << 24) | (1 << 12) | 2, 1 << 29, 0, 0, 0, 0];
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_be_bytes()).collect();
+        let mut cursor = std::io::Cursor::new(&bytes);
+        let mut reader = deku::reader::Reader::new(&mut cursor);
+        assert!(ArrayOfCifs::from_reader_with_ctx(&mut reader, deku::ctx::Endian::Big).is_err());
+    }
+
+    #[test]
+    fn nested_array_rejected() {
+        let mut record = CifRecord::new();
+        record.set_array_of_cifs(Some(ArrayOfCifs::default()));
+        assert!(matches!(
+            ArrayOfCifs::new(vec![record]),
+            Err(VitaError::NestedArrayOfCifs)
+        ));
+
+        // One record whose CIF1 word enables the array of CIFs field:
+        // array size, header word, CIF0 (CIF1 enabled), CIF1, then the
+        // nested (empty) array.
+        let words: [u32; 7] = [7, (4 << 24) | (3 << 12) | 1, 1 << 1, 1 << 11, 3, 3 << 24, 0];
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_be_bytes()).collect();
+        let mut cursor = std::io::Cursor::new(&bytes);
+        let mut reader = deku::reader::Reader::new(&mut cursor);
+        assert!(ArrayOfCifs::from_reader_with_ctx(&mut reader, deku::ctx::Endian::Big).is_err());
+    }
+
+    #[test]
+    fn display_lists_all_cifs() {
+        let mut record = CifRecord::new();
+        record.set_bind(Some(1));
+        record.set_network_id(Some(2));
+        let text = record.to_string();
+        assert!(text.contains("CIF2:"));
+        assert!(text.contains("  Bind: true"));
+        assert!(text.contains("CIF3:"));
+        assert!(text.contains("  Network ID: true"));
+    }
+
+    #[cfg(feature = "cif7")]
+    #[test]
+    fn records_only_carry_current_value() {
+        let mut record = CifRecord::new();
+        record.set_bandwidth_hz(Some(1e6));
+        assert_eq!(record.cif7(), None);
+        record.set_cif7_attr(Cif7Attr::Current, true).unwrap();
+        record.set_cif7_attr(Cif7Attr::Max, false).unwrap();
+        assert!(matches!(
+            record.set_bandwidth_hz_attr(Cif7Attr::Max, 2e6),
+            Err(VitaError::UnsupportedCif7Attr)
+        ));
+        assert!(matches!(
+            record.set_cif7_attr(Cif7Attr::Current, false),
+            Err(VitaError::UnsupportedCif7Attr)
+        ));
+        assert_eq!(record.bandwidth_hz(), Some(1e6));
+    }
+}
@@ -0,0 +1,255 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the array-of-records field
+format defined by ANSI/VITA-49.2-2017.
+
+Several variable-length context fields (e.g. the 3-D pointing vector
+structure and the sector/step scan field) share this format: a
+three-word header describing the array, an optional application-specific
+header, and a set of equally-sized records. The bit-mapped indicator
+word in the header tells which subfields are present in each record.
+
+Parsing fails if the sizes in the header don't add up (the array size
+must equal the header size plus the size of every record) or if the
+records are too small to hold the subfields the indicators select.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::VitaError;
+
+/// Number of words in the fixed part of the array-of-records header.
+const FIXED_HEADER_WORDS: usize = 3;
+/// Maximum header size (8-bit field).
+const MAX_HEADER_WORDS: usize = 0xFF;
+/// Maximum number of words per record (12-bit field).
+const MAX_WORDS_PER_RECORD: usize = 0xFFF;
+/// Maximum number of records (12-bit field).
+const MAX_RECORDS: usize = 0xFFF;
+
+/// Checks that the array size matches the header size and record
+/// layout given in the second header word.
+fn sizes_consistent(array_size: u32, w2: u32) -> bool {
+    let header_size = (w2 >> 24) as usize;
+    let words_per_record = ((w2 >> 12) & 0xFFF) as usize;
+    let num_records = (w2 & 0xFFF) as usize;
+    header_size >= FIXED_HEADER_WORDS
+        && array_size as usize == header_size + words_per_record * num_records
+}
+
+/// A record type carried in an array-of-records field.
+pub(crate) trait Record {
+    /// Bit-mapped indicators for the subfields this crate understands
+    /// that are present in the record.
+    fn known_indicators(&self) -> u32;
+    /// Size of the record in 32-bit words.
+    fn size_words(&self) -> u16;
+    /// Trailing record words this crate doesn't interpret.
+    fn extra_words(&self) -> &[u32];
+}
+
+/// Array-of-records header.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArrayOfRecordsHeader {
+    array_size: u32,
+    #[deku(assert = "sizes_consistent(*array_size, *w2)")]
+    w2: u32,
+    indicators: u32,
+    #[deku(count = "((w2 >> 24) as usize).saturating_sub(FIXED_HEADER_WORDS)")]
+    app_specific_header: Vec<u32>,
+}
+
+impl Default for ArrayOfRecordsHeader {
+    fn default() -> Self {
+        ArrayOfRecordsHeader {
+            array_size: FIXED_HEADER_WORDS as u32,
+            w2: (FIXED_HEADER_WORDS as u32) << 24,
+            indicators: 0,
+            app_specific_header: Vec::new(),
+        }
+    }
+}
+
+impl ArrayOfRecordsHeader {
+    /// Create a new header describing `num_records` records of
+    /// `words_per_record` words each.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the application-specific
+    /// header, record size, or record count don't fit in their
+    /// respective header subfields.
+    pub(crate) fn new(
+        indicators: u32,
+        words_per_record: usize,
+        num_records: usize,
+        app_specific_header: Vec<u32>,
+    ) -> Result<ArrayOfRecordsHeader, VitaError> {
+        let header_size = FIXED_HEADER_WORDS + app_specific_header.len();
+        if header_size > MAX_HEADER_WORDS
+            || words_per_record > MAX_WORDS_PER_RECORD
+            || num_records > MAX_RECORDS
+        {
+            return Err(VitaError::OutOfRange);
+        }
+        Ok(ArrayOfRecordsHeader {
+            array_size: (header_size + words_per_record * num_records) as u32,
+            w2: ((header_size as u32) << 24)
+                | ((words_per_record as u32) << 12)
+                | num_records as u32,
+            indicators,
+            app_specific_header,
+        })
+    }
+
+    /// Create a new header describing `records`. Indicator bits outside
+    /// `known_bits` are carried over from `prev_indicators` when the
+    /// records still carry extra words for them.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::MismatchedRecords`] if the records don't all
+    /// carry the same subfields, or [`VitaError::OutOfRange`] if the
+    /// header can't describe them.
+    pub(crate) fn for_records<R: Record>(
+        records: &[R],
+        known_bits: u32,
+        prev_indicators: u32,
+        app_specific_header: Vec<u32>,
+    ) -> Result<ArrayOfRecordsHeader, VitaError> {
+        let (indicators, words_per_record) = match records.first() {
+            Some(first) => {
+                if records.iter().any(|r| {
+                    r.known_indicators() != first.known_indicators()
+                        || r.extra_words().len() != first.extra_words().len()
+                }) {
+                    return Err(VitaError::MismatchedRecords);
+                }
+                let mut indicators = first.known_indicators();
+                if !first.extra_words().is_empty() {
+                    indicators |= prev_indicators & !known_bits;
+                }
+                (indicators, first.size_words() as usize)
+            }
+            None => (0, 0),
+        };
+        ArrayOfRecordsHeader::new(
+            indicators,
+            words_per_record,
+            records.len(),
+            app_specific_header,
+        )
+    }
+
+    /// Gets the size of the header in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (FIXED_HEADER_WORDS + self.app_specific_header.len()) as u16
+    }
+
+    /// Gets the total size of the array (header and records) in 32-bit
+    /// words, as reported by the header.
+    pub fn array_size_words(&self) -> u32 {
+        self.array_size
+    }
+
+    /// Gets the header size in 32-bit words, as reported by the header.
+    pub fn header_size_words(&self) -> u8 {
+        (self.w2 >> 24) as u8
+    }
+
+    /// Gets the size of each record in 32-bit words.
+    pub fn words_per_record(&self) -> u16 {
+        ((self.w2 >> 12) & 0xFFF) as u16
+    }
+
+    /// Gets the number of records in the array.
+    pub fn num_records(&self) -> u16 {
+        (self.w2 & 0xFFF) as u16
+    }
+
+    /// Gets the bit-mapped indicator word describing which subfields
+    /// are present in each record.
+    pub fn indicators(&self) -> u32 {
+        self.indicators
+    }
+
+    /// Gets the application-specific header words.
+    pub fn app_specific_header(&self) -> &[u32] {
+        &self.app_specific_header
+    }
+
+    /// Returns true if the records are big enough to hold `needed_words`
+    /// words of subfields each (trivially true for an empty array).
+    pub(crate) fn records_fit(&self, needed_words: usize) -> bool {
+        self.num_records() == 0 || needed_words <= self.words_per_record() as usize
+    }
+}
+
+impl fmt::Display for ArrayOfRecordsHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{} records of {} words (indicators: {:#010x}, {} app-specific header words)",
+            self.num_records(),
+            self.words_per_record(),
+            self.indicators(),
+            self.app_specific_header.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(words: &[u32]) -> Result<ArrayOfRecordsHeader, DekuError> {
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_be_bytes()).collect();
+        let mut cursor = std::io::Cursor::new(&bytes);
+        let mut reader = deku::reader::Reader::new(&mut cursor);
+        ArrayOfRecordsHeader::from_reader_with_ctx(&mut reader, deku::ctx::Endian::Big)
+    }
+
+    #[test]
+    fn sizes_checked() {
+        // 4-word header, 2 records of 3 words
+        let header = parse(&[10, (4 << 24) | (3 << 12) | 2, 0, 0xAA]).unwrap();
+        assert_eq!(header.app_specific_header(), &[0xAA]);
+        assert_eq!(
+            header,
+            ArrayOfRecordsHeader::new(0, 3, 2, vec![0xAA]).unwrap()
+        );
+        assert!(header.records_fit(3));
+        assert!(!header.records_fit(4));
+
+        // Array size doesn't match the records
+        assert!(parse(&[9, (4 << 24) | (3 << 12) | 2, 0, 0xAA]).is_err());
+        assert!(parse(&[11, (4 << 24) | (3 << 12) | 2, 0, 0xAA]).is_err());
+        // Header size smaller than the fixed header
+        assert!(parse(&[2, 2 << 24, 0]).is_err());
+
+        // An empty array fits anything
+        let empty = ArrayOfRecordsHeader::default();
+        assert!(empty.records_fit(100));
+        assert_eq!(parse(&[3, 3 << 24, 0]).unwrap(), empty);
+    }
+
+    #[test]
+    fn new_limits() {
+        assert!(ArrayOfRecordsHeader::new(0, MAX_WORDS_PER_RECORD, MAX_RECORDS, vec![]).is_ok());
+        assert!(matches!(
+            ArrayOfRecordsHeader::new(0, MAX_WORDS_PER_RECORD + 1, 1, vec![]),
+            Err(VitaError::OutOfRange)
+        ));
+        assert!(matches!(
+            ArrayOfRecordsHeader::new(0, 1, MAX_RECORDS + 1, vec![]),
+            Err(VitaError::OutOfRange)
+        ));
+        assert!(matches!(
+            ArrayOfRecordsHeader::new(0, 1, 1, vec![0; MAX_HEADER_WORDS]),
+            Err(VitaError::OutOfRange)
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the beam widths field
+(ANSI/VITA-49.2-2017 section 9.4.2).
+
+The beam widths field conveys the horizontal and vertical 3 dB
+beam widths of the antenna, in degrees.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+use fixed::{types::extra::U7, FixedU16};
+
+use crate::VitaError;
+
+/// Base beam widths data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeamWidths(u32);
+
+/// Converts a beam width in degrees to its 16-bit fixed-point form.
+fn to_bits(width_deg: f32) -> Result<u32, VitaError> {
+    if !(0.0..=360.0).contains(&width_deg) {
+        return Err(VitaError::OutOfRange);
+    }
+    Ok(FixedU16::<U7>::from_num(width_deg).to_bits() as u32)
+}
+
+impl BeamWidths {
+    /// Create a new `BeamWidths` object given the horizontal and vertical
+    /// beam widths in degrees.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if either width isn't within 0 to
+    /// 360 degrees.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::BeamWidths;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_beam_widths(Some(BeamWidths::new(12.5, 3.25)?));
+    /// let bw = context.beam_widths().unwrap();
+    /// assert_eq!(bw.horizontal_deg(), 12.5);
+    /// assert_eq!(bw.vertical_deg(), 3.25);
+    /// assert!(BeamWidths::new(-1.0, 3.25).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(horizontal_deg: f32, vertical_deg: f32) -> Result<BeamWidths, VitaError> {
+        Ok(BeamWidths(
+            (to_bits(horizontal_deg)? << 16) | to_bits(vertical_deg)?,
+        ))
+    }
+
+    /// Gets the size of the beam widths structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the horizontal beam width (degrees).
+    pub fn horizontal_deg(&self) -> f32 {
+        FixedU16::<U7>::from_bits((self.0 >> 16) as u16).to_num()
+    }
+
+    /// Sets the horizontal beam width (degrees).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the width isn't within 0 to 360
+    /// degrees.
+    pub fn set_horizontal_deg(&mut self, horizontal_deg: f32) -> Result<(), VitaError> {
+        self.0 = (self.0 & 0x0000_FFFF) | (to_bits(horizontal_deg)? << 16);
+        Ok(())
+    }
+
+    /// Gets the vertical beam width (degrees).
+    pub fn vertical_deg(&self) -> f32 {
+        FixedU16::<U7>::from_bits((self.0 & 0xFFFF) as u16).to_num()
+    }
+
+    /// Sets the vertical beam width (degrees).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the width isn't within 0 to 360
+    /// degrees.
+    pub fn set_vertical_deg(&mut self, vertical_deg: f32) -> Result<(), VitaError> {
+        self.0 = (self.0 & 0xFFFF_0000) | to_bits(vertical_deg)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for BeamWidths {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Horizontal: {} deg, Vertical: {} deg",
+            self.horizontal_deg(),
+            self.vertical_deg()
+        )
+    }
+}
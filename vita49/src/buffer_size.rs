@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the buffer size field
+(ANSI/VITA-49.2-2017 section 9.10.7).
+
+The first word holds the size of the buffer in bytes. The second
+word holds the buffer fill level in its upper 16 bits and a set of
+status flags in its lowest bits.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+/// Status bit set when the buffer has overflowed.
+const OVERFLOW_BIT: u64 = 1 << 2;
+/// Status bit set when the buffer is almost full.
+const ALMOST_FULL_BIT: u64 = 1 << 1;
+/// Status bit set when the buffer is almost empty.
+const ALMOST_EMPTY_BIT: u64 = 1 << 0;
+
+/// Base buffer size data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BufferSize(u64);
+
+impl BufferSize {
+    /// Create a new `BufferSize` object given the buffer size in bytes and
+    /// its fill level. All status flags start cleared.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::BufferSize;
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// let mut buffer = BufferSize::new(1 << 20, 0x8000);
+    /// buffer.set_overflow(true);
+    /// context.set_buffer_size(Some(buffer));
+    /// let buffer = context.buffer_size().unwrap();
+    /// assert_eq!(buffer.size_bytes(), 1 << 20);
+    /// assert_eq!(buffer.level(), 0x8000);
+    /// assert!(buffer.overflow());
+    /// assert!(!buffer.almost_full());
+    /// ```
+    pub fn new(size_bytes: u32, level: u16) -> BufferSize {
+        BufferSize(((size_bytes as u64) << 32) | ((level as u64) << 16))
+    }
+
+    /// Gets the size of the buffer size structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the buffer size (bytes).
+    pub fn size_bytes(&self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Sets the buffer size (bytes).
+    pub fn set_size_bytes(&mut self, size_bytes: u32) {
+        self.0 = (self.0 & 0xFFFF_FFFF) | ((size_bytes as u64) << 32);
+    }
+
+    /// Gets the buffer fill level.
+    pub fn level(&self) -> u16 {
+        ((self.0 >> 16) & 0xFFFF) as u16
+    }
+
+    /// Sets the buffer fill level.
+    pub fn set_level(&mut self, level: u16) {
+        self.0 = (self.0 & !(0xFFFF << 16)) | ((level as u64) << 16);
+    }
+
+    /// Returns true if the buffer has overflowed.
+    pub fn overflow(&self) -> bool {
+        self.0 & OVERFLOW_BIT != 0
+    }
+
+    /// Sets the buffer overflow flag.
+    pub fn set_overflow(&mut self, overflow: bool) {
+        self.set_flag(OVERFLOW_BIT, overflow);
+    }
+
+    /// Returns true if the buffer is almost full.
+    pub fn almost_full(&self) -> bool {
+        self.0 & ALMOST_FULL_BIT != 0
+    }
+
+    /// Sets the buffer almost full flag.
+    pub fn set_almost_full(&mut self, almost_full: bool) {
+        self.set_flag(ALMOST_FULL_BIT, almost_full);
+    }
+
+    /// Returns true if the buffer is almost empty.
+    pub fn almost_empty(&self) -> bool {
+        self.0 & ALMOST_EMPTY_BIT != 0
+    }
+
+    /// Sets the buffer almost empty flag.
+    pub fn set_almost_empty(&mut self, almost_empty: bool) {
+        self.set_flag(ALMOST_EMPTY_BIT, almost_empty);
+    }
+
+    fn set_flag(&mut self, bit: u64, set: bool) {
+        if set {
+            self.0 |= bit;
+        } else {
+            self.0 &= !bit;
+        }
+    }
+}
+
+impl fmt::Display for BufferSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Size: {} bytes, Level: {}, Overflow: {}, Almost full: {}, Almost empty: {}",
+            self.size_bytes(),
+            self.level(),
+            self.overflow(),
+            self.almost_full(),
+            self.almost_empty()
+        )
+    }
+}
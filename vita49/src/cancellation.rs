@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::prelude::*;
+use crate::{CifField, CommandPayload};
 use deku::prelude::*;
 use std::fmt;
 
@@ -27,6 +28,21 @@ pub struct Cancellation {
 }
 
 impl Cancellation {
+    /// Build a cancellation from a set of CIF indicators.
+    pub(crate) fn from_indicators(
+        cif0: Cif0,
+        cif1: Option<Cif1>,
+        cif2: Option<Cif2>,
+        cif3: Option<Cif3>,
+    ) -> Cancellation {
+        Cancellation {
+            cif0,
+            cif1,
+            cif2,
+            cif3,
+        }
+    }
+
     /// Get the cancellation size (in 32-bit words).
     pub fn size_words(&self) -> u16 {
         // Start with 1 32-bit word for the CIF0 field
@@ -76,6 +92,67 @@ impl Cancellation {
     pub fn cif3_mut(&mut self) -> &mut Option<Cif3> {
         &mut self.cif3
     }
+
+    /// Returns true if the given field is being cancelled.
+    pub fn is_cancelled(&self, field: CifField) -> bool {
+        field.is_set_in(
+            &self.cif0,
+            self.cif1.as_ref(),
+            self.cif2.as_ref(),
+            self.cif3.as_ref(),
+        )
+    }
+
+    /// Add or remove a field from the set of fields being cancelled.
+    ///
+    /// [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{Cancellation, CifField};
+    /// let mut cancellation = Cancellation::default();
+    /// cancellation.set_cancelled(CifField::Bandwidth, true);
+    /// cancellation.set_cancelled(CifField::NetworkId, true);
+    /// assert!(cancellation.is_cancelled(CifField::NetworkId));
+    /// assert_eq!(
+    ///     cancellation.present_fields().collect::<Vec<_>>(),
+    ///     vec![CifField::Bandwidth, CifField::NetworkId]
+    /// );
+    /// cancellation.set_cancelled(CifField::NetworkId, false);
+    /// assert!(cancellation.cif3().is_none());
+    /// ```
+    pub fn set_cancelled(&mut self, field: CifField, cancelled: bool) {
+        field.set_in(
+            &mut self.cif0,
+            &mut self.cif1,
+            &mut self.cif2,
+            &mut self.cif3,
+            cancelled,
+        );
+    }
+
+    /// Iterate over the fields being cancelled, in packet order.
+    /// Cancellation packets carry no field values, so this is the
+    /// cancellation counterpart of
+    /// [`CifFieldAccess::present_fields()`](crate::CifFieldAccess::present_fields()).
+    pub fn present_fields(&self) -> impl Iterator<Item = CifField> + '_ {
+        CifField::ALL
+            .iter()
+            .copied()
+            .filter(move |f| self.is_cancelled(*f))
+    }
+}
+
+impl TryFrom<CommandPayload> for Cancellation {
+    type Error = CommandPayload;
+
+    fn try_from(value: CommandPayload) -> Result<Self, Self::Error> {
+        match value {
+            CommandPayload::Cancellation(c) => Ok(c),
+            a => Err(a),
+        }
+    }
 }
 
 impl fmt::Display for Cancellation {
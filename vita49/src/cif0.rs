@@ -12,11 +12,17 @@ use crate::ack::AckLevel;
 use crate::ack_response::AckResponse;
 use crate::device_id::DeviceId;
 use crate::{
-    cif7::Cif7Opts, context_association_lists::ContextAssociationLists,
-    ecef_ephemeris::EcefEphemeris, formatted_gps::FormattedGps, gain::Gain, gps_ascii::GpsAscii,
+    cif7::{Cif7Manipulators, Cif7Opts},
+    context_association_lists::ContextAssociationLists,
+    ecef_ephemeris::EcefEphemeris,
+    formatted_gps::FormattedGps,
+    gain::Gain,
+    gps_ascii::GpsAscii,
+    state_event_indicators::StateEventIndicators,
 };
+use crate::{timestamp::VitaTimestamp, Tsf, Tsi, VitaError};
 use deku::prelude::*;
-use fixed::types::extra::{U20, U7};
+use fixed::types::extra::{U20, U6, U7};
 use fixed::{FixedI16, FixedI64, FixedU64};
 use vita49_macros::{ack_field, cif_basic, cif_field, cif_fields, cif_radix, cif_radix_masked};
 
@@ -65,6 +71,11 @@ impl Cif0 {
         self.0
     }
 
+    /// Create a CIF from a raw u32.
+    pub(crate) fn from_u32(word: u32) -> Cif0 {
+        Cif0(word)
+    }
+
     /// Returns true if the whole CIF is empty.
     pub fn empty(&self) -> bool {
         self.0 == 0
@@ -83,11 +94,11 @@ pub struct Cif0Fields {
     gain: Gain,
     over_range_count: u32,
     sample_rate: u64,
-    timestamp_adjustment: u64,
+    timestamp_adjustment: i64,
     timestamp_cal_time: u32,
     temperature: i32,
     device_id: DeviceId,
-    state_indicators: u32,
+    state_indicators: StateEventIndicators,
     signal_data_payload_format: u64,
     formatted_gps: FormattedGps,
     formatted_ins: FormattedGps,
@@ -128,7 +139,7 @@ pub struct Cif0AckFields {
 /// Trait for common CIF0 manipulation methods. Used by Context and
 /// Command packets.
 #[rustfmt::skip]
-pub trait Cif0Manipulators {
+pub trait Cif0Manipulators: Cif7Manipulators {
     /// Get a reference to the packet's CIF0 (indicators)
     fn cif0(&self) -> &Cif0;
     /// Get a mutable reference to the packet's CIF0 (indicators)
@@ -148,23 +159,119 @@ pub trait Cif0Manipulators {
     cif_basic!(cif0, gain, gain, Gain);
     cif_basic!(cif0, over_range_count, over_range_count, u32);
     cif_radix!(cif0, sample_rate, sample_rate_sps, f64, FixedU64::<U20>);
-    // TODO: add full support
-    cif_basic!(cif0, timestamp_adjustment, timestamp_adjustment, u64);
-    // TODO: add full support
+    cif_basic!(cif0, timestamp_adjustment, timestamp_adjustment_fs, i64);
     cif_basic!(cif0, timestamp_cal_time, timestamp_cal_time, u32);
-    // TODO: add full support
-    cif_basic!(cif0, temperature, temperature, i32);
+    cif_radix_masked!(cif0, temperature, temperature_c, f32, FixedI16::<U6>, i32, i16, -273.15..=FixedI16::<U6>::MAX.to_num());
     cif_basic!(cif0, device_id, device_id, DeviceId);
-    // TODO: add full support
-    cif_basic!(cif0, state_indicators, state_indicators, u32);
+    cif_basic!(cif0, state_indicators, state_indicators, StateEventIndicators);
     // TODO: add full support
     cif_basic!(cif0, signal_data_payload_format, signal_data_payload_format, u64);
     cif_basic!(cif0, formatted_gps, formatted_gps, FormattedGps);
     cif_basic!(cif0, formatted_ins, formatted_ins, FormattedGps);
     cif_basic!(cif0, ecef_ephemeris, ecef_ephemeris, EcefEphemeris);
     cif_basic!(cif0, relative_ephemeris, relative_ephemeris, EcefEphemeris);
+    cif_basic!(cif0, ephemeris_ref_id, ephemeris_ref_id, u32);
     cif_basic!(cif0, gps_ascii, gps_ascii, GpsAscii);
     cif_basic!(cif0, context_association_lists, context_association_lists, ContextAssociationLists);
+
+    /// Get the current timestamp adjustment in picoseconds. If `None`
+    /// is returned, the field is unset.
+    fn timestamp_adjustment_ps(&self) -> Option<f64> {
+        self.timestamp_adjustment_fs().map(|fs| *fs as f64 / 1000.0)
+    }
+    /// Set the timestamp adjustment in picoseconds (stored with femtosecond
+    /// resolution). If `None` is passed, the field will be unset.
+    ///
+    /// [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value doesn't fit in the
+    /// 64-bit femtosecond field.
+    fn set_timestamp_adjustment_ps(&mut self, adjustment_ps: Option<f64>) -> Result<(), VitaError> {
+        let fs = match adjustment_ps {
+            Some(ps) => {
+                let fs = (ps * 1000.0).round();
+                if !(fs >= i64::MIN as f64 && fs < i64::MAX as f64) {
+                    return Err(VitaError::OutOfRange);
+                }
+                Some(fs as i64)
+            }
+            None => None,
+        };
+        self.set_timestamp_adjustment_fs(fs);
+        Ok(())
+    }
+
+    /// Get the timestamp calibration time as a [`VitaTimestamp`]. The field
+    /// only carries integer seconds, so `tsi` should be the TSI mode of the
+    /// packet this field came from.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// let cal = VitaTimestamp::new(Tsi::Utc, Some(1_700_000_000), Tsf::Null, None)?;
+    /// context.set_calibration_time(Some(cal))?;
+    /// assert_eq!(context.timestamp_cal_time(), Some(&1_700_000_000));
+    /// assert_eq!(context.calibration_time(Tsi::Utc), Some(cal));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn calibration_time(&self, tsi: Tsi) -> Option<VitaTimestamp> {
+        let secs = *self.timestamp_cal_time()?;
+        VitaTimestamp::new(tsi, Some(secs), Tsf::Null, None).ok()
+    }
+    /// Set the timestamp calibration time from a [`VitaTimestamp`]. Only the
+    /// integer part is stored. If `None` is passed, the field will be unset.
+    ///
+    /// [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::TimestampModeMismatch`] if the timestamp has
+    /// no integer part.
+    fn set_calibration_time(&mut self, cal_time: Option<VitaTimestamp>) -> Result<(), VitaError> {
+        let secs = match cal_time {
+            Some(ts) => Some(ts.integer().ok_or(VitaError::TimestampModeMismatch)?),
+            None => None,
+        };
+        self.set_timestamp_cal_time(secs);
+        Ok(())
+    }
+
+    /// Apply this packet's timestamp adjustment to a timestamp taken from
+    /// an associated data packet, giving the time at the reference point.
+    /// If no adjustment is set, the timestamp is returned unchanged.
+    ///
+    /// # Errors
+    /// See [`VitaTimestamp::adjusted_by_fs()`].
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut data = Vrt::new_signal_data_packet();
+    /// data.set_integer_timestamp(Some(100), Tsi::Gps)?;
+    /// data.set_fractional_timestamp(Some(1_000), Tsf::RealTimePs)?;
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_timestamp_adjustment_ps(Some(250.0))?;
+    /// assert_eq!(context.timestamp_adjustment_fs(), Some(&250_000));
+    ///
+    /// let corrected = context.apply_timestamp_adjustment(&data.timestamp()?)?;
+    /// assert_eq!(corrected.integer(), Some(100));
+    /// assert_eq!(corrected.fractional(), Some(1_250));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn apply_timestamp_adjustment(&self, timestamp: &VitaTimestamp) -> Result<VitaTimestamp, VitaError> {
+        match self.timestamp_adjustment_fs() {
+            Some(fs) => timestamp.adjusted_by_fs(*fs),
+            None => Ok(*timestamp),
+        }
+    }
 }
 
 /// Shared trait for manipulating CIF0 ACK fields.
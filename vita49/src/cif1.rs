@@ -6,20 +6,38 @@ Defines fields and methods related to CIF1 (ANSI/VITA-49.2-2017 9.1).
 Fields here are compatible with VITA 49.2 and later.
 */
 
+use core::f32::consts::PI;
 use core::fmt;
 
 use crate::{
-    ack::AckLevel, ack_response::AckResponse, cif0::Cif0, cif7::Cif7Opts, gain::Gain,
-    spectrum::Spectrum, Cif0AckFields, Threshold,
+    ack::AckLevel,
+    ack_response::AckResponse,
+    array_of_cifs::ArrayOfCifs,
+    beam_widths::BeamWidths,
+    buffer_size::BufferSize,
+    cif0::Cif0,
+    cif7::{Cif7Manipulators, Cif7Opts},
+    eb_no_ber::EbNoBer,
+    gain::Gain,
+    health_status::HealthStatus,
+    index_list::IndexList,
+    intercept_points::InterceptPoints,
+    pointing_vector::PointingVector,
+    pointing_vector_struct::PointingVectorStruct,
+    polarization::Polarization,
+    sector_scan::SectorScan,
+    snr_noise_figure::SnrNoiseFigure,
+    spec_compliance::V49SpecCompliance,
+    spectrum::Spectrum,
+    version_build_code::VersionBuildCode,
+    Cif0AckFields, Threshold,
 };
 use deku::prelude::*;
 use fixed::{
     types::extra::{U20, U6, U7},
     FixedI16, FixedI32, FixedU64,
 };
-use vita49_macros::{
-    ack_field, cif_basic, cif_field, cif_fields, cif_radix, cif_radix_masked, todo_cif_field,
-};
+use vita49_macros::{ack_field, cif_basic, cif_field, cif_fields, cif_radix, cif_radix_masked};
 
 /// Base data structure for the CIF1 single-bit indicators
 #[derive(
@@ -33,7 +51,7 @@ impl Cif1 {
     cif_field!(phase_offset, 31);
     cif_field!(polarization, 30);
     cif_field!(three_d_pointing_vector, 29);
-    todo_cif_field!(three_d_pointing_vector_struct, 28, 1);
+    cif_field!(three_d_pointing_vector_struct, 28);
     cif_field!(spatial_scan_type, 27);
     cif_field!(spatial_ref_type, 26);
     cif_field!(beam_widths, 25);
@@ -48,11 +66,11 @@ impl Cif1 {
     cif_field!(aux_gain, 14);
     cif_field!(aux_bandwidth, 13);
     // Bit 12 is reserved
-    todo_cif_field!(array_of_cifs, 11, 1);
+    cif_field!(array_of_cifs, 11);
     cif_field!(spectrum, 10);
-    todo_cif_field!(sector_scan, 9, 1);
+    cif_field!(sector_scan, 9);
     // Bit 8 is reserved
-    todo_cif_field!(index_list, 7, 1);
+    cif_field!(index_list, 7);
     cif_field!(discrete_io_32, 6);
     cif_field!(discrete_io_64, 5);
     cif_field!(health_status, 4);
@@ -61,6 +79,16 @@ impl Cif1 {
     cif_field!(buffer_size, 1);
     // Bit 0 is reserved
 
+    /// Get the CIF field as a raw u32.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Create a CIF from a raw u32.
+    pub(crate) fn from_u32(word: u32) -> Cif1 {
+        Cif1(word)
+    }
+
     fn empty(&self) -> bool {
         self.0 == 0
     }
@@ -68,49 +96,34 @@ impl Cif1 {
 
 #[cif_fields(cif1)]
 pub struct Cif1Fields {
-    // TODO: add full support
     phase_offset: i32,
-    // TODO: add full support
-    polarization: i32,
-    // TODO: add full support
-    three_d_pointing_vector: i32,
-    // TODO: add basic support
-    three_d_pointing_vector_struct: u32,
+    polarization: Polarization,
+    three_d_pointing_vector: PointingVector,
+    three_d_pointing_vector_struct: PointingVectorStruct,
     // TODO: add full support
     spatial_scan_type: u32,
     // TODO: add full support
     spatial_ref_type: u32,
-    // TODO: add full support
-    beam_widths: u32,
+    beam_widths: BeamWidths,
     range: i32,
-    // TODO: add full support
-    eb_over_no_and_ber: i32,
+    eb_over_no_and_ber: EbNoBer,
     threshold: Threshold,
     compression_point: i32,
-    // TODO: add full support
-    second_and_third_order_intercept_points: i32,
-    // TODO: add full support
-    snr_figure: i32,
+    second_and_third_order_intercept_points: InterceptPoints,
+    snr_figure: SnrNoiseFigure,
     aux_freq: u64,
     aux_gain: Gain,
     aux_bandwidth: u64,
-    // TODO: add basic support
-    array_of_cifs: u32,
+    array_of_cifs: ArrayOfCifs,
     spectrum: Spectrum,
-    // TODO: add basic support
-    sector_scan: u32,
-    // TODO: add basic support
-    index_list: u32,
+    sector_scan: SectorScan,
+    index_list: IndexList,
     discrete_io_32: u32,
     discrete_io_64: u64,
-    // TODO: add full support
-    health_status: u32,
-    // TODO: add full support
-    v49_spec_compliance: u32,
-    // TODO: add full support
-    version_and_build_code: u32,
-    // TODO: add full support
-    buffer_size: u64,
+    health_status: HealthStatus,
+    v49_spec_compliance: V49SpecCompliance,
+    version_and_build_code: VersionBuildCode,
+    buffer_size: BufferSize,
 }
 
 #[cif_fields(cif1)]
@@ -146,7 +159,7 @@ pub struct Cif1AckFields {
 /// Trait for common CIF1 manipulation methods. Used by Context and
 /// Command packets.
 #[rustfmt::skip]
-pub trait Cif1Manipulators {
+pub trait Cif1Manipulators: Cif7Manipulators {
     /// Get a reference to the packet's CIF0 (indicators)
     fn cif0(&self) -> &Cif0;
     /// Get a mutable reference to the packet's CIF0 (indicators)
@@ -160,48 +173,34 @@ pub trait Cif1Manipulators {
     /// Get a mutable reference to the packet's CIF1 data fields
     fn cif1_fields_mut(&mut self) -> &mut Option<Cif1Fields>;
 
-    cif_radix_masked!(cif1, phase_offset, phase_offset_radians, f32, FixedI16::<U7>, i32, i16);
-    // TODO: add full support
-    cif_basic!(cif1, polarization, polarization, i32);
-    // TODO: add full support
-    cif_basic!(cif1, three_d_pointing_vector, three_d_pointing_vector, i32);
-    // TODO: add basic support
-    cif_basic!(cif1, three_d_pointing_vector_struct, three_d_pointing_vector_struct, u32);
+    cif_radix_masked!(cif1, phase_offset, phase_offset_radians, f32, FixedI16::<U7>, i32, i16, -PI..=PI);
+    cif_basic!(cif1, polarization, polarization, Polarization);
+    cif_basic!(cif1, three_d_pointing_vector, three_d_pointing_vector, PointingVector);
+    cif_basic!(cif1, three_d_pointing_vector_struct, three_d_pointing_vector_struct, PointingVectorStruct);
     // TODO: add full support
     cif_basic!(cif1, spatial_scan_type, spatial_scan_type, u32);
     // TODO: add full support
     cif_basic!(cif1, spatial_ref_type, spatial_ref_type, u32);
-    // TODO: add full support
-    cif_basic!(cif1, beam_widths, beam_widths, u32);
+    cif_basic!(cif1, beam_widths, beam_widths, BeamWidths);
     cif_radix!(cif1, range, range_m, f32, FixedI32::<U6>);
-    // TODO: add full support
-    cif_basic!(cif1, eb_over_no_and_ber, eb_over_no_and_ber, i32);
+    cif_basic!(cif1, eb_over_no_and_ber, eb_over_no_and_ber, EbNoBer);
     cif_basic!(cif1, threshold, threshold, Threshold);
     cif_radix_masked!(cif1, compression_point, compression_point_dbm, f32, FixedI16::<U7>, i32, i16);
-    // TODO: add full support
-    cif_basic!(cif1, second_and_third_order_intercept_points, second_and_third_order_intercept_points, i32);
-    // TODO: add full support
-    cif_basic!(cif1, snr_figure, snr_figure, i32);
+    cif_basic!(cif1, second_and_third_order_intercept_points, second_and_third_order_intercept_points, InterceptPoints);
+    cif_basic!(cif1, snr_figure, snr_figure, SnrNoiseFigure);
     cif_radix!(cif1, aux_freq, aux_freq_hz, f64, FixedU64::<U20>);
     cif_basic!(cif1, aux_gain, aux_gain, Gain);
     cif_radix!(cif1, aux_bandwidth, aux_bandwidth_hz, f64, FixedU64::<U20>);
-    // TODO: add basic support
-    cif_basic!(cif1, array_of_cifs, array_of_cifs, u32);
+    cif_basic!(cif1, array_of_cifs, array_of_cifs, ArrayOfCifs);
     cif_basic!(cif1, spectrum, spectrum, Spectrum);
-    // TODO: add basic support
-    cif_basic!(cif1, sector_scan, sector_scan, u32);
-    // TODO: add basic support
-    cif_basic!(cif1, index_list, index_list, u32);
+    cif_basic!(cif1, sector_scan, sector_scan, SectorScan);
+    cif_basic!(cif1, index_list, index_list, IndexList);
     cif_basic!(cif1, discrete_io_32, discrete_io_32, u32);
     cif_basic!(cif1, discrete_io_64, discrete_io_64, u64);
-    // TODO: add full support
-    cif_basic!(cif1, health_status, health_status, u32);
-    // TODO: add full support
-    cif_basic!(cif1, v49_spec_compliance, v49_spec_compliance, u32);
-    // TODO: add full support
-    cif_basic!(cif1, version_and_build_code, version_and_build_code, u32);
-    // TODO: add full support
-    cif_basic!(cif1, buffer_size, buffer_size, u64);
+    cif_basic!(cif1, health_status, health_status, HealthStatus);
+    cif_basic!(cif1, v49_spec_compliance, v49_spec_compliance, V49SpecCompliance);
+    cif_basic!(cif1, version_and_build_code, version_and_build_code, VersionBuildCode);
+    cif_basic!(cif1, buffer_size, buffer_size, BufferSize);
 }
 
 /// Shared trait for manipulating CIF1 ACK fields.
@@ -6,8 +6,16 @@ Defines fields and methods related to CIF2 (ANSI/VITA-49.2-2017 9.1).
 Fields here are compatible with VITA 49.2 and later.
 */
 
+use core::fmt;
+
 use crate::command_prelude::*;
-use crate::{ack_response::AckResponse, cif0::Cif0, cif7::Cif7Opts, Cif0AckFields};
+use crate::{
+    ack_response::AckResponse,
+    cif0::Cif0,
+    cif7::{Cif7Manipulators, Cif7Opts},
+    Cif0AckFields,
+};
+use crate::{CountryCode, EmsDeviceClass, Operator, PlatformDisplay};
 use deku::prelude::*;
 use vita49_macros::{ack_field, cif_basic, cif_field, cif_fields};
 
@@ -51,6 +59,16 @@ impl Cif2 {
     cif_field!(rf_footprint_range, 3);
     // Bits 0-2 are reserved
 
+    /// Get the CIF field as a raw u32.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Create a CIF from a raw u32.
+    pub(crate) fn from_u32(word: u32) -> Cif2 {
+        Cif2(word)
+    }
+
     fn empty(&self) -> bool {
         self.0 == 0
     }
@@ -70,13 +88,12 @@ pub struct Cif2Fields {
     controller_uuid: u128,
     info_source_id: u32,
     track_id: u32,
-    // TODO: add full support
-    country_code: u32,
-    operator: u32,
+    country_code: CountryCode,
+    operator: Operator,
     platform_class: u32,
     platform_instance: u32,
-    platform_display: u32,
-    ems_device_class: u32,
+    platform_display: PlatformDisplay,
+    ems_device_class: EmsDeviceClass,
     ems_device_type: u32,
     ems_device_instance: u32,
     modulation_class: u32,
@@ -126,7 +143,7 @@ pub struct Cif2AckFields {
 /// Trait for common CIF2 manipulation methods. Used by Context and
 /// Command packets.
 #[rustfmt::skip]
-pub trait Cif2Manipulators {
+pub trait Cif2Manipulators: Cif7Manipulators {
     /// Get a reference to the packet's CIF0 (indicators)
     fn cif0(&self) -> &Cif0;
     /// Get a mutable reference to the packet's CIF0 (indicators)
@@ -152,18 +169,25 @@ pub trait Cif2Manipulators {
     cif_basic!(cif2, controller_uuid, controller_uuid, u128);
     cif_basic!(cif2, info_source_id, info_source_id, u32);
     cif_basic!(cif2, track_id, track_id, u32);
+    cif_basic!(cif2, country_code, country_code, CountryCode);
+    cif_basic!(cif2, operator, operator, Operator);
     // TODO: add full support
-    cif_basic!(cif2, country_code, country_code, u32);
-    cif_basic!(cif2, operator, operator, u32);
     cif_basic!(cif2, platform_class, platform_class, u32);
+    // TODO: add full support
     cif_basic!(cif2, platform_instance, platform_instance, u32);
-    cif_basic!(cif2, platform_display, platform_display, u32);
-    cif_basic!(cif2, ems_device_class, ems_device_class, u32);
+    cif_basic!(cif2, platform_display, platform_display, PlatformDisplay);
+    cif_basic!(cif2, ems_device_class, ems_device_class, EmsDeviceClass);
+    // TODO: add full support
     cif_basic!(cif2, ems_device_type, ems_device_type, u32);
+    // TODO: add full support
     cif_basic!(cif2, ems_device_instance, ems_device_instance, u32);
+    // TODO: add full support
     cif_basic!(cif2, modulation_class, modulation_class, u32);
+    // TODO: add full support
     cif_basic!(cif2, modulation_type, modulation_type, u32);
+    // TODO: add full support
     cif_basic!(cif2, function_id, function_id, u32);
+    // TODO: add full support
     cif_basic!(cif2, mode_id, mode_id, u32);
     cif_basic!(cif2, event_id, event_id, u32);
     cif_basic!(cif2, function_priority_id, function_priority_id, u32);
@@ -243,6 +267,7 @@ pub trait Cif2AckManipulators {
 
 /// Enum to describe the various EMS device relationships.
 /// See ANSI/VITA-49.2-2017 section 9.8.9 for details.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum EmsOrganizationRelationship {
     /// Coalition device type.
     Coalition,
@@ -257,23 +282,53 @@ pub enum EmsOrganizationRelationship {
 impl Cif2Fields {
     /// Get the EMS device organization relationship.
     pub fn ems_device_class_org(&self) -> Option<EmsOrganizationRelationship> {
-        self.ems_device_class
-            .map(|ems_class| match (ems_class >> 14) & 0b11 {
-                0b00 => EmsOrganizationRelationship::Coalition,
-                0b01 => EmsOrganizationRelationship::Known,
-                0b10 => EmsOrganizationRelationship::Unknown,
-                0b11 => EmsOrganizationRelationship::Reserved,
-                _ => unreachable!(),
-            })
+        self.ems_device_class.map(|ems_class| ems_class.org())
     }
     /// Returns true if the device is an exciter, false if not.
     pub fn ems_device_is_exciter(&self) -> Option<bool> {
         self.ems_device_class
-            .map(|ems_class| (ems_class & (1 << 13)) > 0)
+            .map(|ems_class| ems_class.is_exciter())
     }
     /// Returns true if the device is a receiver, false if not.
     pub fn ems_device_is_receiver(&self) -> Option<bool> {
         self.ems_device_class
-            .map(|ems_class| (ems_class & (1 << 12)) > 0)
+            .map(|ems_class| ems_class.is_receiver())
+    }
+}
+
+impl fmt::Display for Cif2 {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "CIF2:")?;
+        writeln!(f, "  Bind: {}", self.bind())?;
+        writeln!(f, "  Cited SID: {}", self.cited_sid())?;
+        writeln!(f, "  Sibling SID: {}", self.sibling_sid())?;
+        writeln!(f, "  Parent SID: {}", self.parent_sid())?;
+        writeln!(f, "  Child SID: {}", self.child_sid())?;
+        writeln!(f, "  Cited message ID: {}", self.cited_message_id())?;
+        writeln!(f, "  Controllee ID: {}", self.controllee_id())?;
+        writeln!(f, "  Controllee UUID: {}", self.controllee_uuid())?;
+        writeln!(f, "  Controller ID: {}", self.controller_id())?;
+        writeln!(f, "  Controller UUID: {}", self.controller_uuid())?;
+        writeln!(f, "  Information source: {}", self.info_source_id())?;
+        writeln!(f, "  Track ID: {}", self.track_id())?;
+        writeln!(f, "  Country code: {}", self.country_code())?;
+        writeln!(f, "  Operator: {}", self.operator())?;
+        writeln!(f, "  Platform class: {}", self.platform_class())?;
+        writeln!(f, "  Platform instance: {}", self.platform_instance())?;
+        writeln!(f, "  Platform display: {}", self.platform_display())?;
+        writeln!(f, "  EMS device class: {}", self.ems_device_class())?;
+        writeln!(f, "  EMS device type: {}", self.ems_device_type())?;
+        writeln!(f, "  EMS device instance: {}", self.ems_device_instance())?;
+        writeln!(f, "  Modulation class: {}", self.modulation_class())?;
+        writeln!(f, "  Modulation type: {}", self.modulation_type())?;
+        writeln!(f, "  Function ID: {}", self.function_id())?;
+        writeln!(f, "  Mode ID: {}", self.mode_id())?;
+        writeln!(f, "  Event ID: {}", self.event_id())?;
+        writeln!(f, "  Function priority ID: {}", self.function_priority_id())?;
+        writeln!(f, "  Communication priority ID: {}", self.comms_priority_id())?;
+        writeln!(f, "  RF footprint: {}", self.rf_footprint())?;
+        writeln!(f, "  RF footprint range: {}", self.rf_footprint_range())?;
+        Ok(())
     }
 }
@@ -6,13 +6,21 @@ Defines fields and methods related to CIF3 (ANSI/VITA-49.2-2017 9.1).
 Fields here are compatible with VITA 49.2 and later.
 */
 
+use core::fmt;
+
 use crate::command_prelude::*;
-use crate::{ack_response::AckResponse, cif0::Cif0, cif7::Cif7Opts};
+use crate::{
+    ack_response::AckResponse,
+    cif0::Cif0,
+    cif7::{Cif7Manipulators, Cif7Opts},
+};
+use crate::{FieldTimestamp, TimeInterval, TimestampDetails};
 use deku::prelude::*;
-use fixed::{types::extra::U6, FixedI16};
-use vita49_macros::{
-    ack_field, cif_basic, cif_field, cif_fields, cif_radix_masked, todo_cif_field,
+use fixed::{
+    types::extra::{U4, U6, U7},
+    FixedI16, FixedU16,
 };
+use vita49_macros::{ack_field, cif_basic, cif_field, cif_fields, cif_radix_masked};
 
 /// Base data structure for the CIF3 single-bit indicators
 #[derive(
@@ -35,8 +43,8 @@ impl Cif3 {
     cif_field!(dwell, 21);
     cif_field!(jitter, 20);
     // Bits 18-19 are reserved
-    todo_cif_field!(age, 17, 3);
-    todo_cif_field!(shelf_life, 16, 3);
+    cif_field!(age, 17);
+    cif_field!(shelf_life, 16);
     // Bits 8-15 are reserved
     cif_field!(air_temp, 7);
     cif_field!(ground_temp, 6);
@@ -47,6 +55,16 @@ impl Cif3 {
     cif_field!(network_id, 1);
     // Bit 0 is reserved
 
+    /// Get the CIF field as a raw u32.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Create a CIF from a raw u32.
+    pub(crate) fn from_u32(word: u32) -> Cif3 {
+        Cif3(word)
+    }
+
     fn empty(&self) -> bool {
         self.0 == 0
     }
@@ -54,21 +72,18 @@ impl Cif3 {
 
 #[cif_fields(cif3)]
 pub struct Cif3Fields {
-    // TODO: add full support
-    timestamp_details: u64,
-    timestamp_skew: i64,
-    rise_time: i64,
-    fall_time: i64,
-    offset_time: i64,
-    pulse_width: i64,
-    period: i64,
-    duration: i64,
-    dwell: i64,
-    jitter: i64,
-    // TODO: add basic support
-    age: u32,
-    // TODO: add basic support
-    shelf_life: u32,
+    timestamp_details: TimestampDetails,
+    timestamp_skew: TimeInterval,
+    rise_time: TimeInterval,
+    fall_time: TimeInterval,
+    offset_time: TimeInterval,
+    pulse_width: TimeInterval,
+    period: TimeInterval,
+    duration: TimeInterval,
+    dwell: TimeInterval,
+    jitter: TimeInterval,
+    age: FieldTimestamp,
+    shelf_life: FieldTimestamp,
     air_temp: i32,
     ground_temp: i32,
     humidity: u32,
@@ -104,7 +119,7 @@ pub struct Cif3AckFields {
 /// Trait for common CIF3 manipulation methods. Used by Context and
 /// Command packets.
 #[rustfmt::skip]
-pub trait Cif3Manipulators {
+pub trait Cif3Manipulators: Cif7Manipulators {
     /// Get a reference to the packet's CIF0 (indicators)
     fn cif0(&self) -> &Cif0;
     /// Get a mutable reference to the packet's CIF0 (indicators)
@@ -118,27 +133,22 @@ pub trait Cif3Manipulators {
     /// Get a mutable reference to the packet's CIF3 data fields
     fn cif3_fields_mut(&mut self) -> &mut Option<Cif3Fields>;
 
-    // TODO: add full support
-    cif_basic!(cif3, timestamp_details, timestamp_details, u64);
-    cif_basic!(cif3, timestamp_skew, timestamp_skew, i64);
-    cif_basic!(cif3, rise_time, rise_time, i64);
-    cif_basic!(cif3, fall_time, fall_time, i64);
-    cif_basic!(cif3, offset_time, offset_time, i64);
-    cif_basic!(cif3, pulse_width, pulse_width, i64);
-    cif_basic!(cif3, period, period, i64);
-    cif_basic!(cif3, duration, duration, i64);
-    cif_basic!(cif3, dwell, dwell, i64);
-    cif_basic!(cif3, jitter, jitter, i64);
-    // TODO: add basic support
-    cif_basic!(cif3, age, age, u32);
-    // TODO: add basic support
-    cif_basic!(cif3, shelf_life, shelf_life, u32);
+    cif_basic!(cif3, timestamp_details, timestamp_details, TimestampDetails);
+    cif_basic!(cif3, timestamp_skew, timestamp_skew, TimeInterval);
+    cif_basic!(cif3, rise_time, rise_time, TimeInterval);
+    cif_basic!(cif3, fall_time, fall_time, TimeInterval);
+    cif_basic!(cif3, offset_time, offset_time, TimeInterval);
+    cif_basic!(cif3, pulse_width, pulse_width, TimeInterval);
+    cif_basic!(cif3, period, period, TimeInterval);
+    cif_basic!(cif3, duration, duration, TimeInterval);
+    cif_basic!(cif3, dwell, dwell, TimeInterval);
+    cif_basic!(cif3, jitter, jitter, TimeInterval);
+    cif_basic!(cif3, age, age, FieldTimestamp);
+    cif_basic!(cif3, shelf_life, shelf_life, FieldTimestamp);
     cif_radix_masked!(cif3, air_temp, air_temp_c, f32, FixedI16::<U6>, i32, i16);
     cif_radix_masked!(cif3, ground_temp, ground_temp_c, f32, FixedI16::<U6>, i32, i16);
-    // TODO: add full support
-    cif_basic!(cif3, humidity, humidity, u32);
-    // TODO: add full support
-    cif_basic!(cif3, barometric_pressure, barometric_pressure, u32);
+    cif_radix_masked!(cif3, humidity, humidity_pct, f32, FixedU16::<U7>, u32, u16, 0.0..=100.0);
+    cif_radix_masked!(cif3, barometric_pressure, barometric_pressure_mbar, f32, FixedU16::<U4>, u32, u16, 0.0..=FixedU16::<U4>::MAX.to_num());
     // TODO: add full support
     cif_basic!(cif3, sea_and_swell_state, sea_and_swell_state, u32);
     // TODO: add full support
@@ -204,3 +214,30 @@ pub trait Cif3AckManipulators {
     ack_field!(3, tropospheric_state);
     ack_field!(3, network_id);
 }
+
+impl fmt::Display for Cif3 {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "CIF3:")?;
+        writeln!(f, "  Timestamp details: {}", self.timestamp_details())?;
+        writeln!(f, "  Timestamp skew: {}", self.timestamp_skew())?;
+        writeln!(f, "  Rise time: {}", self.rise_time())?;
+        writeln!(f, "  Fall time: {}", self.fall_time())?;
+        writeln!(f, "  Offset time: {}", self.offset_time())?;
+        writeln!(f, "  Pulse width: {}", self.pulse_width())?;
+        writeln!(f, "  Period: {}", self.period())?;
+        writeln!(f, "  Duration: {}", self.duration())?;
+        writeln!(f, "  Dwell: {}", self.dwell())?;
+        writeln!(f, "  Jitter: {}", self.jitter())?;
+        writeln!(f, "  Age: {}", self.age())?;
+        writeln!(f, "  Shelf life: {}", self.shelf_life())?;
+        writeln!(f, "  Air temperature: {}", self.air_temp())?;
+        writeln!(f, "  Ground temperature: {}", self.ground_temp())?;
+        writeln!(f, "  Humidity: {}", self.humidity())?;
+        writeln!(f, "  Barometric pressure: {}", self.barometric_pressure())?;
+        writeln!(f, "  Sea and swell state: {}", self.sea_and_swell_state())?;
+        writeln!(f, "  Tropospheric state: {}", self.tropospheric_state())?;
+        writeln!(f, "  Network ID: {}", self.network_id())?;
+        Ok(())
+    }
+}
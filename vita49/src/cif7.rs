@@ -11,20 +11,118 @@ fields to all other CIF fields. For example, if you set the `current` and
 the current bandwidth value will be sent in the CIF0 fields followed immediately
 by the *average* bandwidth.
 
-This crate does not handle the math for the descriptive statistics below and
-additionally does not provide hooks out for each individual attribute field.
-Instead, each CIF field has an equivalent `*_attributes` field which is a vector
-of the main CIF field type.
+Each CIF field has an equivalent `*_attributes` field which is a vector
+of the main CIF field type, holding one entry per enabled attribute other
+than `current` (in CIF7 bit order, most significant bit first).
 
-So, a user wishing to use CIF7 would need to do some additional work to correlate
-the vector of values with the statistical fields in CIF7.
+Rather than correlating that vector with CIF7 by hand, each field also has
+typed `*_attr()`/`set_*_attr()` accessors taking a [`Cif7Attr`]. Setting an
+attribute enables it in CIF7 and keeps the attribute vectors of every other
+field aligned. Probability and belief attributes use their own formats (see
+[`Cif7Probability`] and [`Cif7Belief`]). To compute the statistics from
+observed samples, see `Cif7Accumulator`.
+
+```
+# #[cfg(feature = "cif7")]
+# fn main() -> Result<(), vita49::VitaError> {
+use vita49::prelude::*;
+use vita49::Cif7Attr;
+let mut packet = Vrt::new_context_packet();
+let context = packet.payload_mut().context_mut().unwrap();
+context.set_bandwidth_hz(Some(8e6));
+context.set_bandwidth_hz_attr(Cif7Attr::Max, 10e6)?;
+context.set_bandwidth_hz_attr(Cif7Attr::Average, 9e6)?;
+assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Current), Some(8e6));
+assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Average), Some(9e6));
+assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Max), Some(10e6));
+// Average (bit 30) comes before max (bit 27) in the attribute vector
+assert_eq!(context.bandwidth_hz_attributes(), vec![9e6, 10e6]);
+# Ok(())
+# }
+# #[cfg(not(feature = "cif7"))]
+# fn main() {}
+```
 
 See ANSI/VITA-49.2-2017 section 9.12 for additional details.
 */
 
+use core::fmt;
 use deku::prelude::*;
+use fixed::{types::extra::U8, FixedU8};
 use vita49_macros::cif_field;
 
+#[cfg(feature = "cif7")]
+use crate::Cif0;
+use crate::VitaError;
+
+/// A single CIF7 attribute (descriptive statistic).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cif7Attr {
+    /// Current value (the main CIF field).
+    Current,
+    /// Average value.
+    Average,
+    /// Median value.
+    Median,
+    /// Standard deviation.
+    StdDev,
+    /// Maximum value.
+    Max,
+    /// Minimum value.
+    Min,
+    /// Precision.
+    Precision,
+    /// Accuracy.
+    Accuracy,
+    /// First derivative.
+    FirstDerivative,
+    /// Second derivative.
+    SecondDerivative,
+    /// Third derivative.
+    ThirdDerivative,
+    /// Probability (see [`Cif7Probability`]).
+    Probability,
+    /// Belief (see [`Cif7Belief`]).
+    Belief,
+}
+
+impl Cif7Attr {
+    /// All attributes, in CIF7 bit order (most significant bit first).
+    pub const ALL: [Cif7Attr; 13] = [
+        Cif7Attr::Current,
+        Cif7Attr::Average,
+        Cif7Attr::Median,
+        Cif7Attr::StdDev,
+        Cif7Attr::Max,
+        Cif7Attr::Min,
+        Cif7Attr::Precision,
+        Cif7Attr::Accuracy,
+        Cif7Attr::FirstDerivative,
+        Cif7Attr::SecondDerivative,
+        Cif7Attr::ThirdDerivative,
+        Cif7Attr::Probability,
+        Cif7Attr::Belief,
+    ];
+
+    /// Gets the CIF7 bit position of the attribute.
+    pub fn bit(&self) -> u32 {
+        31 - Cif7Attr::ALL.iter().position(|a| a == self).unwrap() as u32
+    }
+
+    /// Returns true if the attribute holds a value in the field's own
+    /// format (i.e. it's neither probability nor belief).
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, Cif7Attr::Probability | Cif7Attr::Belief)
+    }
+}
+
+impl fmt::Display for Cif7Attr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{self:?}")
+    }
+}
+
 /// Base data structure for the CIF7 single-bit indicators.
 #[derive(
     Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
@@ -55,7 +153,263 @@ impl Cif7 {
     pub fn num_set(&self) -> usize {
         u32::count_ones(self.0) as usize
     }
+
+    /// Returns true if the given attribute is enabled.
+    pub fn is_set(&self, attr: Cif7Attr) -> bool {
+        self.0 & (1 << attr.bit()) != 0
+    }
+
+    /// Enables or disables the given attribute.
+    ///
+    /// Note: this only changes the indicator bit. To keep the attribute
+    /// vectors of a packet's fields aligned, use the packet's
+    /// `set_cif7_attr()` method instead.
+    pub fn set_attr(&mut self, attr: Cif7Attr, enabled: bool) {
+        if enabled {
+            self.0 |= 1 << attr.bit();
+        } else {
+            self.0 &= !(1 << attr.bit());
+        }
+    }
+
+    /// Gets every enabled attribute, in CIF7 bit order.
+    pub fn attrs(&self) -> Vec<Cif7Attr> {
+        Cif7Attr::ALL
+            .into_iter()
+            .filter(|a| self.is_set(*a))
+            .collect()
+    }
+
+    /// Gets the position of the given attribute's value in a field's
+    /// `*_attributes` vector. Returns `None` for [`Cif7Attr::Current`]
+    /// (which is held in the main field) or if the attribute isn't
+    /// enabled.
+    pub fn attr_index(&self, attr: Cif7Attr) -> Option<usize> {
+        if attr == Cif7Attr::Current || !self.is_set(attr) {
+            return None;
+        }
+        Some(self.slot_index(attr))
+    }
+
+    /// Number of enabled non-current attributes ahead of `attr`.
+    #[cfg_attr(not(feature = "cif7"), allow(dead_code))]
+    fn slot_index(&self, attr: Cif7Attr) -> usize {
+        let above = !((1u32 << attr.bit()) | ((1u32 << attr.bit()) - 1));
+        let current = 1 << Cif7Attr::Current.bit();
+        (self.0 & above & !current).count_ones() as usize
+    }
+
+    /// CIF7 with only the current value enabled (equivalent to not
+    /// using CIF7 at all).
+    #[cfg_attr(not(feature = "cif7"), allow(dead_code))]
+    fn current_only() -> Cif7 {
+        Cif7(1 << Cif7Attr::Current.bit())
+    }
+}
+
+/// Probability attribute format.
+///
+/// Bits 15-8 identify the probability function and bits 7-0 hold the
+/// probability in units of 1/256. The remaining bits are zero.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cif7Probability(u32);
+
+impl Cif7Probability {
+    /// Create a new probability attribute.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the probability isn't within
+    /// `0.0..1.0`.
+    pub fn new(function: u8, probability: f32) -> Result<Cif7Probability, VitaError> {
+        if !(0.0..1.0).contains(&probability) {
+            return Err(VitaError::OutOfRange);
+        }
+        let p = FixedU8::<U8>::checked_from_num(probability).ok_or(VitaError::OutOfRange)?;
+        Ok(Cif7Probability(
+            ((function as u32) << 8) | p.to_bits() as u32,
+        ))
+    }
+
+    /// Create a probability attribute from a raw attribute word.
+    pub fn from_u32(word: u32) -> Cif7Probability {
+        Cif7Probability(word)
+    }
+
+    /// Gets the raw attribute word.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the probability function code.
+    pub fn function(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Gets the probability (`0.0..1.0`).
+    pub fn probability(&self) -> f32 {
+        FixedU8::<U8>::from_bits(self.0 as u8).to_num()
+    }
+}
+
+/// Belief attribute format.
+///
+/// Bits 7-0 hold the belief in percent (0-100). The remaining bits are
+/// zero.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cif7Belief(u32);
+
+impl Cif7Belief {
+    /// Create a new belief attribute.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the belief is above 100%.
+    pub fn new(percent: u8) -> Result<Cif7Belief, VitaError> {
+        if percent > 100 {
+            return Err(VitaError::OutOfRange);
+        }
+        Ok(Cif7Belief(percent as u32))
+    }
+
+    /// Create a belief attribute from a raw attribute word.
+    pub fn from_u32(word: u32) -> Cif7Belief {
+        Cif7Belief(word)
+    }
+
+    /// Gets the raw attribute word.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the belief in percent.
+    pub fn percent(&self) -> u8 {
+        self.0 as u8
+    }
+}
+
+/// Operations on a CIF fields structure needed to keep its attribute
+/// vectors aligned with CIF7. Implemented by `cif_fields!`.
+#[cfg(feature = "cif7")]
+pub(crate) trait AttributeSlots {
+    /// Inserts a default-valued attribute at `index` for every field
+    /// that's present.
+    fn insert_attribute_slot(&mut self, index: usize);
+    /// Removes the attribute at `index` from every field.
+    fn remove_attribute_slot(&mut self, index: usize);
+    /// Adds (with a default value) or removes the main value of every
+    /// field that's present, for when the current attribute is toggled.
+    fn set_current_values(&mut self, enabled: bool);
+}
+
+/// Enables or disables a CIF7 attribute for a packet, inserting or
+/// removing the matching entry of every field's attribute vector (or
+/// the main values, for [`Cif7Attr::Current`]).
+#[cfg(feature = "cif7")]
+pub(crate) fn set_attr_enabled(
+    cif0: &mut Cif0,
+    cif7: &mut Option<Cif7>,
+    fields: Vec<&mut dyn AttributeSlots>,
+    attr: Cif7Attr,
+    enabled: bool,
+) {
+    let mut new = cif7.unwrap_or_else(Cif7::current_only);
+    if new.is_set(attr) == enabled {
+        return;
+    }
+    if attr == Cif7Attr::Current {
+        for f in fields {
+            f.set_current_values(enabled);
+        }
+    } else {
+        let index = new.slot_index(attr);
+        for f in fields {
+            if enabled {
+                f.insert_attribute_slot(index);
+            } else {
+                f.remove_attribute_slot(index);
+            }
+        }
+    }
+    new.set_attr(attr, enabled);
+    if new == Cif7::current_only() {
+        *cif7 = None;
+        cif0.unset_field_attributes_enabled();
+    } else {
+        *cif7 = Some(new);
+        cif0.set_field_attributes_enabled();
+    }
+}
+
+/// Trait for CIF7 manipulation methods. A supertrait of the CIF0-CIF3
+/// manipulator traits, whose typed `*_attr()` accessors build on it.
+///
+/// The default implementations are for types that can't carry CIF7
+/// (e.g. array of CIFs records), which only hold the current value.
+pub trait Cif7Manipulators {
+    /// Get a reference to the packet's CIF7 (attribute indicators)
+    fn cif7(&self) -> Option<&Cif7> {
+        None
+    }
+
+    /// Enable or disable a CIF7 attribute. Every field's attribute
+    /// vector gains or loses the matching entry (or, for
+    /// [`Cif7Attr::Current`], every field's main value is added or
+    /// removed), so the packet stays consistent.
+    ///
+    /// `update_packet_size()` should be executed after running this method.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::UnsupportedCif7Attr`] if the type can't carry
+    /// the attribute.
+    #[cfg(feature = "cif7")]
+    fn set_cif7_attr(&mut self, attr: Cif7Attr, enabled: bool) -> Result<(), VitaError> {
+        if enabled != (attr == Cif7Attr::Current) {
+            return Err(VitaError::UnsupportedCif7Attr);
+        }
+        Ok(())
+    }
+}
+
+/// Implements [`Cif7Manipulators`] for a payload holding `cif0`, `cif7`,
+/// and the CIF0-CIF3 data fields.
+macro_rules! cif7_manipulators {
+    ($payload:ty) => {
+        impl crate::cif7::Cif7Manipulators for $payload {
+            fn cif7(&self) -> Option<&crate::Cif7> {
+                self.cif7.as_ref()
+            }
+
+            #[cfg(feature = "cif7")]
+            fn set_cif7_attr(
+                &mut self,
+                attr: crate::Cif7Attr,
+                enabled: bool,
+            ) -> Result<(), crate::VitaError> {
+                use crate::cif7::AttributeSlots;
+                let mut fields: Vec<&mut dyn AttributeSlots> = vec![&mut self.cif0_fields];
+                if let Some(f) = self.cif1_fields.as_mut() {
+                    fields.push(f);
+                }
+                if let Some(f) = self.cif2_fields.as_mut() {
+                    fields.push(f);
+                }
+                if let Some(f) = self.cif3_fields.as_mut() {
+                    fields.push(f);
+                }
+                crate::cif7::set_attr_enabled(
+                    &mut self.cif0,
+                    &mut self.cif7,
+                    fields,
+                    attr,
+                    enabled,
+                );
+                Ok(())
+            }
+        }
+    };
 }
+pub(crate) use cif7_manipulators;
 
 /// Structure representing the state of CI7.
 #[derive(
@@ -79,7 +433,7 @@ impl Cif7Opts {
         if let Some(c) = cif7 {
             Cif7Opts {
                 current_val: c.current(),
-                num_extra_attrs: if c.num_set() > 0 { c.num_set() - 1 } else { 0 },
+                num_extra_attrs: c.num_set() - c.current() as usize,
             }
         } else {
             Cif7Opts {
@@ -0,0 +1,260 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Descriptive statistics for filling CIF7 attributes from observed
+samples (ANSI/VITA-49.2-2017 section 9.12).
+
+A [`Cif7Accumulator`] gathers timestamped values of a single field over
+a sliding time window and computes the statistics CIF7 can carry. The
+results are written into a packet through the field's typed
+`set_*_attr()` accessor.
+
+Derivatives are computed from finite differences of consecutive
+samples and are given in units per second.
+*/
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::{Cif7Attr, VitaError};
+
+/// Sliding-window accumulator for the samples of one CIF field.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Cif7Accumulator {
+    window: Duration,
+    /// Samples (time, value) sorted by time.
+    samples: VecDeque<(Duration, f64)>,
+}
+
+impl Cif7Accumulator {
+    /// Create a new accumulator keeping samples for the given time window
+    /// (relative to the newest sample).
+    pub fn new(window: Duration) -> Cif7Accumulator {
+        Cif7Accumulator {
+            window,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Gets the time window.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Adds a sample observed at time `at` (measured from any fixed
+    /// reference, e.g. the UNIX epoch). Samples may arrive out of order;
+    /// samples older than the window are dropped.
+    pub fn push(&mut self, at: Duration, value: f64) {
+        let index = self.samples.partition_point(|(t, _)| *t <= at);
+        self.samples.insert(index, (at, value));
+        let newest = self.samples.back().unwrap().0;
+        let oldest = newest.saturating_sub(self.window);
+        while self.samples.front().is_some_and(|(t, _)| *t < oldest) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Gets the number of samples in the window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns true if the window holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Drops every sample.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Gets the newest value.
+    pub fn current(&self) -> Option<f64> {
+        self.samples.back().map(|(_, v)| *v)
+    }
+
+    /// Gets the mean value.
+    pub fn average(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.values().sum::<f64>() / self.len() as f64)
+    }
+
+    /// Gets the median value.
+    pub fn median(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = self.values().collect();
+        sorted.sort_by(f64::total_cmp);
+        let mid = sorted.len() / 2;
+        if sorted.len() % 2 == 0 {
+            Some((sorted[mid - 1] + sorted[mid]) / 2.0)
+        } else {
+            Some(sorted[mid])
+        }
+    }
+
+    /// Gets the (population) standard deviation.
+    pub fn std_dev(&self) -> Option<f64> {
+        let mean = self.average()?;
+        let variance =
+            self.values().map(|v| (v - mean) * (v - mean)).sum::<f64>() / self.len() as f64;
+        Some(variance.sqrt())
+    }
+
+    /// Gets the minimum value.
+    pub fn min(&self) -> Option<f64> {
+        self.values().reduce(f64::min)
+    }
+
+    /// Gets the maximum value.
+    pub fn max(&self) -> Option<f64> {
+        self.values().reduce(f64::max)
+    }
+
+    /// Gets the newest `order`-th derivative (in units per second^order).
+    /// Returns `None` unless the window holds at least `order + 1`
+    /// samples at distinct times.
+    pub fn derivative(&self, order: usize) -> Option<f64> {
+        let mut series: Vec<(f64, f64)> = self
+            .samples
+            .iter()
+            .map(|(t, v)| (t.as_secs_f64(), *v))
+            .collect();
+        for _ in 0..order {
+            series = series
+                .windows(2)
+                .filter(|w| w[1].0 > w[0].0)
+                .map(|w| {
+                    (
+                        (w[0].0 + w[1].0) / 2.0,
+                        (w[1].1 - w[0].1) / (w[1].0 - w[0].0),
+                    )
+                })
+                .collect();
+        }
+        series.last().map(|(_, v)| *v)
+    }
+
+    /// Gets the statistic for the given CIF7 attribute. Returns `None`
+    /// if there aren't enough samples or if the attribute can't be
+    /// derived from samples (precision, accuracy, probability, and
+    /// belief).
+    pub fn stat(&self, attr: Cif7Attr) -> Option<f64> {
+        match attr {
+            Cif7Attr::Current => self.current(),
+            Cif7Attr::Average => self.average(),
+            Cif7Attr::Median => self.median(),
+            Cif7Attr::StdDev => self.std_dev(),
+            Cif7Attr::Max => self.max(),
+            Cif7Attr::Min => self.min(),
+            Cif7Attr::FirstDerivative => self.derivative(1),
+            Cif7Attr::SecondDerivative => self.derivative(2),
+            Cif7Attr::ThirdDerivative => self.derivative(3),
+            Cif7Attr::Precision | Cif7Attr::Accuracy | Cif7Attr::Probability | Cif7Attr::Belief => {
+                None
+            }
+        }
+    }
+
+    /// Computes the given attributes and passes each one to `set`
+    /// (typically a field's `set_*_attr()` accessor). Attributes without
+    /// enough samples yet are skipped.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::UnsupportedCif7Attr`] if an attribute can't
+    /// be derived from samples, or any error returned by `set`.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::Cif7Accumulator;
+    /// use std::time::Duration;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut temperature = Cif7Accumulator::new(Duration::from_secs(60));
+    /// for (secs, temp) in [(0, 20.0), (10, 22.0), (20, 21.0), (30, 25.0)] {
+    ///     temperature.push(Duration::from_secs(secs), temp);
+    /// }
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// temperature.apply(
+    ///     &[Cif7Attr::Current, Cif7Attr::Average, Cif7Attr::Max],
+    ///     |attr, v| context.set_temperature_c_attr(attr, v as f32),
+    /// )?;
+    /// packet.update_packet_size();
+    ///
+    /// let context = packet.payload().context().unwrap();
+    /// assert_eq!(context.temperature_c(), Some(25.0));
+    /// assert_eq!(context.temperature_c_attr(Cif7Attr::Average), Some(22.0));
+    /// assert_eq!(context.temperature_c_attr(Cif7Attr::Max), Some(25.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply<F>(&self, attrs: &[Cif7Attr], mut set: F) -> Result<(), VitaError>
+    where
+        F: FnMut(Cif7Attr, f64) -> Result<(), VitaError>,
+    {
+        for attr in attrs {
+            if matches!(
+                attr,
+                Cif7Attr::Precision | Cif7Attr::Accuracy | Cif7Attr::Probability | Cif7Attr::Belief
+            ) {
+                return Err(VitaError::UnsupportedCif7Attr);
+            }
+            if let Some(v) = self.stat(*attr) {
+                set(*attr, v)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.samples.iter().map(|(_, v)| *v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let mut acc = Cif7Accumulator::new(Duration::from_secs(3));
+        assert_eq!(acc.stat(Cif7Attr::Average), None);
+        // v = t^2, pushed out of order
+        for t in [1, 0, 2, 3, 4] {
+            acc.push(Duration::from_secs(t), (t * t) as f64);
+        }
+        // t=0 falls out of the window
+        assert_eq!(acc.len(), 4);
+        assert_eq!(acc.current(), Some(16.0));
+        assert_eq!(acc.average(), Some(7.5));
+        assert_eq!(acc.median(), Some(6.5));
+        assert_eq!(acc.min(), Some(1.0));
+        assert_eq!(acc.max(), Some(16.0));
+        assert!((acc.std_dev().unwrap() - 5.678908345800274).abs() < 1e-12);
+        assert_eq!(acc.derivative(1), Some(7.0));
+        assert_eq!(acc.derivative(2), Some(2.0));
+        assert_eq!(acc.derivative(3), Some(0.0));
+        assert_eq!(acc.stat(Cif7Attr::Precision), None);
+        assert!(matches!(
+            acc.apply(&[Cif7Attr::Belief], |_, _| Ok(())),
+            Err(VitaError::UnsupportedCif7Attr)
+        ));
+
+        acc.clear();
+        acc.push(Duration::from_secs(10), 1.0);
+        let mut applied = Vec::new();
+        acc.apply(&[Cif7Attr::Current, Cif7Attr::FirstDerivative], |a, v| {
+            applied.push((a, v));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(applied, vec![(Cif7Attr::Current, 1.0)]);
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Conversions between the payloads sharing the full CIF layout.
+
+[`Context`], [`Control`], and [`QueryAck`] payloads all carry the same
+CIF0-CIF3 (and CIF7) indicators and data fields, so each converts into
+the others without loss:
+
+- a query ACK can be re-emitted as a context packet
+- a context can become a control payload reproducing a recorded
+  configuration
+
+A [`Cancellation`] carries only the indicators, so it can be built from
+any of them (e.g. to cancel exactly the fields a control packet set).
+
+```
+use vita49::prelude::*;
+use vita49::{Cancellation, Control, QueryAck};
+let mut context = Context::new();
+context.set_bandwidth_hz(Some(8e6));
+context.set_network_id(Some(4));
+
+let control = Control::from(context.clone());
+assert_eq!(control.bandwidth_hz(), Some(8e6));
+let query_ack = QueryAck::from(control.clone());
+assert_eq!(Context::from(query_ack), context);
+
+let cancellation = Cancellation::from(&control);
+assert!(cancellation.cif0().bandwidth());
+assert!(cancellation.cif3().unwrap().network_id());
+```
+*/
+
+use crate::cif0::{Cif0, Cif0Fields, Cif0Manipulators};
+use crate::cif1::{Cif1, Cif1Fields, Cif1Manipulators};
+use crate::cif2::{Cif2, Cif2Fields, Cif2Manipulators};
+use crate::cif3::{Cif3, Cif3Fields, Cif3Manipulators};
+use crate::cif7::Cif7;
+use crate::{Cancellation, Context, Control, QueryAck};
+
+pub(crate) mod sealed {
+    use super::*;
+
+    /// The CIF indicators and data fields of a payload, used to move them
+    /// between payload types.
+    #[derive(Clone, PartialEq, Debug, Default)]
+    pub struct CifParts {
+        pub(crate) cif0: Cif0,
+        pub(crate) cif1: Option<Cif1>,
+        pub(crate) cif2: Option<Cif2>,
+        pub(crate) cif3: Option<Cif3>,
+        pub(crate) cif7: Option<Cif7>,
+        pub(crate) cif0_fields: Cif0Fields,
+        pub(crate) cif1_fields: Option<Cif1Fields>,
+        pub(crate) cif2_fields: Option<Cif2Fields>,
+        pub(crate) cif3_fields: Option<Cif3Fields>,
+    }
+
+    /// Keeps [`CifPayload`] from being implemented outside the crate.
+    pub trait Sealed: Sized {
+        /// Break the payload into its CIF indicators and data fields.
+        fn into_parts(self) -> CifParts;
+
+        /// Build the payload from CIF indicators and data fields.
+        fn from_parts(parts: CifParts) -> Self;
+    }
+}
+
+/// Payloads carrying the full set of CIF indicators and data fields
+/// ([`Context`], [`Control`], and [`QueryAck`]). This trait is sealed.
+pub trait CifPayload: sealed::Sealed {
+    /// Convert into another payload type with the same CIF contents.
+    fn convert<T: CifPayload>(self) -> T {
+        T::from_parts(self.into_parts())
+    }
+}
+
+/// Implements [`CifPayload`] for a payload holding the CIF0-CIF3 (and
+/// CIF7) indicators and data fields.
+macro_rules! cif_payload {
+    ($payload:ident) => {
+        impl crate::cif_payload::sealed::Sealed for $payload {
+            fn into_parts(self) -> crate::cif_payload::sealed::CifParts {
+                crate::cif_payload::sealed::CifParts {
+                    cif0: self.cif0,
+                    cif1: self.cif1,
+                    cif2: self.cif2,
+                    cif3: self.cif3,
+                    cif7: self.cif7,
+                    cif0_fields: self.cif0_fields,
+                    cif1_fields: self.cif1_fields,
+                    cif2_fields: self.cif2_fields,
+                    cif3_fields: self.cif3_fields,
+                }
+            }
+
+            fn from_parts(parts: crate::cif_payload::sealed::CifParts) -> Self {
+                $payload {
+                    cif0: parts.cif0,
+                    cif1: parts.cif1,
+                    cif2: parts.cif2,
+                    cif3: parts.cif3,
+                    cif7: parts.cif7,
+                    cif0_fields: parts.cif0_fields,
+                    cif1_fields: parts.cif1_fields,
+                    cif2_fields: parts.cif2_fields,
+                    cif3_fields: parts.cif3_fields,
+                }
+            }
+        }
+
+        impl crate::cif_payload::CifPayload for $payload {}
+    };
+}
+pub(crate) use cif_payload;
+
+macro_rules! cif_payload_from {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl From<$from> for $to {
+                fn from(value: $from) -> Self {
+                    value.convert()
+                }
+            }
+        )*
+    };
+}
+
+cif_payload_from! {
+    Context => Control,
+    Context => QueryAck,
+    Control => Context,
+    Control => QueryAck,
+    QueryAck => Context,
+    QueryAck => Control,
+}
+
+impl<T> From<&T> for Cancellation
+where
+    T: Cif0Manipulators + Cif1Manipulators + Cif2Manipulators + Cif3Manipulators,
+{
+    fn from(value: &T) -> Self {
+        let mut cif0 = *Cif0Manipulators::cif0(value);
+        // Cancellations carry no field attributes
+        cif0.unset_field_attributes_enabled();
+        Cancellation::from_indicators(
+            cif0,
+            Cif1Manipulators::cif1(value).copied(),
+            Cif2Manipulators::cif2(value).copied(),
+            Cif3Manipulators::cif3(value).copied(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{CifField, CifFieldAccess, CommandPayload};
+
+    fn sample_context() -> Context {
+        let mut context = Context::new();
+        context.set_bandwidth_hz(Some(8e6));
+        context.set_range_m(Some(5.0));
+        context.set_bind(Some(2));
+        context.set_network_id(Some(3));
+        #[cfg(feature = "cif7")]
+        context.set_bandwidth_hz_attr(Cif7Attr::Max, 10e6).unwrap();
+        context
+    }
+
+    #[test]
+    fn conversions_keep_every_cif() {
+        let context = sample_context();
+        let control = Control::from(context.clone());
+        let query_ack = QueryAck::from(control.clone());
+        assert!(control.present_fields().eq(context.present_fields()));
+        assert_eq!(query_ack.bind(), Some(&2));
+        assert_eq!(query_ack.network_id(), Some(&3));
+        #[cfg(feature = "cif7")]
+        assert_eq!(query_ack.bandwidth_hz_attr(Cif7Attr::Max), Some(10e6));
+
+        assert_eq!(Context::from(query_ack.clone()), context);
+        assert_eq!(Control::from(query_ack), control);
+        assert_eq!(
+            QueryAck::from(context.clone()).convert::<Context>(),
+            context
+        );
+        assert_eq!(Context::new().convert::<Control>(), Control::default());
+    }
+
+    #[test]
+    fn cancellation_from_payload() {
+        let context = sample_context();
+        let cancellation = Cancellation::from(&context);
+        assert_eq!(
+            cancellation.present_fields().collect::<Vec<_>>(),
+            vec![
+                CifField::Bandwidth,
+                CifField::Range,
+                CifField::Bind,
+                CifField::NetworkId
+            ]
+        );
+        // Cancellations carry no field attributes
+        assert!(!cancellation.cif0().field_attributes_enabled());
+        assert_eq!(Cancellation::from(&Control::from(context)), cancellation);
+        assert_eq!(
+            Cancellation::from(&QueryAck::default()),
+            Cancellation::default()
+        );
+
+        let mut packet = Vrt::new_cancellation_packet();
+        let command = packet.payload_mut().command_mut().unwrap();
+        *command.payload_mut() = CommandPayload::Cancellation(cancellation.clone());
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        let command = parsed.payload().command().unwrap();
+        assert_eq!(command.payload().cancellation().unwrap(), &cancellation);
+    }
+}
@@ -20,6 +20,15 @@ pub struct ClassIdentifier {
 }
 
 impl ClassIdentifier {
+    /// Builds a class identifier from its two raw 32-bit words.
+    pub(crate) fn from_words(word_1: u32, word_2: u32) -> ClassIdentifier {
+        ClassIdentifier {
+            word_1,
+            information_class_code: (word_2 >> 16) as u16,
+            packet_class_code: (word_2 & 0xFFFF) as u16,
+        }
+    }
+
     /// Gets the number of padding bits.
     /// # Example
     /// ```
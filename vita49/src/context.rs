@@ -108,6 +108,10 @@ impl TryFrom<Payload> for Context {
     }
 }
 
+crate::cif_payload::cif_payload!(Context);
+
+crate::cif7::cif7_manipulators!(Context);
+
 impl Cif0Manipulators for Context {
     fn cif0(&self) -> &Cif0 {
         &self.cif0
@@ -245,4 +249,129 @@ mod tests {
         assert_eq!(context.spectrum().unwrap().num_transform_points(), 1280);
         assert_eq!(context.spectrum().unwrap().f1_index(), -640);
     }
+
+    #[test]
+    fn temperature_range_checked() {
+        use crate::prelude::*;
+        let mut context = Context::new();
+        context.set_temperature_c(Some(-40.25)).unwrap();
+        assert_eq!(context.temperature_c(), Some(-40.25));
+        assert!(context.cif0.temperature());
+        assert!(matches!(
+            context.set_temperature_c(Some(-300.0)),
+            Err(VitaError::OutOfRange)
+        ));
+        assert!(matches!(
+            context.set_temperature_c(Some(512.0)),
+            Err(VitaError::OutOfRange)
+        ));
+        // Failed sets leave the previous value in place
+        assert_eq!(context.temperature_c(), Some(-40.25));
+        context.set_temperature_c(None).unwrap();
+        assert!(!context.cif0.temperature());
+    }
+
+    #[test]
+    fn phase_offset_range_checked() {
+        use crate::prelude::*;
+        let mut context = Context::new();
+        context.set_phase_offset_radians(Some(-1.5)).unwrap();
+        assert_eq!(context.phase_offset_radians(), Some(-1.5));
+        assert!(matches!(
+            context.set_phase_offset_radians(Some(4.0)),
+            Err(VitaError::OutOfRange)
+        ));
+        assert_eq!(context.phase_offset_radians(), Some(-1.5));
+    }
+
+    #[test]
+    fn environmental_fields() {
+        use crate::prelude::*;
+        let mut context = Context::new();
+        context.set_humidity_pct(Some(45.5)).unwrap();
+        context.set_barometric_pressure_mbar(Some(1013.25)).unwrap();
+        assert_eq!(context.humidity_pct(), Some(45.5));
+        assert_eq!(context.barometric_pressure_mbar(), Some(1013.25));
+        assert!(context.set_humidity_pct(Some(100.5)).is_err());
+        assert!(context.set_barometric_pressure_mbar(Some(-1.0)).is_err());
+        assert!(context.set_barometric_pressure_mbar(Some(4096.0)).is_err());
+        // Failed sets leave the previous value in place
+        assert_eq!(context.humidity_pct(), Some(45.5));
+        assert_eq!(context.barometric_pressure_mbar(), Some(1013.25));
+        context.set_humidity_pct(Some(100.0)).unwrap();
+        assert_eq!(context.humidity_pct(), Some(100.0));
+    }
+
+    #[test]
+    fn environmental_fields_wire_round_trip() {
+        use crate::prelude::*;
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_humidity_pct(Some(45.5)).unwrap();
+        context.set_barometric_pressure_mbar(Some(1013.25)).unwrap();
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+        // Humidity: 45.5 * 2^7, pressure: 1013.25 * 2^4, both in the
+        // lower 16 bits of their words
+        assert!(bytes.ends_with(&[0x00, 0x00, 0x16, 0xC0, 0x00, 0x00, 0x3F, 0x54]));
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        let context = parsed.payload().context().unwrap();
+        assert_eq!(context.humidity_pct(), Some(45.5));
+        assert_eq!(context.barometric_pressure_mbar(), Some(1013.25));
+    }
+
+    #[cfg(feature = "cif7")]
+    #[test]
+    fn cif7_attributes() {
+        use crate::prelude::*;
+        use crate::Cif7Probability;
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_bandwidth_hz(Some(8e6));
+        context.set_range_m(Some(100.0));
+
+        // Enabling an attribute on one field keeps every field aligned
+        context.set_bandwidth_hz_attr(Cif7Attr::Max, 10e6).unwrap();
+        context.set_bandwidth_hz_attr(Cif7Attr::Min, 1e6).unwrap();
+        context
+            .set_bandwidth_hz_probability(Cif7Probability::new(1, 0.5).unwrap())
+            .unwrap();
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Current), Some(8e6));
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Max), Some(10e6));
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Min), Some(1e6));
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Average), None);
+        assert_eq!(context.bandwidth_hz_attributes().len(), 3);
+        assert_eq!(context.range_m_attributes(), vec![0.0; 3]);
+        assert!(matches!(
+            context.set_bandwidth_hz_attr(Cif7Attr::Probability, 1.0),
+            Err(VitaError::UnsupportedCif7Attr)
+        ));
+        assert!(matches!(
+            context.set_phase_offset_radians_attr(Cif7Attr::Max, 4.0),
+            Err(VitaError::OutOfRange)
+        ));
+
+        packet.update_packet_size();
+        let mut parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+        let context = parsed.payload_mut().context_mut().unwrap();
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Max), Some(10e6));
+        let probability = context.bandwidth_hz_probability().unwrap();
+        assert_eq!(probability.function(), 1);
+        assert_eq!(probability.probability(), 0.5);
+
+        // Disabling attributes drops their entries everywhere
+        context.set_cif7_attr(Cif7Attr::Max, false).unwrap();
+        context.set_cif7_attr(Cif7Attr::Current, false).unwrap();
+        assert_eq!(context.bandwidth_hz(), None);
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Max), None);
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Min), Some(1e6));
+        assert_eq!(context.range_m_attributes().len(), 2);
+
+        parsed.update_packet_size();
+        let reparsed = Vrt::try_from(&parsed.to_bytes().unwrap()[..]).unwrap();
+        let context = reparsed.payload().context().unwrap();
+        assert_eq!(context.bandwidth_hz(), None);
+        assert_eq!(context.bandwidth_hz_attr(Cif7Attr::Min), Some(1e6));
+        assert_eq!(context.range_m_attr(Cif7Attr::Min), Some(0.0));
+    }
 }
@@ -4,10 +4,25 @@
 /*!
 Data structures and methods related to context association lists
 (ANSI/VITA-49.2-2017 section 9.13.2).
+
+Each list holds the stream IDs of other streams that are associated
+with the stream carrying the field. The list sizes are carried in the
+first two words of the field, so the setters here keep them in sync
+with the lists themselves.
 */
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Cif0Manipulators, VitaError, Vrt};
 use deku::prelude::*;
 
+/// Maximum number of source or system list entries (9-bit count).
+const MAX_SOURCE_SYSTEM_ENTRIES: usize = 0x1FF;
+/// Maximum number of vector component list entries (16-bit count).
+const MAX_VECTOR_COMPONENT_ENTRIES: usize = 0xFFFF;
+/// Maximum number of asynchronous channel list entries (15-bit count).
+const MAX_ASYNC_CHANNEL_ENTRIES: usize = 0x7FFF;
+
 /// Base context association lists structure.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
@@ -15,19 +30,56 @@ use deku::prelude::*;
 pub struct ContextAssociationLists {
     w1: u32,
     w2: u32,
-    #[deku(count = "((w1 >> 16) & 0x3FF)")]
+    #[deku(count = "((w1 >> 16) & 0x1FF)")]
     source_list: Vec<u32>,
-    #[deku(count = "(w1 & 0x3FF)")]
+    #[deku(count = "(w1 & 0x1FF)")]
     system_list: Vec<u32>,
     #[deku(count = "(w2 >> 16)")]
     vector_component_list: Vec<u32>,
-    #[deku(count = "(w2 & 0x1FF)")]
+    #[deku(count = "(w2 & 0x7FFF)")]
     async_channel_list: Vec<u32>,
-    #[deku(cond = "(w2 & (1 << 15) > 1)", count = "(w2 & 0x1FF)")]
+    #[deku(cond = "(w2 & (1 << 15) > 1)", count = "(w2 & 0x7FFF)")]
     async_channel_tag_list: Vec<u32>,
 }
 
 impl ContextAssociationLists {
+    /// Create a new, empty set of context association lists.
+    pub fn new() -> ContextAssociationLists {
+        ContextAssociationLists::default()
+    }
+
+    /// Create a builder for context association lists.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::ContextAssociationLists;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let lists = ContextAssociationLists::builder()
+    ///     .source_list(vec![0x10, 0x11])
+    ///     .system_list(vec![0x20])
+    ///     .async_channel_list(vec![0x30, 0x31], Some(vec![7, 8]))
+    ///     .build()?;
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_context_association_lists(Some(lists));
+    /// packet.update_packet_size();
+    ///
+    /// let parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+    /// let lists = parsed.payload().context().unwrap().context_association_lists().unwrap();
+    /// assert_eq!(lists.source_list(), &[0x10, 0x11]);
+    /// assert_eq!(lists.system_list(), &[0x20]);
+    /// assert!(lists.vector_component_list().is_empty());
+    /// assert_eq!(lists.async_channel_list(), &[0x30, 0x31]);
+    /// assert_eq!(lists.async_channel_tag_list(), Some(&[7, 8][..]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> ContextAssociationListsBuilder {
+        ContextAssociationListsBuilder::default()
+    }
+
     /// Get the size of the lists in 32-bit words.
     pub fn size_words(&self) -> u16 {
         // Start with the 2 top words
@@ -39,4 +91,453 @@ impl ContextAssociationLists {
         ret += self.async_channel_tag_list.len();
         ret as u16
     }
+
+    /// Gets the source context association list (stream IDs).
+    pub fn source_list(&self) -> &[u32] {
+        &self.source_list
+    }
+
+    /// Sets the source context association list (stream IDs).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the list has more than 511 entries.
+    pub fn set_source_list(&mut self, list: Vec<u32>) -> Result<(), VitaError> {
+        if list.len() > MAX_SOURCE_SYSTEM_ENTRIES {
+            return Err(VitaError::OutOfRange);
+        }
+        self.w1 = self.w1 & !(0x1FF << 16) | ((list.len() as u32) << 16);
+        self.source_list = list;
+        Ok(())
+    }
+
+    /// Gets the system context association list (stream IDs).
+    pub fn system_list(&self) -> &[u32] {
+        &self.system_list
+    }
+
+    /// Sets the system context association list (stream IDs).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the list has more than 511 entries.
+    pub fn set_system_list(&mut self, list: Vec<u32>) -> Result<(), VitaError> {
+        if list.len() > MAX_SOURCE_SYSTEM_ENTRIES {
+            return Err(VitaError::OutOfRange);
+        }
+        self.w1 = self.w1 & !0x1FF | list.len() as u32;
+        self.system_list = list;
+        Ok(())
+    }
+
+    /// Gets the vector-component context association list (stream IDs).
+    pub fn vector_component_list(&self) -> &[u32] {
+        &self.vector_component_list
+    }
+
+    /// Sets the vector-component context association list (stream IDs).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the list has more than 65535 entries.
+    pub fn set_vector_component_list(&mut self, list: Vec<u32>) -> Result<(), VitaError> {
+        if list.len() > MAX_VECTOR_COMPONENT_ENTRIES {
+            return Err(VitaError::OutOfRange);
+        }
+        self.w2 = self.w2 & 0xFFFF | ((list.len() as u32) << 16);
+        self.vector_component_list = list;
+        Ok(())
+    }
+
+    /// Gets the asynchronous-channel context association list (stream IDs).
+    pub fn async_channel_list(&self) -> &[u32] {
+        &self.async_channel_list
+    }
+
+    /// Gets the asynchronous-channel tag list, if included. When present,
+    /// each tag corresponds to the entry at the same index in the
+    /// asynchronous-channel list.
+    pub fn async_channel_tag_list(&self) -> Option<&[u32]> {
+        if self.w2 & (1 << 15) > 0 {
+            Some(&self.async_channel_tag_list)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the asynchronous-channel context association list (stream IDs)
+    /// along with an optional tag list.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the list has more than 32767
+    /// entries or if the tag list isn't the same length as the channel list.
+    pub fn set_async_channel_list(
+        &mut self,
+        list: Vec<u32>,
+        tags: Option<Vec<u32>>,
+    ) -> Result<(), VitaError> {
+        if list.len() > MAX_ASYNC_CHANNEL_ENTRIES
+            || tags.as_ref().is_some_and(|t| t.len() != list.len())
+        {
+            return Err(VitaError::OutOfRange);
+        }
+        self.w2 = self.w2 & !0xFFFF | ((tags.is_some() as u32) << 15) | list.len() as u32;
+        self.async_channel_list = list;
+        self.async_channel_tag_list = tags.unwrap_or_default();
+        Ok(())
+    }
+}
+
+/// Builder for [`ContextAssociationLists`].
+#[derive(Clone, Debug, Default)]
+pub struct ContextAssociationListsBuilder {
+    source_list: Vec<u32>,
+    system_list: Vec<u32>,
+    vector_component_list: Vec<u32>,
+    async_channel_list: Vec<u32>,
+    async_channel_tag_list: Option<Vec<u32>>,
+}
+
+impl ContextAssociationListsBuilder {
+    /// Sets the source context association list (stream IDs).
+    pub fn source_list(mut self, list: Vec<u32>) -> Self {
+        self.source_list = list;
+        self
+    }
+    /// Sets the system context association list (stream IDs).
+    pub fn system_list(mut self, list: Vec<u32>) -> Self {
+        self.system_list = list;
+        self
+    }
+    /// Sets the vector-component context association list (stream IDs).
+    pub fn vector_component_list(mut self, list: Vec<u32>) -> Self {
+        self.vector_component_list = list;
+        self
+    }
+    /// Sets the asynchronous-channel context association list (stream IDs)
+    /// along with an optional tag list.
+    pub fn async_channel_list(mut self, list: Vec<u32>, tags: Option<Vec<u32>>) -> Self {
+        self.async_channel_list = list;
+        self.async_channel_tag_list = tags;
+        self
+    }
+    /// Builds the lists.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if any list is too long for its
+    /// count field or the tag list length doesn't match the channel list.
+    pub fn build(self) -> Result<ContextAssociationLists, VitaError> {
+        let mut ret = ContextAssociationLists::new();
+        ret.set_source_list(self.source_list)?;
+        ret.set_system_list(self.system_list)?;
+        ret.set_vector_component_list(self.vector_component_list)?;
+        ret.set_async_channel_list(self.async_channel_list, self.async_channel_tag_list)?;
+        Ok(ret)
+    }
+}
+
+/// Kind of association between two streams.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssociationKind {
+    /// Source context association.
+    Source,
+    /// System context association.
+    System,
+    /// Vector-component context association.
+    VectorComponent,
+    /// Asynchronous-channel context association.
+    AsyncChannel,
+}
+
+/// A single directed association from one stream to another.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Association {
+    /// Stream ID of the context packet carrying the association lists.
+    pub from: u32,
+    /// Associated stream ID.
+    pub to: u32,
+    /// Which list the association came from.
+    pub kind: AssociationKind,
+    /// Asynchronous-channel tag (if any).
+    pub tag: Option<u32>,
+}
+
+/// Graph of stream IDs built from the context association lists of a
+/// set of context packets.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ContextAssociationGraph {
+    edges: BTreeMap<u32, Vec<Association>>,
+    known: BTreeSet<u32>,
+}
+
+impl ContextAssociationGraph {
+    /// Builds a graph from a set of received packets. Packets that
+    /// aren't context packets or have no stream ID are ignored. If more
+    /// than one context packet is given for the same stream, the last
+    /// one wins.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{AssociationKind, ContextAssociationGraph, ContextAssociationLists};
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut system = Vrt::new_context_packet();
+    /// system.set_stream_id(Some(1));
+    /// let lists = ContextAssociationLists::builder().source_list(vec![2, 3]).build()?;
+    /// let context = system.payload_mut().context_mut().unwrap();
+    /// context.set_context_association_lists(Some(lists));
+    ///
+    /// let mut channel = Vrt::new_context_packet();
+    /// channel.set_stream_id(Some(2));
+    ///
+    /// let graph = ContextAssociationGraph::from_packets([&system, &channel]);
+    /// assert_eq!(graph.associated_streams(1), vec![2, 3]);
+    /// assert_eq!(graph.associating_streams(3), vec![1]);
+    /// assert_eq!(graph.associations(1)[0].kind, AssociationKind::Source);
+    /// // Stream 3 is referenced but no context for it has been seen
+    /// assert_eq!(graph.unresolved(), vec![3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_packets<'a>(packets: impl IntoIterator<Item = &'a Vrt>) -> ContextAssociationGraph {
+        let mut ret = ContextAssociationGraph::default();
+        for packet in packets {
+            let (Some(sid), Ok(context)) = (packet.stream_id(), packet.payload().context()) else {
+                continue;
+            };
+            ret.known.insert(sid);
+            let mut edges = Vec::new();
+            if let Some(lists) = context.context_association_lists() {
+                let mut push = |to: u32, kind: AssociationKind, tag: Option<u32>| {
+                    edges.push(Association {
+                        from: sid,
+                        to,
+                        kind,
+                        tag,
+                    })
+                };
+                for to in lists.source_list() {
+                    push(*to, AssociationKind::Source, None);
+                }
+                for to in lists.system_list() {
+                    push(*to, AssociationKind::System, None);
+                }
+                for to in lists.vector_component_list() {
+                    push(*to, AssociationKind::VectorComponent, None);
+                }
+                let tags = lists.async_channel_tag_list();
+                for (i, to) in lists.async_channel_list().iter().enumerate() {
+                    push(
+                        *to,
+                        AssociationKind::AsyncChannel,
+                        tags.and_then(|t| t.get(i).copied()),
+                    );
+                }
+            }
+            ret.edges.insert(sid, edges);
+        }
+        ret
+    }
+
+    /// Gets every stream ID in the graph (both those with context
+    /// packets and those only referenced by association lists).
+    pub fn stream_ids(&self) -> Vec<u32> {
+        let mut ret: BTreeSet<u32> = self.known.clone();
+        ret.extend(self.edges.values().flatten().map(|a| a.to));
+        ret.into_iter().collect()
+    }
+
+    /// Gets all associations carried by the given stream's context.
+    pub fn associations(&self, stream_id: u32) -> &[Association] {
+        self.edges.get(&stream_id).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Gets the stream IDs the given stream's context associates with.
+    pub fn associated_streams(&self, stream_id: u32) -> Vec<u32> {
+        let set: BTreeSet<u32> = self.associations(stream_id).iter().map(|a| a.to).collect();
+        set.into_iter().collect()
+    }
+
+    /// Gets the stream IDs whose context associates with the given stream.
+    pub fn associating_streams(&self, stream_id: u32) -> Vec<u32> {
+        let set: BTreeSet<u32> = self
+            .edges
+            .values()
+            .flatten()
+            .filter(|a| a.to == stream_id)
+            .map(|a| a.from)
+            .collect();
+        set.into_iter().collect()
+    }
+
+    /// Gets every association in the graph.
+    pub fn edges(&self) -> impl Iterator<Item = &Association> {
+        self.edges.values().flatten()
+    }
+
+    /// Gets the stream IDs that are referenced by an association list
+    /// but for which no context packet has been seen.
+    pub fn unresolved(&self) -> Vec<u32> {
+        let set: BTreeSet<u32> = self
+            .edges()
+            .map(|a| a.to)
+            .filter(|sid| !self.known.contains(sid))
+            .collect();
+        set.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn context_packet(sid: Option<u32>, lists: Option<ContextAssociationLists>) -> Vrt {
+        let mut packet = Vrt::new_context_packet();
+        packet.set_stream_id(sid);
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_context_association_lists(lists);
+        packet.update_packet_size();
+        packet
+    }
+
+    #[test]
+    fn builder_limits() {
+        let ok = ContextAssociationLists::builder()
+            .source_list(vec![0; MAX_SOURCE_SYSTEM_ENTRIES])
+            .system_list(vec![0; MAX_SOURCE_SYSTEM_ENTRIES])
+            .vector_component_list(vec![0; MAX_VECTOR_COMPONENT_ENTRIES])
+            .async_channel_list(vec![0; MAX_ASYNC_CHANNEL_ENTRIES], None)
+            .build()
+            .unwrap();
+        assert_eq!(ok.source_list().len(), MAX_SOURCE_SYSTEM_ENTRIES);
+        assert_eq!(ok.system_list().len(), MAX_SOURCE_SYSTEM_ENTRIES);
+        assert_eq!(
+            ok.vector_component_list().len(),
+            MAX_VECTOR_COMPONENT_ENTRIES
+        );
+        assert_eq!(ok.async_channel_list().len(), MAX_ASYNC_CHANNEL_ENTRIES);
+
+        let too_long = [
+            ContextAssociationLists::builder().source_list(vec![0; MAX_SOURCE_SYSTEM_ENTRIES + 1]),
+            ContextAssociationLists::builder().system_list(vec![0; MAX_SOURCE_SYSTEM_ENTRIES + 1]),
+            ContextAssociationLists::builder().vector_component_list(vec![
+                0;
+                MAX_VECTOR_COMPONENT_ENTRIES
+                    + 1
+            ]),
+            ContextAssociationLists::builder()
+                .async_channel_list(vec![0; MAX_ASYNC_CHANNEL_ENTRIES + 1], None),
+        ];
+        for builder in too_long {
+            assert!(matches!(builder.build(), Err(VitaError::OutOfRange)));
+        }
+    }
+
+    #[test]
+    fn tag_list_length() {
+        for tags in [vec![], vec![1], vec![1, 2, 3]] {
+            let result = ContextAssociationLists::builder()
+                .async_channel_list(vec![10, 11], Some(tags))
+                .build();
+            assert!(matches!(result, Err(VitaError::OutOfRange)));
+        }
+
+        // A failed setter leaves the previous lists alone
+        let mut lists = ContextAssociationLists::builder()
+            .async_channel_list(vec![10, 11], Some(vec![1, 2]))
+            .build()
+            .unwrap();
+        assert!(lists
+            .set_async_channel_list(vec![12], Some(vec![]))
+            .is_err());
+        assert_eq!(lists.async_channel_list(), &[10, 11]);
+        assert_eq!(lists.async_channel_tag_list(), Some(&[1, 2][..]));
+
+        // Dropping the tags clears the tag-list flag
+        lists.set_async_channel_list(vec![12], None).unwrap();
+        assert_eq!(lists.async_channel_tag_list(), None);
+        assert_eq!(lists.size_words(), 3);
+    }
+
+    #[test]
+    fn wire_round_trip() {
+        let lists = ContextAssociationLists::builder()
+            .source_list(vec![1])
+            .system_list(vec![2, 3])
+            .vector_component_list(vec![4])
+            .async_channel_list(vec![5, 6], Some(vec![7, 8]))
+            .build()
+            .unwrap();
+        assert_eq!(lists.size_words(), 2 + 1 + 2 + 1 + 2 + 2);
+        let packet = context_packet(Some(1), Some(lists.clone()));
+        let parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+        let context = parsed.payload().context().unwrap();
+        assert_eq!(context.context_association_lists(), Some(&lists));
+    }
+
+    #[test]
+    fn graph() {
+        let system = context_packet(
+            Some(1),
+            Some(
+                ContextAssociationLists::builder()
+                    .source_list(vec![2])
+                    .system_list(vec![2])
+                    .async_channel_list(vec![4, 5], Some(vec![40, 50]))
+                    .build()
+                    .unwrap(),
+            ),
+        );
+        let stale = context_packet(
+            Some(2),
+            Some(
+                ContextAssociationLists::builder()
+                    .source_list(vec![9])
+                    .build()
+                    .unwrap(),
+            ),
+        );
+        let channel = context_packet(
+            Some(2),
+            Some(
+                ContextAssociationLists::builder()
+                    .vector_component_list(vec![4])
+                    .build()
+                    .unwrap(),
+            ),
+        );
+        let anonymous = context_packet(
+            None,
+            Some(
+                ContextAssociationLists::builder()
+                    .source_list(vec![7])
+                    .build()
+                    .unwrap(),
+            ),
+        );
+        let mut signal = Vrt::new_signal_data_packet();
+        signal.set_stream_id(Some(4));
+
+        let graph =
+            ContextAssociationGraph::from_packets([&system, &stale, &channel, &anonymous, &signal]);
+        // Two kinds of association to the same stream are listed once
+        assert_eq!(graph.associated_streams(1), vec![2, 4, 5]);
+        assert_eq!(graph.associations(1).len(), 4);
+        let tags: Vec<_> = graph
+            .associations(1)
+            .iter()
+            .filter(|a| a.kind == AssociationKind::AsyncChannel)
+            .map(|a| (a.to, a.tag))
+            .collect();
+        assert_eq!(tags, vec![(4, Some(40)), (5, Some(50))]);
+        // The later packet for stream 2 replaces the earlier one
+        assert_eq!(graph.associated_streams(2), vec![4]);
+        assert_eq!(graph.associating_streams(4), vec![1, 2]);
+        assert_eq!(graph.associating_streams(1), Vec::<u32>::new());
+        // Data packets and packets without a stream ID are ignored
+        assert_eq!(graph.stream_ids(), vec![1, 2, 4, 5]);
+        assert_eq!(graph.unresolved(), vec![4, 5]);
+        assert_eq!(graph.edges().count(), 5);
+        assert!(graph.associations(4).is_empty());
+    }
 }
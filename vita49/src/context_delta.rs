@@ -0,0 +1,360 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods for describing the differences between two
+context snapshots.
+
+A [`ContextDelta`] (from [`Context::diff()`]) lists every CIF0-CIF3 field
+that was added, removed, or changed between two contexts, along with the
+old and new values. A delta can be applied to a context (see
+[`Context::apply()`]) or turned into a minimal control packet that moves
+a controllee from one state to the other (see [`ContextDelta::to_control()`]).
+
+CIF7 attributes aren't compared; only the main field values are. When a
+delta is applied, removed fields drop their attributes and added fields
+get a default-valued entry for every attribute the target has enabled.
+
+```
+use vita49::prelude::*;
+use vita49::{CifField, CommandPayload, FieldChange, FieldValue};
+# fn main() -> Result<(), VitaError> {
+let mut before = Context::new();
+before.set_bandwidth_hz(Some(8e6));
+before.set_sample_rate_sps(Some(10e6));
+let mut after = before.clone();
+after.set_bandwidth_hz(Some(20e6));
+after.set_sample_rate_sps(None);
+after.set_network_id(Some(3));
+
+let delta = before.diff(&after);
+assert_eq!(
+    delta.get(CifField::Bandwidth),
+    Some(&FieldChange::Changed {
+        old: FieldValue::F64(8e6),
+        new: FieldValue::F64(20e6)
+    })
+);
+assert_eq!(delta.len(), 3);
+
+let mut patched = before.clone();
+patched.apply(&delta)?;
+assert_eq!(patched, after);
+
+// Only added and changed fields make it into the control packet
+let mut packet = Vrt::new_control_packet();
+let command = packet.payload_mut().command_mut().unwrap();
+*command.payload_mut() = CommandPayload::Control(delta.to_control()?);
+packet.update_packet_size();
+let control = packet.payload().command().unwrap().payload().control().unwrap();
+assert_eq!(control.bandwidth_hz(), Some(20e6));
+assert_eq!(control.network_id(), Some(&3));
+assert_eq!(control.sample_rate_sps(), None);
+# Ok(())
+# }
+```
+*/
+
+use std::collections::BTreeMap;
+
+use crate::{CifField, CifFieldAccess, Context, Control, FieldValue, VitaError};
+
+/// A change to a single CIF field.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldChange {
+    /// The field was added with the given value.
+    Added(FieldValue),
+    /// The field (which had the given value) was removed.
+    Removed(FieldValue),
+    /// The field's value changed.
+    Changed {
+        /// The previous value.
+        old: FieldValue,
+        /// The new value.
+        new: FieldValue,
+    },
+}
+
+impl FieldChange {
+    /// Gets the value the field has after the change (`None` if it
+    /// was removed).
+    pub fn new_value(&self) -> Option<&FieldValue> {
+        match self {
+            FieldChange::Added(v) | FieldChange::Changed { new: v, .. } => Some(v),
+            FieldChange::Removed(_) => None,
+        }
+    }
+
+    /// Gets the value the field had before the change (`None` if it
+    /// was added).
+    pub fn old_value(&self) -> Option<&FieldValue> {
+        match self {
+            FieldChange::Removed(v) | FieldChange::Changed { old: v, .. } => Some(v),
+            FieldChange::Added(_) => None,
+        }
+    }
+}
+
+/// The set of field changes between two contexts.
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContextDelta {
+    changes: BTreeMap<CifField, FieldChange>,
+}
+
+impl ContextDelta {
+    /// Compute the changes needed to turn `from` into `to`.
+    pub fn between<T: CifFieldAccess>(from: &T, to: &T) -> ContextDelta {
+        let mut changes = BTreeMap::new();
+        for field in CifField::ALL {
+            let change = match (from.get(*field), to.get(*field)) {
+                (None, Some(new)) => FieldChange::Added(new),
+                (Some(old), None) => FieldChange::Removed(old),
+                (Some(old), Some(new)) if old != new => FieldChange::Changed { old, new },
+                _ => continue,
+            };
+            changes.insert(*field, change);
+        }
+        ContextDelta { changes }
+    }
+
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Gets the number of changed fields.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Gets the change to the given field, if any.
+    pub fn get(&self, field: CifField) -> Option<&FieldChange> {
+        self.changes.get(&field)
+    }
+
+    /// Iterate over the changes, in packet order.
+    pub fn changes(&self) -> impl Iterator<Item = (CifField, &FieldChange)> {
+        self.changes.iter().map(|(f, c)| (*f, c))
+    }
+
+    /// Iterate over the fields that were added.
+    pub fn added(&self) -> impl Iterator<Item = CifField> + '_ {
+        self.fields_where(|c| matches!(c, FieldChange::Added(_)))
+    }
+
+    /// Iterate over the fields that were removed.
+    pub fn removed(&self) -> impl Iterator<Item = CifField> + '_ {
+        self.fields_where(|c| matches!(c, FieldChange::Removed(_)))
+    }
+
+    /// Iterate over the fields whose value changed.
+    pub fn changed(&self) -> impl Iterator<Item = CifField> + '_ {
+        self.fields_where(|c| matches!(c, FieldChange::Changed { .. }))
+    }
+
+    /// Apply the changes to a context (or any other structure with CIF
+    /// fields). Fields not in the delta are left alone.
+    ///
+    /// # Errors
+    /// Returns any error a field's setter returns. The target is left
+    /// unchanged in that case.
+    pub fn apply_to<T: CifFieldAccess + Clone>(&self, target: &mut T) -> Result<(), VitaError> {
+        // Patch a copy so a field failing partway leaves no half-applied delta
+        let mut patched = target.clone();
+        for (field, change) in &self.changes {
+            match change.new_value() {
+                Some(v) => patched.set(*field, v.clone())?,
+                None => patched.unset(*field),
+            }
+        }
+        *target = patched;
+        Ok(())
+    }
+
+    /// Build a minimal control payload setting every added or changed
+    /// field to its new value. Control packets can't remove fields, so
+    /// removed fields are left out.
+    ///
+    /// # Errors
+    /// Returns any error a field's setter returns.
+    pub fn to_control(&self) -> Result<Control, VitaError> {
+        let mut control = Control::default();
+        for (field, change) in &self.changes {
+            if let Some(v) = change.new_value() {
+                control.set(*field, v.clone())?;
+            }
+        }
+        Ok(control)
+    }
+
+    fn fields_where(
+        &self,
+        pred: impl Fn(&FieldChange) -> bool + 'static,
+    ) -> impl Iterator<Item = CifField> + '_ {
+        self.changes
+            .iter()
+            .filter(move |(_, c)| pred(c))
+            .map(|(f, _)| *f)
+    }
+}
+
+impl TryFrom<&ContextDelta> for Control {
+    type Error = VitaError;
+
+    fn try_from(delta: &ContextDelta) -> Result<Self, Self::Error> {
+        delta.to_control()
+    }
+}
+
+impl Context {
+    /// Compute the changes needed to turn this context into `other`.
+    pub fn diff(&self, other: &Context) -> ContextDelta {
+        ContextDelta::between(self, other)
+    }
+
+    /// Apply a set of changes to this context.
+    ///
+    /// [`update_packet_size()`](crate::Vrt::update_packet_size()) should be executed after running this method.
+    ///
+    /// # Errors
+    /// Returns any error a field's setter returns. The context is left
+    /// unchanged in that case.
+    pub fn apply(&mut self, delta: &ContextDelta) -> Result<(), VitaError> {
+        delta.apply_to(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn float_changes() {
+        let mut before = Context::new();
+        before.set_bandwidth_hz(Some(8e6));
+        before.set_reference_level_db(Some(-10.5));
+        assert!(before.diff(&before.clone()).is_empty());
+
+        // The smallest step the encoding can hold still counts as a change
+        let mut after = before.clone();
+        after.set_bandwidth_hz(Some(8e6 + 1.0 / (1 << 20) as f64));
+        let delta = before.diff(&after);
+        assert_eq!(
+            delta.changed().collect::<Vec<_>>(),
+            vec![CifField::Bandwidth]
+        );
+
+        // NaN can't be encoded, so it's rejected rather than compared
+        let nan = ContextDelta {
+            changes: BTreeMap::from([(
+                CifField::Bandwidth,
+                FieldChange::Changed {
+                    old: FieldValue::F64(8e6),
+                    new: FieldValue::F64(f64::NAN),
+                },
+            )]),
+        };
+        let mut patched = before.clone();
+        assert!(matches!(patched.apply(&nan), Err(VitaError::OutOfRange)));
+        assert!(matches!(nan.to_control(), Err(VitaError::OutOfRange)));
+        assert_eq!(patched, before);
+    }
+
+    #[test]
+    fn failed_apply_leaves_target_unchanged() {
+        let mut before = Context::new();
+        before.set_bandwidth_hz(Some(8e6));
+        before.set_sample_rate_sps(Some(10e6));
+        // The phase offset comes last and is outside ±π
+        let delta = ContextDelta {
+            changes: BTreeMap::from([
+                (
+                    CifField::Bandwidth,
+                    FieldChange::Changed {
+                        old: FieldValue::F64(8e6),
+                        new: FieldValue::F64(20e6),
+                    },
+                ),
+                (
+                    CifField::SampleRate,
+                    FieldChange::Removed(FieldValue::F64(10e6)),
+                ),
+                (
+                    CifField::ReferencePointId,
+                    FieldChange::Added(FieldValue::U32(3)),
+                ),
+                (
+                    CifField::PhaseOffset,
+                    FieldChange::Added(FieldValue::F32(10.0)),
+                ),
+            ]),
+        };
+        let mut patched = before.clone();
+        assert!(matches!(patched.apply(&delta), Err(VitaError::OutOfRange)));
+        assert_eq!(patched, before);
+        assert!(Cif1Manipulators::cif1(&patched).is_none());
+    }
+
+    #[cfg(feature = "cif7")]
+    #[test]
+    fn apply_keeps_attributes_aligned() {
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_bandwidth_hz(Some(8e6));
+        context.set_sample_rate_sps(Some(10e6));
+        context.set_bandwidth_hz_attr(Cif7Attr::Max, 10e6).unwrap();
+        let mut after = context.clone();
+        after.set_bandwidth_hz(None);
+        after.set_range_m(Some(5.0));
+
+        context.apply(&context.diff(&after)).unwrap();
+        assert_eq!(context, &after);
+        // The removed field dropped its attributes and the added one
+        // gained a slot for every enabled attribute
+        assert!(context.bandwidth_hz_attributes().is_empty());
+        assert_eq!(context.range_m_attr(Cif7Attr::Max), Some(0.0));
+
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        let context = parsed.payload().context().unwrap();
+        assert_eq!(context, &after);
+        assert_eq!(context.sample_rate_sps_attr(Cif7Attr::Max), Some(0.0));
+        assert_eq!(context.range_m(), Some(5.0));
+    }
+
+    #[test]
+    fn to_control_leaves_out_removed_fields() {
+        let mut before = Context::new();
+        before.set_bandwidth_hz(Some(8e6));
+        before.set_sample_rate_sps(Some(10e6));
+        before.set_network_id(Some(1));
+        let mut after = before.clone();
+        after.set_bandwidth_hz(Some(20e6));
+        after.set_sample_rate_sps(None);
+        after.set_network_id(None);
+        after.set_range_m(Some(5.0));
+
+        let delta = before.diff(&after);
+        assert_eq!(
+            delta.removed().collect::<Vec<_>>(),
+            vec![CifField::SampleRate, CifField::NetworkId]
+        );
+        let control = delta.to_control().unwrap();
+        assert_eq!(
+            control.present_fields().collect::<Vec<_>>(),
+            vec![CifField::Bandwidth, CifField::Range]
+        );
+        assert!(!Cif0Manipulators::cif0(&control).sample_rate());
+        assert!(Cif3Manipulators::cif3(&control).is_none());
+        assert_eq!(Control::try_from(&delta).unwrap(), control);
+
+        // A delta that only removes fields has nothing to command
+        let removals = after.diff(&Context::new());
+        assert!(removals.changes().all(|(_, c)| c.new_value().is_none()));
+        assert_eq!(removals.to_control().unwrap(), Control::default());
+    }
+}
@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{cif7::Cif7Opts, prelude::*};
+use crate::{cif7::Cif7Opts, prelude::*, CommandPayload};
 use deku::prelude::*;
 use std::fmt;
 
@@ -72,6 +72,21 @@ impl Control {
     }
 }
 
+impl TryFrom<CommandPayload> for Control {
+    type Error = CommandPayload;
+
+    fn try_from(value: CommandPayload) -> Result<Self, Self::Error> {
+        match value {
+            CommandPayload::Control(c) => Ok(c),
+            a => Err(a),
+        }
+    }
+}
+
+crate::cif_payload::cif_payload!(Control);
+
+crate::cif7::cif7_manipulators!(Control);
+
 impl Cif0Manipulators for Control {
     fn cif0(&self) -> &Cif0 {
         &self.cif0
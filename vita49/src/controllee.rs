@@ -0,0 +1,650 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+A framework for answering control packets with validation, execution,
+and query-state ACKs (ANSI/VITA-49.2-2017 section 8.3).
+
+A [`Controllee`] wraps a user-supplied [`ControlleeHandler`], which
+validates, applies, and reports individual CIF fields. For each control
+packet, [`Controllee::process()`] calls the handler and builds the ACK
+packets the controller asked for, honoring the CAM settings:
+
+- The action mode decides whether fields are applied (execute), only
+  checked as if they were applied (dry run), or only checked (no action).
+- Fields with warnings (or errors) are only applied when warnings (or
+  errors) are permitted.
+- If any field is blocked, nothing is applied unless partial packet
+  implementation is permitted.
+- Without that permission, a field failing to execute also stops the
+  packet: the remaining fields are skipped and the ones already applied
+  are undone with [`ControlleeHandler::rollback()`]. Fields the handler
+  can't undo stay applied and the execution ACK reports a partial action.
+- In NACK-only mode, validation and execution ACKs are only sent when
+  they report a warning or error.
+
+Every ACK mirrors the command's stream ID, message ID, and controllee/
+controller identifiers so the controller can match it with its request.
+
+```
+use vita49::prelude::*;
+use vita49::command_prelude::*;
+use vita49::{CifField, Controllee, ControlleeHandler, FieldValue};
+
+#[derive(Default)]
+struct Radio {
+    bandwidth_hz: f64,
+}
+
+impl ControlleeHandler for Radio {
+    fn validate(&self, field: CifField, value: &FieldValue) -> Result<(), AckResponse> {
+        let mut response = AckResponse::default();
+        match (field, value) {
+            (CifField::Bandwidth, FieldValue::F64(bw)) if *bw <= 100e6 => return Ok(()),
+            (CifField::Bandwidth, _) => response.set_param_out_of_range(),
+            _ => response.set_field_value_invalid(),
+        }
+        Err(response)
+    }
+
+    fn execute(&mut self, field: CifField, value: &FieldValue) -> Result<(), AckResponse> {
+        if let (CifField::Bandwidth, FieldValue::F64(bw)) = (field, value) {
+            self.bandwidth_hz = *bw;
+        }
+        Ok(())
+    }
+
+    fn query(&self, field: CifField) -> Option<FieldValue> {
+        match field {
+            CifField::Bandwidth => Some(self.bandwidth_hz.into()),
+            _ => None,
+        }
+    }
+}
+
+# fn main() -> Result<(), VitaError> {
+let mut packet = Vrt::new_control_packet();
+let command = packet.payload_mut().command_mut()?;
+let mut cam = ControlAckMode::default();
+cam.set_action_mode(ActionMode::Execute);
+cam.set_execution();
+cam.set_state();
+command.set_cam(cam);
+command.set_message_id(42);
+command.payload_mut().control_mut()?.set_bandwidth_hz(Some(8e6));
+packet.update_packet_size();
+
+let mut controllee = Controllee::new(Radio::default());
+let acks = controllee.process(&packet)?;
+assert_eq!(controllee.handler().bandwidth_hz, 8e6);
+assert_eq!(acks.len(), 2);
+
+let exec_ack = acks[0].payload().command()?;
+assert_eq!(exec_ack.message_id(), 42);
+assert!(exec_ack.cam().action_scheduled_or_executed());
+assert!(!exec_ack.cam().error());
+let query_ack = acks[1].payload().command()?.payload().query_ack()?;
+assert_eq!(query_ack.bandwidth_hz(), Some(8e6));
+# Ok(())
+# }
+```
+*/
+
+use crate::{
+    prelude::*, Ack, AckLevel, AckResponse, ActionMode, CifField, CifFieldAccess, Command,
+    CommandPayload, FieldValue,
+};
+
+/// Device-specific handling of individual CIF fields for a [`Controllee`].
+///
+/// Each handler returns `Err` with an [`AckResponse`] describing what's
+/// wrong with a field. Fields the device doesn't support should be
+/// rejected by [`validate()`](ControlleeHandler::validate()) (e.g. with
+/// [`AckResponse::set_field_value_invalid()`]).
+pub trait ControlleeHandler {
+    /// Check a requested field value without applying it. Problems are
+    /// reported as errors. Accepts everything by default.
+    fn validate(&self, _field: CifField, _value: &FieldValue) -> Result<(), AckResponse> {
+        Ok(())
+    }
+
+    /// Check a requested field value for conditions that don't prevent
+    /// applying it but that the controller should hear about (e.g.
+    /// hazardous power levels). Problems are reported as warnings.
+    /// Accepts everything by default.
+    fn check_warnings(&self, _field: CifField, _value: &FieldValue) -> Result<(), AckResponse> {
+        Ok(())
+    }
+
+    /// Apply a field value to the device. Problems are reported as
+    /// errors in the execution ACK and the field is considered not
+    /// executed.
+    ///
+    /// If the controller permits errors, this is also called for fields
+    /// [`validate()`](ControlleeHandler::validate()) rejected, so it must
+    /// not assume the value is valid or the field supported (e.g. return
+    /// [`AckResponse::set_field_value_invalid()`] for unsupported fields).
+    fn execute(&mut self, field: CifField, value: &FieldValue) -> Result<(), AckResponse>;
+
+    /// Undo a field [`execute()`](ControlleeHandler::execute()) applied
+    /// earlier in the same packet. When partial packet implementation
+    /// isn't permitted and a field fails to execute, this is called for
+    /// every field already applied, most recent first. Returns true if
+    /// the field was restored.
+    ///
+    /// Can't undo anything by default, so applied fields stay in effect
+    /// and the execution ACK reports a partial action.
+    fn rollback(&mut self, _field: CifField) -> bool {
+        false
+    }
+
+    /// Get the current value of a field for a query-state ACK. Fields
+    /// returning `None` are left out of the reply.
+    fn query(&self, field: CifField) -> Option<FieldValue>;
+}
+
+/// Results of checking and (possibly) applying one field.
+struct FieldOutcome {
+    field: CifField,
+    warning: Option<AckResponse>,
+    error: Option<AckResponse>,
+    exec_error: Option<AckResponse>,
+    executed: bool,
+}
+
+/// Processes control packets on behalf of a [`ControlleeHandler`] and
+/// builds the requested ACK packets.
+#[derive(Clone, Debug, Default)]
+pub struct Controllee<H> {
+    handler: H,
+}
+
+impl<H: ControlleeHandler> Controllee<H> {
+    /// Create a new controllee around the given handler.
+    pub fn new(handler: H) -> Controllee<H> {
+        Controllee { handler }
+    }
+
+    /// Get a reference to the handler.
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Get a mutable reference to the handler.
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Consume the controllee, returning the handler.
+    pub fn into_handler(self) -> H {
+        self.handler
+    }
+
+    /// Process a control packet, applying its fields as the CAM allows,
+    /// and return the requested ACK packets (validation, then execution,
+    /// then query-state). The returned list is empty if no ACKs were
+    /// requested (or, in NACK-only mode, if there was nothing to report).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::CommandOnly`] or [`VitaError::ControlOnly`]
+    /// if the packet isn't a control packet, or any error raised while
+    /// storing a queried value in the query-state ACK.
+    pub fn process(&mut self, packet: &Vrt) -> Result<Vec<Vrt>, VitaError> {
+        let command = packet.payload().command()?;
+        let control = command.payload().control()?;
+        let cam = command.cam();
+        let action_mode = cam.action_mode();
+
+        let mut outcomes: Vec<FieldOutcome> = control
+            .present_fields()
+            .map(|field| {
+                let value = control.get(field);
+                let (warning, error) = match &value {
+                    Some(v) => (
+                        self.handler.check_warnings(field, v).err(),
+                        self.handler.validate(field, v).err(),
+                    ),
+                    None => (None, None),
+                };
+                FieldOutcome {
+                    field,
+                    warning,
+                    error,
+                    exec_error: None,
+                    executed: false,
+                }
+            })
+            .collect();
+
+        let blocked = |o: &FieldOutcome| {
+            (o.error.is_some() && !cam.errors_permitted())
+                || (o.warning.is_some() && !cam.warnings_permitted())
+        };
+        let any_blocked = outcomes.iter().any(blocked);
+        let take_action = matches!(action_mode, ActionMode::Execute | ActionMode::DryRun)
+            && (!any_blocked || cam.partial_packet_impl_permitted());
+
+        if take_action {
+            for outcome in outcomes.iter_mut() {
+                if blocked(outcome) {
+                    continue;
+                }
+                // A dry run responds as if every field had been applied
+                let result = match (action_mode, control.get(outcome.field)) {
+                    (ActionMode::Execute, Some(v)) => self.handler.execute(outcome.field, &v),
+                    _ => Ok(()),
+                };
+                match result {
+                    Ok(()) => outcome.executed = true,
+                    Err(r) => {
+                        outcome.exec_error = Some(r);
+                        if !cam.partial_packet_impl_permitted() {
+                            break;
+                        }
+                    }
+                }
+            }
+            let failed = outcomes.iter().any(|o| o.exec_error.is_some());
+            if failed && !cam.partial_packet_impl_permitted() {
+                for outcome in outcomes.iter_mut().rev().filter(|o| o.executed) {
+                    if self.handler.rollback(outcome.field) {
+                        outcome.executed = false;
+                    }
+                }
+            }
+        }
+
+        let mut acks = Vec::new();
+        if cam.validation() {
+            let mut ack = Ack::default();
+            for outcome in &outcomes {
+                ack.set_field_response(outcome.field, AckLevel::Warning, outcome.warning);
+                ack.set_field_response(outcome.field, AckLevel::Error, outcome.error);
+            }
+            if let Some(reply) = ack_packet(packet, command, CommandPayload::ValidationAck(ack))? {
+                acks.push(reply);
+            }
+        }
+
+        if cam.execution() {
+            let mut ack = Ack::default();
+            for outcome in &outcomes {
+                let mut warning = outcome.warning;
+                let mut error = outcome.error;
+                if let Some(r) = outcome.exec_error {
+                    error.get_or_insert_with(AckResponse::default).merge(r);
+                }
+                if action_mode != ActionMode::NoAction && !outcome.executed {
+                    // Flag the field on the most severe response it has
+                    let response = match error.as_mut() {
+                        Some(e) => e,
+                        None => warning.get_or_insert_with(AckResponse::default),
+                    };
+                    response.set_field_not_executed();
+                }
+                ack.set_field_response(outcome.field, AckLevel::Warning, warning);
+                ack.set_field_response(outcome.field, AckLevel::Error, error);
+            }
+            let executed = outcomes.iter().filter(|o| o.executed).count();
+            let mut reply = ack_packet(packet, command, CommandPayload::ExecAck(ack))?;
+            if let Some(reply) = reply.as_mut() {
+                let ack_command = reply.payload_mut().command_mut()?;
+                let mut ack_cam = ack_command.cam();
+                if executed > 0 {
+                    ack_cam.set_action_scheduled_or_executed();
+                }
+                if executed > 0 && executed < outcomes.len() {
+                    ack_cam.set_partial_action_taken();
+                }
+                ack_command.set_cam(ack_cam);
+            }
+            acks.extend(reply);
+        }
+
+        if cam.state() {
+            let mut ack = crate::QueryAck::default();
+            let present: Vec<CifField> = outcomes.iter().map(|o| o.field).collect();
+            // An empty query asks for everything
+            let fields = if present.is_empty() {
+                CifField::ALL
+            } else {
+                &present[..]
+            };
+            for field in fields {
+                if let Some(v) = self.handler.query(*field) {
+                    ack.set(*field, v)?;
+                }
+            }
+            acks.extend(ack_packet(packet, command, CommandPayload::QueryAck(ack))?);
+        }
+
+        Ok(acks)
+    }
+}
+
+/// Build an ACK packet replying to `command` (carried by `packet`). The
+/// CAM is copied from the command with only the bit for this kind of ACK
+/// set and the warning/error bits matching the ACK's contents. Returns
+/// `None` if the command is NACK-only and the ACK has nothing to report.
+fn ack_packet(
+    packet: &Vrt,
+    command: &Command,
+    payload: CommandPayload,
+) -> Result<Option<Vrt>, VitaError> {
+    let mut cam = command.cam();
+    cam.unset_validation();
+    cam.unset_execution();
+    cam.unset_state();
+    cam.unset_warning();
+    cam.unset_error();
+    cam.unset_partial_action_taken();
+    cam.unset_action_scheduled_or_executed();
+
+    let mut reply = match &payload {
+        CommandPayload::ValidationAck(ack) | CommandPayload::ExecAck(ack) => {
+            let warning = ack.has_warnings();
+            let error = ack.has_errors();
+            if command.cam().nack_only() && !warning && !error {
+                return Ok(None);
+            }
+            if warning {
+                cam.set_warning();
+            }
+            if error {
+                cam.set_error();
+            }
+            if matches!(payload, CommandPayload::ValidationAck(_)) {
+                cam.set_validation();
+                Vrt::new_validation_ack_packet()
+            } else {
+                cam.set_execution();
+                Vrt::new_exec_ack_packet()
+            }
+        }
+        _ => {
+            cam.set_state();
+            Vrt::new_query_ack_packet()
+        }
+    };
+
+    // Mirror the command's identifiers so the controller knows which
+    // command we're replying to
+    reply.set_stream_id(packet.stream_id());
+    let ack = reply.payload_mut().command_mut()?;
+    // The CAM carries the ID formats, so it must be set before the IDs
+    ack.set_cam(cam);
+    ack.set_message_id(command.message_id());
+    ack.set_controllee_id(command.controllee_id())?;
+    ack.set_controllee_uuid(command.controllee_uuid())?;
+    ack.set_controller_id(command.controller_id())?;
+    ack.set_controller_uuid(command.controller_uuid())?;
+    *ack.payload_mut() = payload;
+    reply.update_packet_size();
+    Ok(Some(reply))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Control, ControlAckMode};
+
+    /// Accepts bandwidths up to 100 MHz, warns above 50 MHz, fails to
+    /// apply sample rates, and can only undo bandwidth changes.
+    #[derive(Default)]
+    struct TestRadio {
+        bandwidth_hz: Option<f64>,
+        rf_ref_freq_hz: Option<f64>,
+    }
+
+    impl ControlleeHandler for TestRadio {
+        fn validate(&self, field: CifField, value: &FieldValue) -> Result<(), AckResponse> {
+            let mut response = AckResponse::default();
+            match (field, value) {
+                (CifField::Bandwidth, FieldValue::F64(v)) if *v > 100e6 => {
+                    response.set_param_out_of_range()
+                }
+                (CifField::Bandwidth | CifField::RfRefFreq | CifField::SampleRate, _) => {
+                    return Ok(())
+                }
+                _ => response.set_field_value_invalid(),
+            }
+            Err(response)
+        }
+
+        fn check_warnings(&self, field: CifField, value: &FieldValue) -> Result<(), AckResponse> {
+            let mut response = AckResponse::default();
+            match (field, value) {
+                (CifField::Bandwidth, FieldValue::F64(v)) if *v > 50e6 => {
+                    response.set_distrortion();
+                    Err(response)
+                }
+                _ => Ok(()),
+            }
+        }
+
+        fn execute(&mut self, field: CifField, value: &FieldValue) -> Result<(), AckResponse> {
+            match (field, value) {
+                (CifField::Bandwidth, FieldValue::F64(v)) => self.bandwidth_hz = Some(*v),
+                (CifField::RfRefFreq, FieldValue::F64(v)) => self.rf_ref_freq_hz = Some(*v),
+                _ => {
+                    let mut response = AckResponse::default();
+                    response.set_device_failure();
+                    return Err(response);
+                }
+            }
+            Ok(())
+        }
+
+        fn rollback(&mut self, field: CifField) -> bool {
+            if field != CifField::Bandwidth {
+                return false;
+            }
+            self.bandwidth_hz = None;
+            true
+        }
+
+        fn query(&self, field: CifField) -> Option<FieldValue> {
+            match field {
+                CifField::Bandwidth => self.bandwidth_hz.map(FieldValue::F64),
+                CifField::RfRefFreq => self.rf_ref_freq_hz.map(FieldValue::F64),
+                _ => None,
+            }
+        }
+    }
+
+    fn control_packet(cam: ControlAckMode, set: impl FnOnce(&mut Control)) -> Vrt {
+        let mut packet = Vrt::new_control_packet();
+        packet.set_stream_id(Some(0x1234));
+        let command = packet.payload_mut().command_mut().unwrap();
+        command.set_cam(cam);
+        command.set_message_id(7);
+        command.set_controller_id(Some(99)).unwrap();
+        set(command.payload_mut().control_mut().unwrap());
+        packet.update_packet_size();
+        packet
+    }
+
+    fn request_cam(mode: ActionMode) -> ControlAckMode {
+        let mut cam = ControlAckMode::default();
+        cam.set_action_mode(mode);
+        cam.set_validation();
+        cam.set_execution();
+        cam
+    }
+
+    /// Round-trips the ACKs through their wire format.
+    fn parsed(acks: Vec<Vrt>) -> Vec<Vrt> {
+        acks.iter()
+            .map(|a| Vrt::try_from(&a.to_bytes().unwrap()[..]).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn execute_with_warnings_and_errors() {
+        let mut cam = request_cam(ActionMode::Execute);
+        cam.set_warnings_permitted();
+        cam.set_partial_packet_impl_permitted();
+        let packet = control_packet(cam, |c| {
+            c.set_bandwidth_hz(Some(60e6));
+            c.set_rf_ref_freq_hz(Some(1e9));
+            c.set_sample_rate_sps(Some(1e6));
+            c.set_network_id(Some(1));
+        });
+        let mut controllee = Controllee::new(TestRadio::default());
+        let acks = parsed(controllee.process(&packet).unwrap());
+        assert_eq!(acks.len(), 2);
+        assert_eq!(controllee.handler().bandwidth_hz, Some(60e6));
+        assert_eq!(controllee.handler().rf_ref_freq_hz, Some(1e9));
+
+        let validation = acks[0].payload().command().unwrap();
+        assert_eq!(acks[0].stream_id(), Some(0x1234));
+        assert_eq!(validation.message_id(), 7);
+        assert_eq!(validation.controller_id(), Some(99));
+        assert!(validation.cam().validation() && !validation.cam().execution());
+        assert!(validation.cam().warning() && validation.cam().error());
+        let ack = validation.payload().validation_ack().unwrap();
+        let (warning, error) = ack.field_response(CifField::Bandwidth);
+        assert!(warning.unwrap().distortion() && error.is_none());
+        assert_eq!(ack.field_response(CifField::RfRefFreq), (None, None));
+        assert_eq!(ack.field_response(CifField::SampleRate), (None, None));
+        let (warning, error) = ack.field_response(CifField::NetworkId);
+        let error = error.unwrap();
+        assert!(warning.is_none());
+        assert!(error.field_value_invalid() && !error.field_not_executed());
+
+        let exec = acks[1].payload().command().unwrap();
+        assert!(exec.cam().execution() && !exec.cam().validation());
+        assert!(exec.cam().action_scheduled_or_executed());
+        assert!(exec.cam().partial_action_taken());
+        let ack = exec.payload().exec_ack().unwrap();
+        let (warning, error) = ack.field_response(CifField::Bandwidth);
+        let warning = warning.unwrap();
+        assert!(warning.distortion() && !warning.field_not_executed());
+        assert!(error.is_none());
+        let (warning, error) = ack.field_response(CifField::SampleRate);
+        let error = error.unwrap();
+        assert!(warning.is_none());
+        assert!(error.device_failure() && error.field_not_executed());
+        let (_, error) = ack.field_response(CifField::NetworkId);
+        let error = error.unwrap();
+        assert!(error.field_value_invalid() && error.field_not_executed());
+    }
+
+    #[test]
+    fn blocked_fields_stop_the_packet() {
+        let packet = control_packet(request_cam(ActionMode::Execute), |c| {
+            c.set_bandwidth_hz(Some(60e6));
+            c.set_rf_ref_freq_hz(Some(1e9));
+        });
+        let mut controllee = Controllee::new(TestRadio::default());
+        let acks = parsed(controllee.process(&packet).unwrap());
+        assert_eq!(controllee.handler().bandwidth_hz, None);
+        assert_eq!(controllee.handler().rf_ref_freq_hz, None);
+
+        let exec = acks[1].payload().command().unwrap();
+        assert!(!exec.cam().action_scheduled_or_executed());
+        assert!(exec.cam().warning() && !exec.cam().error());
+        let ack = exec.payload().exec_ack().unwrap();
+        let (warning, error) = ack.field_response(CifField::Bandwidth);
+        assert!(warning.unwrap().field_not_executed() && error.is_none());
+        let (warning, error) = ack.field_response(CifField::RfRefFreq);
+        assert!(warning.unwrap().field_not_executed() && error.is_none());
+    }
+
+    #[test]
+    fn errors_and_warnings_on_one_field() {
+        let mut cam = request_cam(ActionMode::Execute);
+        cam.set_warnings_permitted();
+        cam.set_errors_permitted();
+        let packet = control_packet(cam, |c| {
+            c.set_bandwidth_hz(Some(150e6));
+        });
+        let mut controllee = Controllee::new(TestRadio::default());
+        let acks = parsed(controllee.process(&packet).unwrap());
+        // Errors are permitted, so the rejected field is still executed
+        assert_eq!(controllee.handler().bandwidth_hz, Some(150e6));
+
+        let ack = acks[0].payload().command().unwrap();
+        let ack = ack.payload().validation_ack().unwrap();
+        let (warning, error) = ack.field_response(CifField::Bandwidth);
+        assert!(warning.unwrap().distortion());
+        assert!(error.unwrap().param_out_of_range());
+    }
+
+    #[test]
+    fn failed_execution_rolls_back() {
+        let packet = control_packet(request_cam(ActionMode::Execute), |c| {
+            c.set_bandwidth_hz(Some(20e6));
+            c.set_rf_ref_freq_hz(Some(1e9));
+            c.set_sample_rate_sps(Some(1e6));
+        });
+        let mut controllee = Controllee::new(TestRadio::default());
+        let acks = parsed(controllee.process(&packet).unwrap());
+        // Only the bandwidth can be undone
+        assert_eq!(controllee.handler().bandwidth_hz, None);
+        assert_eq!(controllee.handler().rf_ref_freq_hz, Some(1e9));
+
+        let exec = acks[1].payload().command().unwrap();
+        assert!(exec.cam().partial_action_taken());
+        let ack = exec.payload().exec_ack().unwrap();
+        let (warning, error) = ack.field_response(CifField::Bandwidth);
+        assert!(warning.unwrap().field_not_executed() && error.is_none());
+        assert_eq!(ack.field_response(CifField::RfRefFreq), (None, None));
+        let (_, error) = ack.field_response(CifField::SampleRate);
+        assert!(error.unwrap().device_failure());
+    }
+
+    #[test]
+    fn dry_run_and_no_action() {
+        let packet = control_packet(request_cam(ActionMode::DryRun), |c| {
+            c.set_rf_ref_freq_hz(Some(1e9));
+        });
+        let mut controllee = Controllee::new(TestRadio::default());
+        let acks = parsed(controllee.process(&packet).unwrap());
+        assert_eq!(controllee.handler().rf_ref_freq_hz, None);
+        let exec = acks[1].payload().command().unwrap();
+        assert!(exec.cam().action_scheduled_or_executed());
+        assert!(!exec.cam().warning() && !exec.cam().error());
+
+        let packet = control_packet(request_cam(ActionMode::NoAction), |c| {
+            c.set_rf_ref_freq_hz(Some(1e9));
+        });
+        let acks = parsed(controllee.process(&packet).unwrap());
+        let exec = acks[1].payload().command().unwrap();
+        assert!(!exec.cam().action_scheduled_or_executed());
+        assert!(!exec.cam().warning() && !exec.cam().error());
+    }
+
+    #[test]
+    fn nack_only_and_query() {
+        let mut cam = request_cam(ActionMode::Execute);
+        cam.set_nack_only();
+        cam.set_state();
+        let packet = control_packet(cam, |c| {
+            c.set_rf_ref_freq_hz(Some(1e9));
+        });
+        let mut controllee = Controllee::new(TestRadio::default());
+        let acks = parsed(controllee.process(&packet).unwrap());
+        assert_eq!(acks.len(), 1);
+        let query = acks[0].payload().command().unwrap();
+        assert!(query.cam().state() && !query.cam().execution());
+        let query = query.payload().query_ack().unwrap();
+        assert_eq!(query.rf_ref_freq_hz(), Some(1e9));
+        assert_eq!(query.bandwidth_hz(), None);
+
+        // An empty query reports every field the handler knows
+        let mut cam = ControlAckMode::default();
+        cam.set_state();
+        let packet = control_packet(cam, |_| {});
+        let acks = parsed(controllee.process(&packet).unwrap());
+        let query = acks[0].payload().command().unwrap();
+        let query = query.payload().query_ack().unwrap();
+        assert_eq!(query.rf_ref_freq_hz(), Some(1e9));
+
+        let packet = Vrt::new_cancellation_packet();
+        assert!(matches!(
+            controllee.process(&packet),
+            Err(VitaError::ControlOnly)
+        ));
+    }
+}
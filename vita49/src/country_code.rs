@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the country code field
+(ANSI/VITA-49.2-2017 section 9.8.1).
+
+The field carries an ISO-3166-1 alpha-2 country code (e.g. "US") as
+two ASCII characters in bits 15-0. Bits 31-16 are reserved.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::VitaError;
+
+/// Base country code data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CountryCode(u32);
+
+impl CountryCode {
+    /// Create a new country code from a two-letter ISO-3166-1 code.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::NonAscii`] if the code isn't made of ASCII
+    /// letters, or [`VitaError::OutOfRange`] if it isn't exactly two
+    /// characters long.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::CountryCode;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_country_code(Some(CountryCode::new("us")?));
+    /// assert_eq!(context.country_code().unwrap().code(), "US");
+    /// assert_eq!(context.country_code().unwrap().as_u32(), 0x5553);
+    /// assert!(CountryCode::new("USA").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(code: &str) -> Result<CountryCode, VitaError> {
+        if !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(VitaError::NonAscii);
+        }
+        match code.to_ascii_uppercase().as_bytes() {
+            [a, b] => Ok(CountryCode(u16::from_be_bytes([*a, *b]) as u32)),
+            _ => Err(VitaError::OutOfRange),
+        }
+    }
+
+    /// Gets the size of the country code structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the raw 32-bit value of the field.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the two-letter country code. Non-printable characters are
+    /// replaced with `?`.
+    pub fn code(&self) -> String {
+        (self.0 as u16)
+            .to_be_bytes()
+            .iter()
+            .map(|b| {
+                if b.is_ascii_graphic() {
+                    char::from(*b)
+                } else {
+                    '?'
+                }
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn round_trip() {
+        let code = CountryCode::new("gb").unwrap();
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_country_code(Some(code));
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+        assert!(bytes.ends_with(&[0, 0, b'G', b'B']));
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        let parsed = *parsed.payload().context().unwrap().country_code().unwrap();
+        assert_eq!(parsed, code);
+        assert_eq!(parsed.code(), "GB");
+        assert_eq!(parsed.to_string(), "GB");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(CountryCode::new("U"), Err(VitaError::OutOfRange)));
+        assert!(matches!(CountryCode::new(""), Err(VitaError::OutOfRange)));
+        assert!(matches!(
+            CountryCode::new("USA"),
+            Err(VitaError::OutOfRange)
+        ));
+        assert!(matches!(CountryCode::new("U1"), Err(VitaError::NonAscii)));
+        assert!(matches!(CountryCode::new("Ü"), Err(VitaError::NonAscii)));
+        // Values off the wire that aren't letters are still displayable
+        assert_eq!(CountryCode(0x0041).code(), "?A");
+    }
+}
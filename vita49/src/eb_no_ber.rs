@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the Eb/No and BER field
+(ANSI/VITA-49.2-2017 section 9.5.17).
+
+Either subfield may be left unspecified, in which case it carries
+the value 0x7FFF and is reported as `None`.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::optional_db::{decode, encode, UNSPECIFIED_PAIR};
+use crate::VitaError;
+
+/// Base Eb/No and BER data structure.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EbNoBer(u32);
+
+impl Default for EbNoBer {
+    fn default() -> Self {
+        EbNoBer(UNSPECIFIED_PAIR)
+    }
+}
+
+impl EbNoBer {
+    /// Create a new `EbNoBer` object given Eb/No and BER in dB. Passing
+    /// `None` leaves the subfield unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if either value can't be
+    /// represented (-256 to just under 255.99 dB).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::EbNoBer;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_eb_over_no_and_ber(Some(EbNoBer::new(Some(9.5), None)?));
+    /// let ebno = context.eb_over_no_and_ber().unwrap();
+    /// assert_eq!(ebno.eb_no_db(), Some(9.5));
+    /// assert_eq!(ebno.ber_db(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(eb_no_db: Option<f32>, ber_db: Option<f32>) -> Result<EbNoBer, VitaError> {
+        Ok(EbNoBer((encode(eb_no_db)? << 16) | encode(ber_db)?))
+    }
+
+    /// Gets the size of the Eb/No and BER structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the energy per bit to noise density ratio (dB), if specified.
+    pub fn eb_no_db(&self) -> Option<f32> {
+        decode((self.0 >> 16) as u16)
+    }
+
+    /// Sets the energy per bit to noise density ratio (dB). Passing `None`
+    /// leaves the subfield unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+    pub fn set_eb_no_db(&mut self, eb_no_db: Option<f32>) -> Result<(), VitaError> {
+        self.0 = (self.0 & 0x0000_FFFF) | (encode(eb_no_db)? << 16);
+        Ok(())
+    }
+
+    /// Gets the bit error rate (dB), if specified.
+    pub fn ber_db(&self) -> Option<f32> {
+        decode((self.0 & 0xFFFF) as u16)
+    }
+
+    /// Sets the bit error rate (dB). Passing `None` leaves the subfield
+    /// unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+    pub fn set_ber_db(&mut self, ber_db: Option<f32>) -> Result<(), VitaError> {
+        self.0 = (self.0 & 0xFFFF_0000) | encode(ber_db)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for EbNoBer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "Eb/No: ")?;
+        match self.eb_no_db() {
+            Some(v) => write!(f, "{v} dB")?,
+            None => write!(f, "unspecified")?,
+        }
+        write!(f, ", BER: ")?;
+        match self.ber_db() {
+            Some(v) => write!(f, "{v} dB"),
+            None => write!(f, "unspecified"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unspecified_subfields() {
+        let ebno = EbNoBer::default();
+        assert_eq!(ebno.eb_no_db(), None);
+        assert_eq!(ebno.ber_db(), None);
+        assert_eq!(ebno.0, 0x7FFF_7FFF);
+
+        let mut ebno = EbNoBer::new(Some(-3.5), Some(-60.0)).unwrap();
+        assert_eq!(ebno.eb_no_db(), Some(-3.5));
+        assert_eq!(ebno.ber_db(), Some(-60.0));
+        ebno.set_eb_no_db(None).unwrap();
+        assert_eq!(ebno.eb_no_db(), None);
+        assert_eq!(ebno.ber_db(), Some(-60.0));
+        // The sentinel value itself can't be set as a measurement
+        assert!(matches!(
+            ebno.set_ber_db(Some(255.992_19)),
+            Err(VitaError::OutOfRange)
+        ));
+        assert_eq!(ebno.ber_db(), Some(-60.0));
+        assert!(ebno.set_ber_db(Some(-257.0)).is_err());
+    }
+}
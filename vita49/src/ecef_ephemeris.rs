@@ -4,21 +4,33 @@
 /*!
 Data structures and methods related to the ECEF ephemeris format
 (ANSI/VITA-49.2-2017 section 9.4.3).
+
+The same format is used for the relative ephemeris field (section
+9.4.9). Any subfield that isn't known is set to a special "unspecified"
+value, which is exposed here as `None`.
 */
 
+use core::fmt;
+
+use crate::fix_prologue::FixPrologue;
+use crate::formatted_gps::{decode_i32, encode_i32, UNSPECIFIED_I32};
+use crate::{Tsf, Tsi, VitaError, VitaTimestamp};
 use deku::prelude::*;
+use fixed::types::extra::{U16, U22, U5};
+
+/// WGS-84 semi-major axis (meters).
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS-84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// WGS-84 first eccentricity squared.
+const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
 
 /// Base ECEF ephemeris data structure.
-#[derive(
-    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
-)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EcefEphemeris {
-    w1: u32,
-    ts1: u32,
-    ts2: u32,
-    ts3: u32,
+    prologue: FixPrologue,
     position_x: i32,
     position_y: i32,
     position_z: i32,
@@ -30,9 +42,333 @@ pub struct EcefEphemeris {
     velocity_dz: i32,
 }
 
+impl Default for EcefEphemeris {
+    fn default() -> Self {
+        EcefEphemeris {
+            prologue: FixPrologue::default(),
+            position_x: UNSPECIFIED_I32,
+            position_y: UNSPECIFIED_I32,
+            position_z: UNSPECIFIED_I32,
+            attitude_alpha: UNSPECIFIED_I32,
+            attitude_beta: UNSPECIFIED_I32,
+            attitude_phi: UNSPECIFIED_I32,
+            velocity_dx: UNSPECIFIED_I32,
+            velocity_dy: UNSPECIFIED_I32,
+            velocity_dz: UNSPECIFIED_I32,
+        }
+    }
+}
+
+/// Generates a getter/setter pair for a fixed point subfield.
+macro_rules! subfield {
+    ($get:ident, $set:ident, $field:ident, $frac:ty, $range:expr, $desc:literal) => {
+        #[doc = concat!("Gets the ", $desc, ".")]
+        pub fn $get(&self) -> Option<f64> {
+            decode_i32::<$frac>(self.$field)
+        }
+        #[doc = concat!("Sets the ", $desc, ". If `None` is passed, the subfield will be unspecified.")]
+        ///
+        /// # Errors
+        /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+        pub fn $set(&mut self, value: Option<f64>) -> Result<(), VitaError> {
+            self.$field = encode_i32::<$frac>(value, $range)?;
+            Ok(())
+        }
+    };
+}
+
 impl EcefEphemeris {
+    /// Create a new ECEF ephemeris structure with every subfield
+    /// unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::EcefEphemeris;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut eph = EcefEphemeris::new(0x12_3456);
+    /// eph.set_position_x_m(Some(1_115_072.5))?;
+    /// eph.set_velocity_dz_mps(Some(-2.25))?;
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_ecef_ephemeris(Some(eph));
+    ///
+    /// let eph = context.ecef_ephemeris().unwrap();
+    /// assert_eq!(eph.position_x_m(), Some(1_115_072.5));
+    /// assert_eq!(eph.position_y_m(), None);
+    /// assert_eq!(eph.velocity_dz_mps(), Some(-2.25));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(manufacturer_oui: u32) -> EcefEphemeris {
+        let mut ret = EcefEphemeris::default();
+        ret.set_manufacturer_oui(manufacturer_oui);
+        ret
+    }
+
     /// Gets the size of the ECEF ephemeris field in 32-bit words.
     pub fn size_words(&self) -> u16 {
         (std::mem::size_of_val(self) / std::mem::size_of::<u32>()) as u16
     }
+
+    /// Gets the TimeStamp-Integer (TSI) mode of the fix timestamp.
+    pub fn tsi(&self) -> Tsi {
+        self.prologue.tsi()
+    }
+
+    /// Gets the TimeStamp-Fractional (TSF) mode of the fix timestamp.
+    pub fn tsf(&self) -> Tsf {
+        self.prologue.tsf()
+    }
+
+    /// Gets the manufacturer Organizational Unique Identifier (OUI).
+    pub fn manufacturer_oui(&self) -> u32 {
+        self.prologue.manufacturer_oui()
+    }
+
+    /// Sets the manufacturer Organizational Unique Identifier (OUI).
+    ///
+    /// Note: while this API takes a 32-bit integer, only the least
+    /// significant 24 bits are used.
+    pub fn set_manufacturer_oui(&mut self, oui: u32) {
+        self.prologue.set_manufacturer_oui(oui);
+    }
+
+    /// Gets the timestamp of the position fix. If `None` is returned,
+    /// no timestamp is specified. A part (integer or fractional) whose
+    /// word holds the unspecified value is left out even if its mode
+    /// is set.
+    pub fn fix_timestamp(&self) -> Option<VitaTimestamp> {
+        self.prologue.fix_timestamp()
+    }
+
+    /// Sets the timestamp of the position fix (including the TSI/TSF
+    /// modes). If `None` is passed, the timestamp will be unspecified.
+    pub fn set_fix_timestamp(&mut self, timestamp: Option<VitaTimestamp>) {
+        self.prologue.set_fix_timestamp(timestamp);
+    }
+
+    subfield!(
+        position_x_m,
+        set_position_x_m,
+        position_x,
+        U5,
+        ..,
+        "X position (meters)"
+    );
+    subfield!(
+        position_y_m,
+        set_position_y_m,
+        position_y,
+        U5,
+        ..,
+        "Y position (meters)"
+    );
+    subfield!(
+        position_z_m,
+        set_position_z_m,
+        position_z,
+        U5,
+        ..,
+        "Z position (meters)"
+    );
+    subfield!(
+        attitude_alpha_deg,
+        set_attitude_alpha_deg,
+        attitude_alpha,
+        U22,
+        -180.0..=180.0,
+        "alpha attitude angle (degrees)"
+    );
+    subfield!(
+        attitude_beta_deg,
+        set_attitude_beta_deg,
+        attitude_beta,
+        U22,
+        -180.0..=180.0,
+        "beta attitude angle (degrees)"
+    );
+    subfield!(
+        attitude_phi_deg,
+        set_attitude_phi_deg,
+        attitude_phi,
+        U22,
+        -180.0..=180.0,
+        "phi attitude angle (degrees)"
+    );
+    subfield!(
+        velocity_dx_mps,
+        set_velocity_dx_mps,
+        velocity_dx,
+        U16,
+        ..,
+        "X velocity (meters/second)"
+    );
+    subfield!(
+        velocity_dy_mps,
+        set_velocity_dy_mps,
+        velocity_dy,
+        U16,
+        ..,
+        "Y velocity (meters/second)"
+    );
+    subfield!(
+        velocity_dz_mps,
+        set_velocity_dz_mps,
+        velocity_dz,
+        U16,
+        ..,
+        "Z velocity (meters/second)"
+    );
+
+    /// Gets the position as a WGS-84 geodetic position. If any position
+    /// component is unspecified, `None` is returned.
+    ///
+    /// Note: this only makes sense for the ECEF ephemeris field. The
+    /// relative ephemeris field isn't Earth-centered.
+    pub fn geodetic_position(&self) -> Option<GeodeticPosition> {
+        Some(GeodeticPosition::from_ecef(
+            self.position_x_m()?,
+            self.position_y_m()?,
+            self.position_z_m()?,
+        ))
+    }
+
+    /// Sets the position from a WGS-84 geodetic position. If `None` is
+    /// passed, the position will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the position can't be represented.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{EcefEphemeris, GeodeticPosition};
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut eph = EcefEphemeris::new(0);
+    /// let pos = GeodeticPosition {
+    ///     latitude_deg: 38.8895,
+    ///     longitude_deg: -77.0353,
+    ///     altitude_m: 100.0,
+    /// };
+    /// eph.set_geodetic_position(Some(pos))?;
+    /// let back = eph.geodetic_position().unwrap();
+    /// assert!((back.latitude_deg - pos.latitude_deg).abs() < 1e-6);
+    /// assert!((back.longitude_deg - pos.longitude_deg).abs() < 1e-6);
+    /// assert!((back.altitude_m - pos.altitude_m).abs() < 0.1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_geodetic_position(
+        &mut self,
+        position: Option<GeodeticPosition>,
+    ) -> Result<(), VitaError> {
+        let (x, y, z) = match position.map(|p| p.to_ecef()) {
+            Some((x, y, z)) => (Some(x), Some(y), Some(z)),
+            None => (None, None, None),
+        };
+        let mut ret = *self;
+        ret.set_position_x_m(x)?;
+        ret.set_position_y_m(y)?;
+        ret.set_position_z_m(z)?;
+        *self = ret;
+        Ok(())
+    }
+}
+
+impl fmt::Display for EcefEphemeris {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Ephemeris:")?;
+        writeln!(f, "  Manufacturer OUI: {:#x}", self.manufacturer_oui())?;
+        writeln!(f, "  Fix timestamp: {:?}", self.fix_timestamp())?;
+        writeln!(f, "  Position (m): {:?}, {:?}, {:?}", self.position_x_m(), self.position_y_m(), self.position_z_m())?;
+        writeln!(f, "  Attitude (deg): {:?}, {:?}, {:?}", self.attitude_alpha_deg(), self.attitude_beta_deg(), self.attitude_phi_deg())?;
+        writeln!(f, "  Velocity (m/s): {:?}, {:?}, {:?}", self.velocity_dx_mps(), self.velocity_dy_mps(), self.velocity_dz_mps())?;
+        Ok(())
+    }
+}
+
+/// A WGS-84 geodetic position.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeodeticPosition {
+    /// Geodetic latitude (degrees).
+    pub latitude_deg: f64,
+    /// Longitude (degrees).
+    pub longitude_deg: f64,
+    /// Height above the WGS-84 ellipsoid (meters).
+    pub altitude_m: f64,
+}
+
+impl GeodeticPosition {
+    /// Converts an ECEF position (meters) into a geodetic position.
+    pub fn from_ecef(x_m: f64, y_m: f64, z_m: f64) -> GeodeticPosition {
+        let p = x_m.hypot(y_m);
+        let longitude = y_m.atan2(x_m);
+        // Iterate on the latitude; this converges to well under a
+        // millimeter within a handful of iterations.
+        let mut latitude = z_m.atan2(p * (1.0 - WGS84_E2));
+        let mut altitude = 0.0;
+        for _ in 0..8 {
+            let sin_lat = latitude.sin();
+            let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+            altitude = p * latitude.cos() + (z_m + WGS84_E2 * n * sin_lat) * sin_lat - n;
+            latitude = z_m.atan2(p * (1.0 - WGS84_E2 * n / (n + altitude)));
+        }
+        GeodeticPosition {
+            latitude_deg: latitude.to_degrees(),
+            longitude_deg: longitude.to_degrees(),
+            altitude_m: altitude,
+        }
+    }
+
+    /// Converts the geodetic position into an ECEF position (meters),
+    /// returned as `(x, y, z)`.
+    pub fn to_ecef(&self) -> (f64, f64, f64) {
+        let lat = self.latitude_deg.to_radians();
+        let lon = self.longitude_deg.to_radians();
+        let n = WGS84_A / (1.0 - WGS84_E2 * lat.sin() * lat.sin()).sqrt();
+        (
+            (n + self.altitude_m) * lat.cos() * lon.cos(),
+            (n + self.altitude_m) * lat.cos() * lon.sin(),
+            (n * (1.0 - WGS84_E2) + self.altitude_m) * lat.sin(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeodeticPosition;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn geodetic_round_trip() {
+        for (lat, lon, alt) in [
+            (0.0, 0.0, 0.0),
+            (38.8895, -77.0353, 12.0),
+            (-33.8688, 151.2093, 1500.0),
+            (89.9999, 45.0, -20.0),
+            (-90.0, 0.0, 0.0),
+        ] {
+            let pos = GeodeticPosition {
+                latitude_deg: lat,
+                longitude_deg: lon,
+                altitude_m: alt,
+            };
+            let (x, y, z) = pos.to_ecef();
+            let back = GeodeticPosition::from_ecef(x, y, z);
+            assert_abs_diff_eq!(back.latitude_deg, lat, epsilon = 1e-9);
+            assert_abs_diff_eq!(back.altitude_m, alt, epsilon = 1e-4);
+            if lat.abs() < 90.0 {
+                assert_abs_diff_eq!(back.longitude_deg, lon, epsilon = 1e-9);
+            }
+        }
+        // Equator/prime meridian is exactly on the X axis
+        let (x, y, z) = GeodeticPosition::default().to_ecef();
+        assert_abs_diff_eq!(x, 6_378_137.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(y, 0.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(z, 0.0, epsilon = 1e-6);
+    }
 }
@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the EMS device class field
+(ANSI/VITA-49.2-2017 section 9.8.9).
+
+Bits 15-14 give the organization relationship of the device, bit 13
+flags an exciter, bit 12 flags a receiver, and bits 11-0 hold the
+device class code. Bits 31-16 are reserved.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::EmsOrganizationRelationship;
+
+const EXCITER_BIT: u32 = 1 << 13;
+const RECEIVER_BIT: u32 = 1 << 12;
+
+/// Base EMS device class data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmsDeviceClass(u32);
+
+impl EmsDeviceClass {
+    /// Create a new EMS device class.
+    ///
+    /// Note: while this API takes a 16-bit integer, only the least
+    /// significant 12 bits of `class_code` are used.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{EmsDeviceClass, EmsOrganizationRelationship};
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// let class = EmsDeviceClass::new(EmsOrganizationRelationship::Known, false, true, 0x42);
+    /// context.set_ems_device_class(Some(class));
+    /// let class = context.ems_device_class().unwrap();
+    /// assert_eq!(class.org(), EmsOrganizationRelationship::Known);
+    /// assert!(class.is_receiver());
+    /// assert!(!class.is_exciter());
+    /// assert_eq!(class.class_code(), 0x42);
+    /// ```
+    pub fn new(
+        org: EmsOrganizationRelationship,
+        exciter: bool,
+        receiver: bool,
+        class_code: u16,
+    ) -> EmsDeviceClass {
+        let org = match org {
+            EmsOrganizationRelationship::Coalition => 0b00,
+            EmsOrganizationRelationship::Known => 0b01,
+            EmsOrganizationRelationship::Unknown => 0b10,
+            EmsOrganizationRelationship::Reserved => 0b11,
+        };
+        let mut ret = EmsDeviceClass((org << 14) | (class_code as u32 & 0xFFF));
+        ret.set_exciter(exciter);
+        ret.set_receiver(receiver);
+        ret
+    }
+
+    /// Gets the size of the EMS device class structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the raw 32-bit value of the field.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the device organization relationship.
+    pub fn org(&self) -> EmsOrganizationRelationship {
+        match (self.0 >> 14) & 0b11 {
+            0b00 => EmsOrganizationRelationship::Coalition,
+            0b01 => EmsOrganizationRelationship::Known,
+            0b10 => EmsOrganizationRelationship::Unknown,
+            0b11 => EmsOrganizationRelationship::Reserved,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns true if the device is an exciter, false if not.
+    pub fn is_exciter(&self) -> bool {
+        self.0 & EXCITER_BIT != 0
+    }
+
+    /// Sets whether the device is an exciter.
+    pub fn set_exciter(&mut self, exciter: bool) {
+        if exciter {
+            self.0 |= EXCITER_BIT;
+        } else {
+            self.0 &= !EXCITER_BIT;
+        }
+    }
+
+    /// Returns true if the device is a receiver, false if not.
+    pub fn is_receiver(&self) -> bool {
+        self.0 & RECEIVER_BIT != 0
+    }
+
+    /// Sets whether the device is a receiver.
+    pub fn set_receiver(&mut self, receiver: bool) {
+        if receiver {
+            self.0 |= RECEIVER_BIT;
+        } else {
+            self.0 &= !RECEIVER_BIT;
+        }
+    }
+
+    /// Gets the device class code (bits 11-0).
+    pub fn class_code(&self) -> u16 {
+        (self.0 & 0xFFF) as u16
+    }
+}
+
+impl fmt::Display for EmsDeviceClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{:?}, Exciter: {}, Receiver: {}, Class: {:#05x}",
+            self.org(),
+            self.is_exciter(),
+            self.is_receiver(),
+            self.class_code()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn round_trip() {
+        for org in [
+            EmsOrganizationRelationship::Coalition,
+            EmsOrganizationRelationship::Known,
+            EmsOrganizationRelationship::Unknown,
+            EmsOrganizationRelationship::Reserved,
+        ] {
+            let class = EmsDeviceClass::new(org, true, false, 0xABC);
+            let mut packet = Vrt::new_context_packet();
+            let context = packet.payload_mut().context_mut().unwrap();
+            context.set_ems_device_class(Some(class));
+            packet.update_packet_size();
+            let parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+            let parsed = *parsed
+                .payload()
+                .context()
+                .unwrap()
+                .ems_device_class()
+                .unwrap();
+            assert_eq!(parsed, class);
+            assert_eq!(parsed.org(), org);
+            assert!(parsed.is_exciter());
+            assert!(!parsed.is_receiver());
+            assert_eq!(parsed.class_code(), 0xABC);
+        }
+    }
+
+    #[test]
+    fn bit_layout() {
+        let mut class =
+            EmsDeviceClass::new(EmsOrganizationRelationship::Unknown, true, true, 0xFFFF);
+        // Class codes are truncated to 12 bits and don't spill into the flags
+        assert_eq!(
+            class.as_u32(),
+            (0b10 << 14) | EXCITER_BIT | RECEIVER_BIT | 0xFFF
+        );
+        class.set_exciter(false);
+        assert_eq!(class.as_u32(), (0b10 << 14) | RECEIVER_BIT | 0xFFF);
+        class.set_receiver(false);
+        assert_eq!(class.as_u32(), (0b10 << 14) | 0xFFF);
+        assert_eq!(class.org(), EmsOrganizationRelationship::Unknown);
+        assert_eq!(
+            class.to_string(),
+            "Unknown, Exciter: false, Receiver: false, Class: 0xfff"
+        );
+    }
+}
@@ -64,4 +64,37 @@ pub enum VitaError {
     /// Error given when trying to set a reserved value.
     #[error("attempted to set reserved field")]
     ReservedField,
+    /// Error given when a buffer is too short to hold the data being
+    /// decoded from it.
+    #[error("buffer too short")]
+    BufferTooShort,
+    /// Error given when a packet header carries a reserved packet type.
+    #[error("reserved packet type")]
+    ReservedPacketType,
+    /// Error given when text that must be ASCII contains other characters.
+    #[error("text must be ASCII")]
+    NonAscii,
+    /// Error given when an NMEA sentence is malformed or fails its checksum.
+    #[error("invalid NMEA sentence")]
+    InvalidNmea,
+    /// Error given when the records of an array-of-records field don't
+    /// all carry the same subfields.
+    #[error("array-of-records entries must carry the same subfields")]
+    MismatchedRecords,
+    /// Error given when the records of an array of CIFs would carry an
+    /// array of CIFs themselves.
+    #[error("array of CIFs records can't carry a nested array of CIFs")]
+    NestedArrayOfCifs,
+    /// Error given when a CIF7 attribute can't be used in a given
+    /// place (e.g. probability/belief through a numeric accessor, or
+    /// any attribute on a structure without CIF7).
+    #[error("CIF7 attribute not supported here")]
+    UnsupportedCif7Attr,
+    /// Error given when a [`FieldValue`](crate::FieldValue) doesn't hold
+    /// the type the targeted CIF field uses.
+    #[error("field value type doesn't match the CIF field")]
+    FieldValueMismatch,
+    /// Error given when a CIF field name isn't recognized.
+    #[error("unknown CIF field")]
+    UnknownField,
 }
@@ -0,0 +1,716 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Dynamic access to CIF0-CIF3 fields by identifier.
+
+Each CIF field normally has its own generated accessors (e.g.
+`bandwidth_hz()`/`set_bandwidth_hz()`). For generic tooling (config files,
+scripting, CLIs, diffing), [`CifField`] identifies a field at runtime and
+[`FieldValue`] holds a value of any field type. The [`CifFieldAccess`]
+trait then reads and writes fields on anything implementing all of the
+CIF0-CIF3 manipulator traits (context, control, and query-acknowledge
+packets as well as array-of-CIFs records).
+
+Field names match the typed getters, so `"bandwidth_hz"` parses to
+[`CifField::Bandwidth`] and its values use the same units.
+
+ACK packets report per-field warnings and errors instead of values; those
+are reached with [`Ack::field_response()`](crate::Ack::field_response())
+and [`Ack::set_field_response()`](crate::Ack::set_field_response()).
+
+```
+use vita49::prelude::*;
+use vita49::{CifField, CifFieldAccess, FieldValue};
+# fn main() -> Result<(), VitaError> {
+let mut packet = Vrt::new_context_packet();
+let context = packet.payload_mut().context_mut().unwrap();
+let field: CifField = "bandwidth_hz".parse()?;
+context.set(field, FieldValue::F64(8e6))?;
+context.set(CifField::NetworkId, 7u32.into())?;
+assert_eq!(context.bandwidth_hz(), Some(8e6));
+assert_eq!(context.get(CifField::NetworkId), Some(FieldValue::U32(7)));
+assert_eq!(
+    context.present_fields().collect::<Vec<_>>(),
+    vec![CifField::Bandwidth, CifField::NetworkId]
+);
+assert!(context.set(CifField::Bandwidth, FieldValue::U32(1)).is_err());
+# Ok(())
+# }
+```
+*/
+
+use core::fmt;
+use core::str::FromStr;
+use fixed::{
+    types::extra::{U20, U6, U7},
+    FixedI16, FixedI32, FixedI64, FixedU64,
+};
+
+use crate::cif0::{Cif0, Cif0AckManipulators, Cif0Manipulators};
+use crate::cif1::{Cif1, Cif1AckManipulators, Cif1Manipulators};
+use crate::cif2::{Cif2, Cif2AckManipulators, Cif2Manipulators};
+use crate::cif3::{Cif3, Cif3AckManipulators, Cif3Manipulators};
+use crate::{
+    Ack, AckLevel, AckResponse, ArrayOfCifs, BeamWidths, BufferSize, ContextAssociationLists,
+    CountryCode, DeviceId, EbNoBer, EcefEphemeris, EmsDeviceClass, FieldTimestamp, FormattedGps,
+    Gain, GpsAscii, HealthStatus, IndexList, InterceptPoints, Operator, PlatformDisplay,
+    PointingVector, PointingVectorStruct, Polarization, SectorScan, SnrNoiseFigure, Spectrum,
+    StateEventIndicators, Threshold, TimeInterval, TimestampDetails, V49SpecCompliance,
+    VersionBuildCode, VitaError,
+};
+
+macro_rules! field_values {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        /// A value of any CIF0-CIF3 field, in the same form the typed
+        /// accessors use.
+        #[derive(Clone, PartialEq, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[allow(missing_docs)]
+        pub enum FieldValue {
+            $($variant($ty),)*
+        }
+
+        $(
+            impl From<$ty> for FieldValue {
+                fn from(value: $ty) -> Self {
+                    FieldValue::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+field_values! {
+    U32(u32),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    F32(f32),
+    F64(f64),
+    Gain(Gain),
+    DeviceId(DeviceId),
+    StateEventIndicators(StateEventIndicators),
+    FormattedGps(FormattedGps),
+    EcefEphemeris(EcefEphemeris),
+    GpsAscii(GpsAscii),
+    ContextAssociationLists(ContextAssociationLists),
+    Polarization(Polarization),
+    PointingVector(PointingVector),
+    PointingVectorStruct(PointingVectorStruct),
+    BeamWidths(BeamWidths),
+    EbNoBer(EbNoBer),
+    Threshold(Threshold),
+    InterceptPoints(InterceptPoints),
+    SnrNoiseFigure(SnrNoiseFigure),
+    ArrayOfCifs(ArrayOfCifs),
+    Spectrum(Spectrum),
+    SectorScan(SectorScan),
+    IndexList(IndexList),
+    HealthStatus(HealthStatus),
+    V49SpecCompliance(V49SpecCompliance),
+    VersionBuildCode(VersionBuildCode),
+    BufferSize(BufferSize),
+    CountryCode(CountryCode),
+    Operator(Operator),
+    PlatformDisplay(PlatformDisplay),
+    EmsDeviceClass(EmsDeviceClass),
+    TimestampDetails(TimestampDetails),
+    TimeInterval(TimeInterval),
+    FieldTimestamp(FieldTimestamp),
+}
+
+/// Generates `CifField` and `CifFieldAccess` from the field table.
+///
+/// Each row is `Variant: cif, indicator, set_indicator, unset_indicator =>
+/// getter, setter, FieldValue variant, kind`, where `kind` is `ref` for
+/// getters returning references, `val` for getters returning values, and
+/// `checked` for range-checked setters returning a `Result`.
+macro_rules! cif_field_table {
+    ($(
+        $variant:ident: $cif:tt, $ind:ident, $set_ind:ident, $unset_ind:ident
+            => $get:ident, $set:ident, $value:ident, $kind:ident $(($fixed:ty))?;
+    )*) => {
+        /// Identifies a CIF0-CIF3 field.
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[allow(missing_docs)]
+        pub enum CifField {
+            $($variant,)*
+        }
+
+        impl CifField {
+            /// Every field, in packet order.
+            pub const ALL: &'static [CifField] = &[$(CifField::$variant,)*];
+
+            /// Gets the number of the CIF (0-3) holding the field.
+            pub fn cif(&self) -> u8 {
+                match self {
+                    $(CifField::$variant => $cif,)*
+                }
+            }
+
+            /// Gets the field name (the name of its typed getter,
+            /// e.g. `bandwidth_hz`).
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(CifField::$variant => stringify!($get),)*
+                }
+            }
+
+            /// Returns true if the field's indicator bit is set.
+            pub(crate) fn is_set_in(
+                &self,
+                cif0: &Cif0,
+                cif1: Option<&Cif1>,
+                cif2: Option<&Cif2>,
+                cif3: Option<&Cif3>,
+            ) -> bool {
+                match self {
+                    $(CifField::$variant => {
+                        cif_field_table!(@is_set $cif, $ind, cif0, cif1, cif2, cif3)
+                    })*
+                }
+            }
+
+            /// Sets or clears the field's indicator bit, adding or
+            /// removing CIF1-CIF3 as needed.
+            pub(crate) fn set_in(
+                &self,
+                cif0: &mut Cif0,
+                cif1: &mut Option<Cif1>,
+                cif2: &mut Option<Cif2>,
+                cif3: &mut Option<Cif3>,
+                enabled: bool,
+            ) {
+                match self {
+                    $(CifField::$variant => {
+                        cif_field_table!(
+                            @set_in $cif, $set_ind, $unset_ind, enabled, cif0, cif1, cif2, cif3
+                        )
+                    })*
+                }
+            }
+        }
+
+        impl FromStr for CifField {
+            type Err = VitaError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($get) => Ok(CifField::$variant),)*
+                    _ => Err(VitaError::UnknownField),
+                }
+            }
+        }
+
+        impl Ack {
+            /// Get the warning and error responses for a field, as
+            /// `(warning, error)`. A field can carry both at once; `None`
+            /// means the ACK doesn't report anything at that level.
+            pub fn field_response(
+                &self,
+                field: CifField,
+            ) -> (Option<AckResponse>, Option<AckResponse>) {
+                match field {
+                    $(CifField::$variant => cif_field_table!(@ack_get $cif, self, $ind),)*
+                }
+            }
+
+            /// Set (or, if `response` is `None`, clear) the warning or
+            /// error response for a field.
+            ///
+            /// [`update_packet_size()`](crate::Vrt::update_packet_size()) should be executed after running this method.
+            pub fn set_field_response(
+                &mut self,
+                field: CifField,
+                level: AckLevel,
+                response: Option<AckResponse>,
+            ) {
+                match field {
+                    $(CifField::$variant => {
+                        cif_field_table!(@ack_set $cif, self, $set_ind, level, response)
+                    })*
+                }
+            }
+        }
+
+        impl<T> CifFieldAccess for T
+        where
+            T: Cif0Manipulators + Cif1Manipulators + Cif2Manipulators + Cif3Manipulators,
+        {
+            fn get(&self, field: CifField) -> Option<FieldValue> {
+                match field {
+                    $(CifField::$variant => {
+                        cif_field_table!(@get $kind, self, $get).map(FieldValue::$value)
+                    })*
+                }
+            }
+
+            fn set(&mut self, field: CifField, value: FieldValue) -> Result<(), VitaError> {
+                match (field, value) {
+                    $((CifField::$variant, FieldValue::$value(v)) => {
+                        cif_field_table!(@set $kind, self, $set, v $(, $fixed)?)
+                    })*
+                    _ => Err(VitaError::FieldValueMismatch),
+                }
+            }
+
+            fn unset(&mut self, field: CifField) {
+                match field {
+                    $(CifField::$variant => cif_field_table!(@unset $kind, self, $set),)*
+                }
+            }
+
+            fn present_fields(&self) -> Box<dyn Iterator<Item = CifField> + '_> {
+                Box::new(CifField::ALL.iter().copied().filter(move |f| {
+                    f.is_set_in(
+                        Cif0Manipulators::cif0(self),
+                        Cif1Manipulators::cif1(self),
+                        Cif2Manipulators::cif2(self),
+                        Cif3Manipulators::cif3(self),
+                    )
+                }))
+            }
+        }
+    };
+
+    (@get ref, $s:ident, $get:ident) => { $s.$get().cloned() };
+    (@get radix, $s:ident, $get:ident) => { $s.$get() };
+    (@get checked, $s:ident, $get:ident) => { $s.$get() };
+
+    (@set ref, $s:ident, $set:ident, $v:expr) => {{
+        $s.$set(Some($v));
+        Ok(())
+    }};
+    // The typed setter panics on values its fixed-point encoding can't
+    // hold (including NaN and infinity), so check those first
+    (@set radix, $s:ident, $set:ident, $v:expr, $fixed:ty) => {
+        match <$fixed>::checked_from_num($v) {
+            Some(_) => {
+                $s.$set(Some($v));
+                Ok(())
+            }
+            None => Err(VitaError::OutOfRange),
+        }
+    };
+    (@set checked, $s:ident, $set:ident, $v:expr) => { $s.$set(Some($v)) };
+
+    (@unset ref, $s:ident, $set:ident) => { $s.$set(None) };
+    (@unset radix, $s:ident, $set:ident) => { $s.$set(None) };
+    // Unsetting a field is never out of range
+    (@unset checked, $s:ident, $set:ident) => { $s.$set(None).unwrap_or_default() };
+
+    (@ack_get 0, $s:ident, $ind:ident) => {(
+        Cif0AckManipulators::wif0_fields($s).and_then(|f| f.$ind),
+        Cif0AckManipulators::eif0_fields($s).and_then(|f| f.$ind),
+    )};
+    (@ack_get 1, $s:ident, $ind:ident) => {(
+        $s.wif1_fields().and_then(|f| f.$ind),
+        $s.eif1_fields().and_then(|f| f.$ind),
+    )};
+    (@ack_get 2, $s:ident, $ind:ident) => {(
+        $s.wif2_fields().and_then(|f| f.$ind),
+        $s.eif2_fields().and_then(|f| f.$ind),
+    )};
+    (@ack_get 3, $s:ident, $ind:ident) => {(
+        $s.wif3_fields().and_then(|f| f.$ind),
+        $s.eif3_fields().and_then(|f| f.$ind),
+    )};
+
+    (@ack_set 0, $s:ident, $set_ind:ident, $l:ident, $r:ident) => {
+        Cif0AckManipulators::$set_ind($s, $l, $r)
+    };
+    (@ack_set 1, $s:ident, $set_ind:ident, $l:ident, $r:ident) => {
+        Cif1AckManipulators::$set_ind($s, $l, $r)
+    };
+    (@ack_set 2, $s:ident, $set_ind:ident, $l:ident, $r:ident) => {
+        Cif2AckManipulators::$set_ind($s, $l, $r)
+    };
+    (@ack_set 3, $s:ident, $set_ind:ident, $l:ident, $r:ident) => {
+        Cif3AckManipulators::$set_ind($s, $l, $r)
+    };
+
+    (@is_set 0, $ind:ident, $c0:ident, $c1:ident, $c2:ident, $c3:ident) => { $c0.$ind() };
+    (@is_set 1, $ind:ident, $c0:ident, $c1:ident, $c2:ident, $c3:ident) => {
+        $c1.is_some_and(|c| c.$ind())
+    };
+    (@is_set 2, $ind:ident, $c0:ident, $c1:ident, $c2:ident, $c3:ident) => {
+        $c2.is_some_and(|c| c.$ind())
+    };
+    (@is_set 3, $ind:ident, $c0:ident, $c1:ident, $c2:ident, $c3:ident) => {
+        $c3.is_some_and(|c| c.$ind())
+    };
+
+    (@set_in 0, $set_ind:ident, $unset_ind:ident, $on:ident,
+        $c0:ident, $c1:ident, $c2:ident, $c3:ident) => {
+        if $on {
+            $c0.$set_ind()
+        } else {
+            $c0.$unset_ind()
+        }
+    };
+    (@set_in 1, $set_ind:ident, $unset_ind:ident, $on:ident,
+        $c0:ident, $c1:ident, $c2:ident, $c3:ident) => {
+        cif_field_table!(@set_in_cif $set_ind, $unset_ind, $on, $c0, $c1,
+            set_cif1_enabled, unset_cif1_enabled)
+    };
+    (@set_in 2, $set_ind:ident, $unset_ind:ident, $on:ident,
+        $c0:ident, $c1:ident, $c2:ident, $c3:ident) => {
+        cif_field_table!(@set_in_cif $set_ind, $unset_ind, $on, $c0, $c2,
+            set_cif2_enabled, unset_cif2_enabled)
+    };
+    (@set_in 3, $set_ind:ident, $unset_ind:ident, $on:ident,
+        $c0:ident, $c1:ident, $c2:ident, $c3:ident) => {
+        cif_field_table!(@set_in_cif $set_ind, $unset_ind, $on, $c0, $c3,
+            set_cif3_enabled, unset_cif3_enabled)
+    };
+    (@set_in_cif $set_ind:ident, $unset_ind:ident, $on:ident, $c0:ident, $c:ident,
+        $enable:ident, $disable:ident) => {
+        if $on {
+            if $c.is_none() {
+                $c0.$enable();
+            }
+            $c.get_or_insert_with(Default::default).$set_ind();
+        } else if let Some(c) = $c.as_mut() {
+            c.$unset_ind();
+            if c.as_u32() == 0 {
+                *$c = None;
+                $c0.$disable();
+            }
+        }
+    };
+}
+
+cif_field_table! {
+    ReferencePointId: 0, reference_point_id, set_reference_point_id, unset_reference_point_id
+        => reference_point_id, set_reference_point_id, U32, ref;
+    Bandwidth: 0, bandwidth, set_bandwidth, unset_bandwidth
+        => bandwidth_hz, set_bandwidth_hz, F64, radix(FixedU64::<U20>);
+    IfRefFreq: 0, if_ref_freq, set_if_ref_freq, unset_if_ref_freq
+        => if_ref_freq_hz, set_if_ref_freq_hz, F64, radix(FixedU64::<U20>);
+    RfRefFreq: 0, rf_ref_freq, set_rf_ref_freq, unset_rf_ref_freq
+        => rf_ref_freq_hz, set_rf_ref_freq_hz, F64, radix(FixedU64::<U20>);
+    RfRefFreqOffset: 0, rf_ref_freq_offset, set_rf_ref_freq_offset, unset_rf_ref_freq_offset
+        => rf_ref_freq_offset_hz, set_rf_ref_freq_offset_hz, F64, radix(FixedI64::<U20>);
+    IfBandOffset: 0, if_band_offset, set_if_band_offset, unset_if_band_offset
+        => if_band_offset_hz, set_if_band_offset_hz, F64, radix(FixedI64::<U20>);
+    ReferenceLevel: 0, reference_level, set_reference_level, unset_reference_level
+        => reference_level_db, set_reference_level_db, F32, radix(FixedI16::<U7>);
+    Gain: 0, gain, set_gain, unset_gain
+        => gain, set_gain, Gain, ref;
+    OverRangeCount: 0, over_range_count, set_over_range_count, unset_over_range_count
+        => over_range_count, set_over_range_count, U32, ref;
+    SampleRate: 0, sample_rate, set_sample_rate, unset_sample_rate
+        => sample_rate_sps, set_sample_rate_sps, F64, radix(FixedU64::<U20>);
+    TimestampAdjustment: 0, timestamp_adjustment, set_timestamp_adjustment, unset_timestamp_adjustment
+        => timestamp_adjustment_fs, set_timestamp_adjustment_fs, I64, ref;
+    TimestampCalTime: 0, timestamp_cal_time, set_timestamp_cal_time, unset_timestamp_cal_time
+        => timestamp_cal_time, set_timestamp_cal_time, U32, ref;
+    Temperature: 0, temperature, set_temperature, unset_temperature
+        => temperature_c, set_temperature_c, F32, checked;
+    DeviceId: 0, device_id, set_device_id, unset_device_id
+        => device_id, set_device_id, DeviceId, ref;
+    StateIndicators: 0, state_indicators, set_state_indicators, unset_state_indicators
+        => state_indicators, set_state_indicators, StateEventIndicators, ref;
+    SignalDataPayloadFormat: 0, signal_data_payload_format, set_signal_data_payload_format,
+        unset_signal_data_payload_format
+        => signal_data_payload_format, set_signal_data_payload_format, U64, ref;
+    FormattedGps: 0, formatted_gps, set_formatted_gps, unset_formatted_gps
+        => formatted_gps, set_formatted_gps, FormattedGps, ref;
+    FormattedIns: 0, formatted_ins, set_formatted_ins, unset_formatted_ins
+        => formatted_ins, set_formatted_ins, FormattedGps, ref;
+    EcefEphemeris: 0, ecef_ephemeris, set_ecef_ephemeris, unset_ecef_ephemeris
+        => ecef_ephemeris, set_ecef_ephemeris, EcefEphemeris, ref;
+    RelativeEphemeris: 0, relative_ephemeris, set_relative_ephemeris, unset_relative_ephemeris
+        => relative_ephemeris, set_relative_ephemeris, EcefEphemeris, ref;
+    EphemerisRefId: 0, ephemeris_ref_id, set_ephemeris_ref_id, unset_ephemeris_ref_id
+        => ephemeris_ref_id, set_ephemeris_ref_id, U32, ref;
+    GpsAscii: 0, gps_ascii, set_gps_ascii, unset_gps_ascii
+        => gps_ascii, set_gps_ascii, GpsAscii, ref;
+    ContextAssociationLists: 0, context_association_lists, set_context_association_lists,
+        unset_context_association_lists
+        => context_association_lists, set_context_association_lists, ContextAssociationLists, ref;
+
+    PhaseOffset: 1, phase_offset, set_phase_offset, unset_phase_offset
+        => phase_offset_radians, set_phase_offset_radians, F32, checked;
+    Polarization: 1, polarization, set_polarization, unset_polarization
+        => polarization, set_polarization, Polarization, ref;
+    ThreeDPointingVector: 1, three_d_pointing_vector, set_three_d_pointing_vector,
+        unset_three_d_pointing_vector
+        => three_d_pointing_vector, set_three_d_pointing_vector, PointingVector, ref;
+    ThreeDPointingVectorStruct: 1, three_d_pointing_vector_struct,
+        set_three_d_pointing_vector_struct, unset_three_d_pointing_vector_struct
+        => three_d_pointing_vector_struct, set_three_d_pointing_vector_struct,
+        PointingVectorStruct, ref;
+    SpatialScanType: 1, spatial_scan_type, set_spatial_scan_type, unset_spatial_scan_type
+        => spatial_scan_type, set_spatial_scan_type, U32, ref;
+    SpatialRefType: 1, spatial_ref_type, set_spatial_ref_type, unset_spatial_ref_type
+        => spatial_ref_type, set_spatial_ref_type, U32, ref;
+    BeamWidths: 1, beam_widths, set_beam_widths, unset_beam_widths
+        => beam_widths, set_beam_widths, BeamWidths, ref;
+    Range: 1, range, set_range, unset_range
+        => range_m, set_range_m, F32, radix(FixedI32::<U6>);
+    EbOverNoAndBer: 1, eb_over_no_and_ber, set_eb_over_no_and_ber, unset_eb_over_no_and_ber
+        => eb_over_no_and_ber, set_eb_over_no_and_ber, EbNoBer, ref;
+    Threshold: 1, threshold, set_threshold, unset_threshold
+        => threshold, set_threshold, Threshold, ref;
+    CompressionPoint: 1, compression_point, set_compression_point, unset_compression_point
+        => compression_point_dbm, set_compression_point_dbm, F32, radix(FixedI16::<U7>);
+    SecondAndThirdOrderInterceptPoints: 1, second_and_third_order_intercept_points,
+        set_second_and_third_order_intercept_points, unset_second_and_third_order_intercept_points
+        => second_and_third_order_intercept_points, set_second_and_third_order_intercept_points,
+        InterceptPoints, ref;
+    SnrFigure: 1, snr_figure, set_snr_figure, unset_snr_figure
+        => snr_figure, set_snr_figure, SnrNoiseFigure, ref;
+    AuxFreq: 1, aux_freq, set_aux_freq, unset_aux_freq
+        => aux_freq_hz, set_aux_freq_hz, F64, radix(FixedU64::<U20>);
+    AuxGain: 1, aux_gain, set_aux_gain, unset_aux_gain
+        => aux_gain, set_aux_gain, Gain, ref;
+    AuxBandwidth: 1, aux_bandwidth, set_aux_bandwidth, unset_aux_bandwidth
+        => aux_bandwidth_hz, set_aux_bandwidth_hz, F64, radix(FixedU64::<U20>);
+    ArrayOfCifs: 1, array_of_cifs, set_array_of_cifs, unset_array_of_cifs
+        => array_of_cifs, set_array_of_cifs, ArrayOfCifs, ref;
+    Spectrum: 1, spectrum, set_spectrum, unset_spectrum
+        => spectrum, set_spectrum, Spectrum, ref;
+    SectorScan: 1, sector_scan, set_sector_scan, unset_sector_scan
+        => sector_scan, set_sector_scan, SectorScan, ref;
+    IndexList: 1, index_list, set_index_list, unset_index_list
+        => index_list, set_index_list, IndexList, ref;
+    DiscreteIo32: 1, discrete_io_32, set_discrete_io_32, unset_discrete_io_32
+        => discrete_io_32, set_discrete_io_32, U32, ref;
+    DiscreteIo64: 1, discrete_io_64, set_discrete_io_64, unset_discrete_io_64
+        => discrete_io_64, set_discrete_io_64, U64, ref;
+    HealthStatus: 1, health_status, set_health_status, unset_health_status
+        => health_status, set_health_status, HealthStatus, ref;
+    V49SpecCompliance: 1, v49_spec_compliance, set_v49_spec_compliance, unset_v49_spec_compliance
+        => v49_spec_compliance, set_v49_spec_compliance, V49SpecCompliance, ref;
+    VersionAndBuildCode: 1, version_and_build_code, set_version_and_build_code,
+        unset_version_and_build_code
+        => version_and_build_code, set_version_and_build_code, VersionBuildCode, ref;
+    BufferSize: 1, buffer_size, set_buffer_size, unset_buffer_size
+        => buffer_size, set_buffer_size, BufferSize, ref;
+
+    Bind: 2, bind, set_bind, unset_bind
+        => bind, set_bind, U32, ref;
+    CitedSid: 2, cited_sid, set_cited_sid, unset_cited_sid
+        => cited_sid, set_cited_sid, U32, ref;
+    SiblingSid: 2, sibling_sid, set_sibling_sid, unset_sibling_sid
+        => sibling_sid, set_sibling_sid, U32, ref;
+    ParentSid: 2, parent_sid, set_parent_sid, unset_parent_sid
+        => parent_sid, set_parent_sid, U32, ref;
+    ChildSid: 2, child_sid, set_child_sid, unset_child_sid
+        => child_sid, set_child_sid, U32, ref;
+    CitedMessageId: 2, cited_message_id, set_cited_message_id, unset_cited_message_id
+        => cited_message_id, set_cited_message_id, U32, ref;
+    ControlleeId: 2, controllee_id, set_controllee_id, unset_controllee_id
+        => controllee_id, set_controllee_id, U32, ref;
+    ControlleeUuid: 2, controllee_uuid, set_controllee_uuid, unset_controllee_uuid
+        => controllee_uuid, set_controllee_uuid, U128, ref;
+    ControllerId: 2, controller_id, set_controller_id, unset_controller_id
+        => controller_id, set_controller_id, U32, ref;
+    ControllerUuid: 2, controller_uuid, set_controller_uuid, unset_controller_uuid
+        => controller_uuid, set_controller_uuid, U128, ref;
+    InfoSourceId: 2, info_source_id, set_info_source_id, unset_info_source_id
+        => info_source_id, set_info_source_id, U32, ref;
+    TrackId: 2, track_id, set_track_id, unset_track_id
+        => track_id, set_track_id, U32, ref;
+    CountryCode: 2, country_code, set_country_code, unset_country_code
+        => country_code, set_country_code, CountryCode, ref;
+    Operator: 2, operator, set_operator, unset_operator
+        => operator, set_operator, Operator, ref;
+    PlatformClass: 2, platform_class, set_platform_class, unset_platform_class
+        => platform_class, set_platform_class, U32, ref;
+    PlatformInstance: 2, platform_instance, set_platform_instance, unset_platform_instance
+        => platform_instance, set_platform_instance, U32, ref;
+    PlatformDisplay: 2, platform_display, set_platform_display, unset_platform_display
+        => platform_display, set_platform_display, PlatformDisplay, ref;
+    EmsDeviceClass: 2, ems_device_class, set_ems_device_class, unset_ems_device_class
+        => ems_device_class, set_ems_device_class, EmsDeviceClass, ref;
+    EmsDeviceType: 2, ems_device_type, set_ems_device_type, unset_ems_device_type
+        => ems_device_type, set_ems_device_type, U32, ref;
+    EmsDeviceInstance: 2, ems_device_instance, set_ems_device_instance, unset_ems_device_instance
+        => ems_device_instance, set_ems_device_instance, U32, ref;
+    ModulationClass: 2, modulation_class, set_modulation_class, unset_modulation_class
+        => modulation_class, set_modulation_class, U32, ref;
+    ModulationType: 2, modulation_type, set_modulation_type, unset_modulation_type
+        => modulation_type, set_modulation_type, U32, ref;
+    FunctionId: 2, function_id, set_function_id, unset_function_id
+        => function_id, set_function_id, U32, ref;
+    ModeId: 2, mode_id, set_mode_id, unset_mode_id
+        => mode_id, set_mode_id, U32, ref;
+    EventId: 2, event_id, set_event_id, unset_event_id
+        => event_id, set_event_id, U32, ref;
+    FunctionPriorityId: 2, function_priority_id, set_function_priority_id,
+        unset_function_priority_id
+        => function_priority_id, set_function_priority_id, U32, ref;
+    CommsPriorityId: 2, comms_priority_id, set_comms_priority_id, unset_comms_priority_id
+        => comms_priority_id, set_comms_priority_id, U32, ref;
+    RfFootprint: 2, rf_footprint, set_rf_footprint, unset_rf_footprint
+        => rf_footprint, set_rf_footprint, U32, ref;
+    RfFootprintRange: 2, rf_footprint_range, set_rf_footprint_range, unset_rf_footprint_range
+        => rf_footprint_range, set_rf_footprint_range, U32, ref;
+
+    TimestampDetails: 3, timestamp_details, set_timestamp_details, unset_timestamp_details
+        => timestamp_details, set_timestamp_details, TimestampDetails, ref;
+    TimestampSkew: 3, timestamp_skew, set_timestamp_skew, unset_timestamp_skew
+        => timestamp_skew, set_timestamp_skew, TimeInterval, ref;
+    RiseTime: 3, rise_time, set_rise_time, unset_rise_time
+        => rise_time, set_rise_time, TimeInterval, ref;
+    FallTime: 3, fall_time, set_fall_time, unset_fall_time
+        => fall_time, set_fall_time, TimeInterval, ref;
+    OffsetTime: 3, offset_time, set_offset_time, unset_offset_time
+        => offset_time, set_offset_time, TimeInterval, ref;
+    PulseWidth: 3, pulse_width, set_pulse_width, unset_pulse_width
+        => pulse_width, set_pulse_width, TimeInterval, ref;
+    Period: 3, period, set_period, unset_period
+        => period, set_period, TimeInterval, ref;
+    Duration: 3, duration, set_duration, unset_duration
+        => duration, set_duration, TimeInterval, ref;
+    Dwell: 3, dwell, set_dwell, unset_dwell
+        => dwell, set_dwell, TimeInterval, ref;
+    Jitter: 3, jitter, set_jitter, unset_jitter
+        => jitter, set_jitter, TimeInterval, ref;
+    Age: 3, age, set_age, unset_age
+        => age, set_age, FieldTimestamp, ref;
+    ShelfLife: 3, shelf_life, set_shelf_life, unset_shelf_life
+        => shelf_life, set_shelf_life, FieldTimestamp, ref;
+    AirTemp: 3, air_temp, set_air_temp, unset_air_temp
+        => air_temp_c, set_air_temp_c, F32, radix(FixedI16::<U6>);
+    GroundTemp: 3, ground_temp, set_ground_temp, unset_ground_temp
+        => ground_temp_c, set_ground_temp_c, F32, radix(FixedI16::<U6>);
+    Humidity: 3, humidity, set_humidity, unset_humidity
+        => humidity_pct, set_humidity_pct, F32, checked;
+    BarometricPressure: 3, barometric_pressure, set_barometric_pressure,
+        unset_barometric_pressure
+        => barometric_pressure_mbar, set_barometric_pressure_mbar, F32, checked;
+    SeaAndSwellState: 3, sea_and_swell_state, set_sea_and_swell_state, unset_sea_and_swell_state
+        => sea_and_swell_state, set_sea_and_swell_state, U32, ref;
+    TroposphericState: 3, tropospheric_state, set_tropospheric_state, unset_tropospheric_state
+        => tropospheric_state, set_tropospheric_state, U32, ref;
+    NetworkId: 3, network_id, set_network_id, unset_network_id
+        => network_id, set_network_id, U32, ref;
+}
+
+/// Access to CIF0-CIF3 fields by [`CifField`] identifier. Implemented for
+/// every type with the CIF0-CIF3 manipulator traits.
+pub trait CifFieldAccess {
+    /// Get the value of a field. If `None` is returned, the field is unset.
+    fn get(&self, field: CifField) -> Option<FieldValue>;
+
+    /// Set the value of a field.
+    ///
+    /// [`update_packet_size()`](crate::Vrt::update_packet_size()) should be executed after running this method.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::FieldValueMismatch`] if the value's type
+    /// doesn't match the field, [`VitaError::OutOfRange`] for a float the
+    /// field can't encode (NaN, infinite, or beyond its fixed-point range),
+    /// or any error the field's typed setter returns.
+    fn set(&mut self, field: CifField, value: FieldValue) -> Result<(), VitaError>;
+
+    /// Unset a field.
+    ///
+    /// [`update_packet_size()`](crate::Vrt::update_packet_size()) should be executed after running this method.
+    fn unset(&mut self, field: CifField);
+
+    /// Iterate over the fields currently present, in packet order.
+    fn present_fields(&self) -> Box<dyn Iterator<Item = CifField> + '_>;
+}
+
+impl fmt::Display for CifField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn every_field_round_trips() {
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        for field in CifField::ALL {
+            assert_eq!(field.name().parse::<CifField>().unwrap(), *field);
+        }
+        context.set(CifField::Bandwidth, 8e6.into()).unwrap();
+        context.set(CifField::Temperature, 21.5f32.into()).unwrap();
+        context
+            .set(CifField::ControlleeUuid, FieldValue::U128(1 << 100))
+            .unwrap();
+        context
+            .set(CifField::Duration, TimeInterval::from_fs(1_000).into())
+            .unwrap();
+        assert!(matches!(
+            context.set(CifField::Humidity, 101.0f32.into()),
+            Err(VitaError::OutOfRange)
+        ));
+        assert!(matches!(
+            context.set(CifField::Gain, 1u32.into()),
+            Err(VitaError::FieldValueMismatch)
+        ));
+        packet.update_packet_size();
+
+        let mut parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+        let context = parsed.payload_mut().context_mut().unwrap();
+        assert_eq!(
+            context.present_fields().collect::<Vec<_>>(),
+            vec![
+                CifField::Bandwidth,
+                CifField::Temperature,
+                CifField::ControlleeUuid,
+                CifField::Duration
+            ]
+        );
+        assert_eq!(
+            context.get(CifField::Temperature),
+            Some(FieldValue::F32(21.5))
+        );
+        for field in CifField::ALL {
+            context.unset(*field);
+        }
+        assert_eq!(context.present_fields().count(), 0);
+        assert!(context.cif3().is_none());
+    }
+
+    #[test]
+    fn unencodable_floats_are_rejected() {
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set(CifField::Bandwidth, 8e6.into()).unwrap();
+        for value in [1e30, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                context.set(CifField::Bandwidth, value.into()),
+                Err(VitaError::OutOfRange)
+            ));
+        }
+        assert_eq!(context.bandwidth_hz(), Some(8e6));
+        // Just past the edge of a 16-bit, radix-6 field
+        assert!(matches!(
+            context.set(CifField::AirTemp, 512.0f32.into()),
+            Err(VitaError::OutOfRange)
+        ));
+        context.set(CifField::AirTemp, (-512.0f32).into()).unwrap();
+        assert_eq!(context.air_temp_c(), Some(-512.0));
+    }
+
+    #[test]
+    fn ack_responses_on_both_levels() {
+        let mut warning = AckResponse::default();
+        warning.set_distrortion();
+        let mut error = AckResponse::default();
+        error.set_param_out_of_range();
+        let mut ack = Ack::default();
+        for field in [CifField::Bandwidth, CifField::PhaseOffset] {
+            ack.set_field_response(field, AckLevel::Warning, Some(warning));
+            ack.set_field_response(field, AckLevel::Error, Some(error));
+            assert_eq!(ack.field_response(field), (Some(warning), Some(error)));
+            ack.set_field_response(field, AckLevel::Warning, None);
+            assert_eq!(ack.field_response(field), (None, Some(error)));
+        }
+        assert_eq!(ack.field_response(CifField::SampleRate), (None, None));
+    }
+}
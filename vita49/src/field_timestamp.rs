@@ -0,0 +1,220 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the timestamp-style CIF3 age
+and shelf life fields (ANSI/VITA-49.2-2017 sections 9.7.2 and 9.7.3).
+
+Both fields are three words long: a 32-bit integer-seconds part
+followed by a 64-bit fractional part, laid out like the packet
+timestamp. The standard gives them the timestamp format selected by the
+TSI and TSF codes in the header of the packet carrying them, so the
+fields hold no mode bits of their own and the modes must be supplied
+when interpreting them (see [`FieldTimestamp::to_timestamp()`] and
+[`FieldTimestamp::to_duration()`]). The age tells how long ago the
+context was valid, and the shelf life tells how long the context
+remains valid.
+*/
+
+use core::fmt;
+use core::time::Duration;
+use deku::prelude::*;
+
+use crate::{Tsf, Tsi, VitaError, VitaTimestamp};
+
+/// Number of picoseconds in one nanosecond.
+const PS_PER_NS: u64 = 1_000;
+/// Number of picoseconds in one second.
+const PS_PER_SEC: u64 = 1_000_000_000_000;
+
+/// Base timestamp-style field data structure (used by the age and
+/// shelf life fields).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldTimestamp {
+    integer: u32,
+    fractional: u64,
+}
+
+impl FieldTimestamp {
+    /// Create a new field from raw integer and fractional parts.
+    pub fn new(integer: u32, fractional: u64) -> FieldTimestamp {
+        FieldTimestamp {
+            integer,
+            fractional,
+        }
+    }
+
+    /// Create a new field from a [`VitaTimestamp`]. Missing parts are
+    /// stored as zero.
+    pub fn from_timestamp(timestamp: &VitaTimestamp) -> FieldTimestamp {
+        FieldTimestamp {
+            integer: timestamp.integer().unwrap_or(0),
+            fractional: timestamp.fractional().unwrap_or(0),
+        }
+    }
+
+    /// Create a new field from a [`Duration`], for use in a packet with
+    /// real-time (picosecond) fractional timestamps.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the duration is too long for
+    /// the 32-bit integer-seconds part.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::FieldTimestamp;
+    /// use std::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_age(Some(FieldTimestamp::from_duration(Duration::from_millis(1_500))?));
+    /// context.set_shelf_life(Some(FieldTimestamp::from_duration(Duration::from_secs(60))?));
+    /// packet.update_packet_size();
+    ///
+    /// let parsed = Vrt::try_from(&packet.to_bytes()?[..])?;
+    /// let context = parsed.payload().context().unwrap();
+    /// let age = context.age().unwrap();
+    /// assert_eq!(age.integer(), 1);
+    /// assert_eq!(age.fractional(), 500_000_000_000);
+    /// assert_eq!(age.to_duration(Tsf::RealTimePs), Some(Duration::from_millis(1_500)));
+    /// let shelf_life = context.shelf_life().unwrap().to_timestamp(Tsi::Other, Tsf::Null)?;
+    /// assert_eq!(shelf_life.integer(), Some(60));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_duration(duration: Duration) -> Result<FieldTimestamp, VitaError> {
+        Ok(FieldTimestamp {
+            integer: u32::try_from(duration.as_secs()).map_err(|_| VitaError::OutOfRange)?,
+            fractional: duration.subsec_nanos() as u64 * PS_PER_NS,
+        })
+    }
+
+    /// Gets the size of the field in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        3
+    }
+
+    /// Gets the raw integer part.
+    pub fn integer(&self) -> u32 {
+        self.integer
+    }
+
+    /// Gets the raw fractional part.
+    pub fn fractional(&self) -> u64 {
+        self.fractional
+    }
+
+    /// Interprets the field as a [`VitaTimestamp`] using the TSI and TSF
+    /// modes of the packet carrying it. Parts with a `Null` mode are
+    /// dropped.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::TimestampModeMismatch`] if both modes are
+    /// `Null`, since the field would then carry no time at all.
+    pub fn to_timestamp(&self, tsi: Tsi, tsf: Tsf) -> Result<VitaTimestamp, VitaError> {
+        if tsi == Tsi::Null && tsf == Tsf::Null {
+            return Err(VitaError::TimestampModeMismatch);
+        }
+        let integer = (tsi != Tsi::Null).then_some(self.integer);
+        let fractional = (tsf != Tsf::Null).then_some(self.fractional);
+        VitaTimestamp::new(tsi, integer, tsf, fractional)
+    }
+
+    /// Interprets the field as a [`Duration`] given the TSF mode of the
+    /// packet carrying it. Returns `None` unless the fractional part is
+    /// real-time (picoseconds) or `Null` (whole seconds only), or if the
+    /// picosecond count is out of range.
+    pub fn to_duration(&self, tsf: Tsf) -> Option<Duration> {
+        let ps = match tsf {
+            Tsf::RealTimePs => self.fractional,
+            Tsf::Null => 0,
+            _ => return None,
+        };
+        if ps >= PS_PER_SEC {
+            return None;
+        }
+        Some(Duration::new(self.integer as u64, (ps / PS_PER_NS) as u32))
+    }
+}
+
+impl fmt::Display for FieldTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Integer: {}, Fractional: {}",
+            self.integer, self.fractional
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn wire_round_trip() {
+        let age = FieldTimestamp::new(0x0102_0304, 0x0506_0708_090A_0B0C);
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_age(Some(age));
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+        assert!(bytes
+            .ends_with(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C]));
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        assert_eq!(parsed.payload().context().unwrap().age(), Some(&age));
+    }
+
+    #[test]
+    fn to_duration_edges() {
+        // Largest valid picosecond count truncates to whole nanoseconds
+        let ts = FieldTimestamp::new(u32::MAX, PS_PER_SEC - 1);
+        assert_eq!(
+            ts.to_duration(Tsf::RealTimePs),
+            Some(Duration::new(u32::MAX as u64, 999_999_999))
+        );
+        // A fractional part of a second or more isn't a valid real-time value
+        let ts = FieldTimestamp::new(1, PS_PER_SEC);
+        assert_eq!(ts.to_duration(Tsf::RealTimePs), None);
+        // With no fractional mode, only the integer part counts
+        assert_eq!(ts.to_duration(Tsf::Null), Some(Duration::from_secs(1)));
+        // Sample counts and free-running counts have no fixed time scale
+        assert_eq!(ts.to_duration(Tsf::SampleCount), None);
+        assert_eq!(ts.to_duration(Tsf::FreeRunningCount), None);
+        assert_eq!(
+            FieldTimestamp::default().to_duration(Tsf::RealTimePs),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn from_duration_and_timestamp() {
+        let ts = FieldTimestamp::from_duration(Duration::new(2, 1)).unwrap();
+        assert_eq!((ts.integer(), ts.fractional()), (2, 1_000));
+        assert!(matches!(
+            FieldTimestamp::from_duration(Duration::from_secs(u32::MAX as u64 + 1)),
+            Err(VitaError::OutOfRange)
+        ));
+
+        let ts = FieldTimestamp::new(7, 9);
+        let vt = ts.to_timestamp(Tsi::Gps, Tsf::SampleCount).unwrap();
+        assert_eq!((vt.integer(), vt.fractional()), (Some(7), Some(9)));
+        assert_eq!(FieldTimestamp::from_timestamp(&vt), ts);
+        let vt = ts.to_timestamp(Tsi::Null, Tsf::SampleCount).unwrap();
+        assert_eq!((vt.integer(), vt.fractional()), (None, Some(9)));
+        assert_eq!(
+            FieldTimestamp::from_timestamp(&vt),
+            FieldTimestamp::new(0, 9)
+        );
+        assert!(matches!(
+            ts.to_timestamp(Tsi::Null, Tsf::Null),
+            Err(VitaError::TimestampModeMismatch)
+        ));
+    }
+}
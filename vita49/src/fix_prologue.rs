@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+The first four words shared by the formatted GPS/INS (ANSI/VITA-49.2-2017
+section 9.4.5) and ECEF/relative ephemeris (section 9.4.3) formats: the
+TSI/TSF modes and manufacturer OUI, followed by the fix timestamp.
+
+A timestamp word holding the "unspecified" value (all ones) is reported
+as absent even if its TSI/TSF mode is set.
+*/
+
+use crate::{Tsf, Tsi, VitaTimestamp};
+use deku::prelude::*;
+
+/// Value used by the integer timestamp word when unspecified.
+const UNSPECIFIED_INT_TS: u32 = 0xFFFF_FFFF;
+/// Value used by the fractional timestamp words when unspecified.
+const UNSPECIFIED_FRAC_TS: u64 = 0xFFFF_FFFF_FFFF_FFFF;
+
+/// TSI/TSF modes, manufacturer OUI, and fix timestamp.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct FixPrologue {
+    w1: u32,
+    ts1: u32,
+    ts2: u32,
+    ts3: u32,
+}
+
+impl Default for FixPrologue {
+    fn default() -> Self {
+        FixPrologue {
+            w1: 0,
+            ts1: UNSPECIFIED_INT_TS,
+            ts2: (UNSPECIFIED_FRAC_TS >> 32) as u32,
+            ts3: UNSPECIFIED_FRAC_TS as u32,
+        }
+    }
+}
+
+impl FixPrologue {
+    /// Gets the TimeStamp-Integer (TSI) mode of the fix timestamp.
+    pub(crate) fn tsi(&self) -> Tsi {
+        (((self.w1 >> 26) & 0b11) as u8).try_into().unwrap()
+    }
+
+    /// Gets the TimeStamp-Fractional (TSF) mode of the fix timestamp.
+    pub(crate) fn tsf(&self) -> Tsf {
+        (((self.w1 >> 24) & 0b11) as u8).try_into().unwrap()
+    }
+
+    /// Gets the manufacturer OUI.
+    pub(crate) fn manufacturer_oui(&self) -> u32 {
+        self.w1 & 0xFF_FFFF
+    }
+
+    /// Sets the manufacturer OUI (only the lower 24 bits are used).
+    pub(crate) fn set_manufacturer_oui(&mut self, oui: u32) {
+        self.w1 = self.w1 & !0xFF_FFFF | (oui & 0xFF_FFFF);
+    }
+
+    /// Gets the fix timestamp. Parts whose mode is null or whose words
+    /// are unspecified are left out; `None` is returned if both are.
+    pub(crate) fn fix_timestamp(&self) -> Option<VitaTimestamp> {
+        let (mut tsi, mut tsf) = (self.tsi(), self.tsf());
+        let frac = ((self.ts2 as u64) << 32) | self.ts3 as u64;
+        if self.ts1 == UNSPECIFIED_INT_TS {
+            tsi = Tsi::Null;
+        }
+        if frac == UNSPECIFIED_FRAC_TS {
+            tsf = Tsf::Null;
+        }
+        if tsi == Tsi::Null && tsf == Tsf::Null {
+            return None;
+        }
+        let integer = (tsi != Tsi::Null).then_some(self.ts1);
+        let fractional = (tsf != Tsf::Null).then_some(frac);
+        VitaTimestamp::new(tsi, integer, tsf, fractional).ok()
+    }
+
+    /// Sets the fix timestamp and its TSI/TSF modes. If `None` is
+    /// passed, the timestamp will be unspecified.
+    pub(crate) fn set_fix_timestamp(&mut self, timestamp: Option<VitaTimestamp>) {
+        let ts = timestamp.unwrap_or_default();
+        self.w1 = self.w1 & !(0b1111 << 24) | ((ts.tsi() as u32) << 26) | ((ts.tsf() as u32) << 24);
+        let frac = ts.fractional().unwrap_or(UNSPECIFIED_FRAC_TS);
+        self.ts1 = ts.integer().unwrap_or(UNSPECIFIED_INT_TS);
+        self.ts2 = (frac >> 32) as u32;
+        self.ts3 = frac as u32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fix_timestamp() {
+        let mut prologue = FixPrologue::default();
+        prologue.set_manufacturer_oui(0x12AB_CDEF);
+        assert_eq!(prologue.manufacturer_oui(), 0xAB_CDEF);
+        assert_eq!(prologue.fix_timestamp(), None);
+
+        let ts = VitaTimestamp::new(Tsi::Utc, Some(7), Tsf::SampleCount, Some(9)).unwrap();
+        prologue.set_fix_timestamp(Some(ts));
+        assert_eq!(
+            (prologue.tsi(), prologue.tsf()),
+            (Tsi::Utc, Tsf::SampleCount)
+        );
+        assert_eq!(prologue.fix_timestamp(), Some(ts));
+        assert_eq!(prologue.manufacturer_oui(), 0xAB_CDEF);
+
+        // Modes set but the words left unspecified
+        prologue.ts1 = UNSPECIFIED_INT_TS;
+        let frac_only = VitaTimestamp::new(Tsi::Null, None, Tsf::SampleCount, Some(9)).unwrap();
+        assert_eq!(prologue.fix_timestamp(), Some(frac_only));
+        prologue.ts2 = u32::MAX;
+        prologue.ts3 = u32::MAX;
+        assert_eq!(prologue.tsi(), Tsi::Utc);
+        assert_eq!(prologue.fix_timestamp(), None);
+
+        prologue.set_fix_timestamp(None);
+        assert_eq!((prologue.tsi(), prologue.tsf()), (Tsi::Null, Tsf::Null));
+        assert_eq!(prologue, {
+            let mut p = FixPrologue::default();
+            p.set_manufacturer_oui(0xAB_CDEF);
+            p
+        });
+    }
+}
@@ -4,21 +4,30 @@
 /*!
 Data structures and methods related to the formatted GPS format
 (ANSI/VITA-49.2-2017 section 9.4.5).
+
+The same format is used for the formatted INS field (section 9.4.6).
+Any subfield that isn't known is set to a special "unspecified"
+value, which is exposed here as `None`.
 */
 
+use core::fmt;
+use core::ops::RangeBounds;
+
+use crate::fix_prologue::FixPrologue;
+use crate::{Tsf, Tsi, VitaError, VitaTimestamp};
 use deku::prelude::*;
+use fixed::types::extra::{LeEqU32, U16, U22, U5};
+use fixed::FixedI32;
+
+/// Value used by 32-bit position subfields when unspecified.
+pub(crate) const UNSPECIFIED_I32: i32 = 0x7FFF_FFFF;
 
 /// Base formatted GPS data structure.
-#[derive(
-    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
-)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FormattedGps {
-    w1: u32,
-    ts1: u32,
-    ts2: u32,
-    ts3: u32,
+    prologue: FixPrologue,
     latitude: i32,
     longitude: i32,
     altitude: i32,
@@ -28,9 +37,330 @@ pub struct FormattedGps {
     magnetic_variation: i32,
 }
 
+impl Default for FormattedGps {
+    fn default() -> Self {
+        FormattedGps {
+            prologue: FixPrologue::default(),
+            latitude: UNSPECIFIED_I32,
+            longitude: UNSPECIFIED_I32,
+            altitude: UNSPECIFIED_I32,
+            speed_over_ground: UNSPECIFIED_I32,
+            heading_angle: UNSPECIFIED_I32,
+            track_angle: UNSPECIFIED_I32,
+            magnetic_variation: UNSPECIFIED_I32,
+        }
+    }
+}
+
+/// Decodes a 32-bit fixed point subfield, mapping the unspecified
+/// value to `None`.
+pub(crate) fn decode_i32<Frac: LeEqU32>(raw: i32) -> Option<f64> {
+    if raw == UNSPECIFIED_I32 {
+        None
+    } else {
+        Some(FixedI32::<Frac>::from_bits(raw).to_num())
+    }
+}
+
+/// Encodes a 32-bit fixed point subfield, mapping `None` to the
+/// unspecified value.
+pub(crate) fn encode_i32<Frac: LeEqU32>(
+    value: Option<f64>,
+    range: impl RangeBounds<f64>,
+) -> Result<i32, VitaError> {
+    let Some(v) = value else {
+        return Ok(UNSPECIFIED_I32);
+    };
+    if !range.contains(&v) {
+        return Err(VitaError::OutOfRange);
+    }
+    FixedI32::<Frac>::checked_from_num(v)
+        .map(|f| f.to_bits())
+        .filter(|bits| *bits != UNSPECIFIED_I32)
+        .ok_or(VitaError::OutOfRange)
+}
+
 impl FormattedGps {
+    /// Create a new formatted GPS structure with every subfield
+    /// unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::FormattedGps;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut gps = FormattedGps::new(0x12_3456);
+    /// gps.set_latitude_deg(Some(38.8895))?;
+    /// gps.set_longitude_deg(Some(-77.0353))?;
+    /// gps.set_altitude_m(Some(12.5))?;
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_formatted_gps(Some(gps));
+    ///
+    /// let gps = context.formatted_gps().unwrap();
+    /// assert_eq!(gps.manufacturer_oui(), 0x12_3456);
+    /// assert!((gps.latitude_deg().unwrap() - 38.8895).abs() < 1e-6);
+    /// assert_eq!(gps.altitude_m(), Some(12.5));
+    /// assert_eq!(gps.speed_over_ground_mps(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(manufacturer_oui: u32) -> FormattedGps {
+        let mut ret = FormattedGps::default();
+        ret.set_manufacturer_oui(manufacturer_oui);
+        ret
+    }
+
     /// Gets the size of the formatted GPS structure in 32-bit words.
     pub fn size_words(&self) -> u16 {
         (std::mem::size_of_val(self) / std::mem::size_of::<u32>()) as u16
     }
+
+    /// Gets the TimeStamp-Integer (TSI) mode of the fix timestamp.
+    pub fn tsi(&self) -> Tsi {
+        self.prologue.tsi()
+    }
+
+    /// Gets the TimeStamp-Fractional (TSF) mode of the fix timestamp.
+    pub fn tsf(&self) -> Tsf {
+        self.prologue.tsf()
+    }
+
+    /// Gets the manufacturer Organizational Unique Identifier (OUI).
+    pub fn manufacturer_oui(&self) -> u32 {
+        self.prologue.manufacturer_oui()
+    }
+
+    /// Sets the manufacturer Organizational Unique Identifier (OUI).
+    ///
+    /// Note: while this API takes a 32-bit integer, only the least
+    /// significant 24 bits are used.
+    pub fn set_manufacturer_oui(&mut self, oui: u32) {
+        self.prologue.set_manufacturer_oui(oui);
+    }
+
+    /// Gets the timestamp of the position fix. If `None` is returned,
+    /// no timestamp is specified. A part (integer or fractional) whose
+    /// word holds the unspecified value is left out even if its mode
+    /// is set.
+    pub fn fix_timestamp(&self) -> Option<VitaTimestamp> {
+        self.prologue.fix_timestamp()
+    }
+
+    /// Sets the timestamp of the position fix (including the TSI/TSF
+    /// modes). If `None` is passed, the timestamp will be unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::FormattedGps;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut gps = FormattedGps::new(0);
+    /// let ts = VitaTimestamp::new(Tsi::Gps, Some(1_000), Tsf::RealTimePs, Some(42))?;
+    /// gps.set_fix_timestamp(Some(ts));
+    /// assert_eq!(gps.tsi(), Tsi::Gps);
+    /// assert_eq!(gps.fix_timestamp(), Some(ts));
+    /// gps.set_fix_timestamp(None);
+    /// assert_eq!(gps.fix_timestamp(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_fix_timestamp(&mut self, timestamp: Option<VitaTimestamp>) {
+        self.prologue.set_fix_timestamp(timestamp);
+    }
+
+    /// Gets the latitude (degrees).
+    pub fn latitude_deg(&self) -> Option<f64> {
+        decode_i32::<U22>(self.latitude)
+    }
+
+    /// Sets the latitude (degrees). If `None` is passed, the
+    /// latitude will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value isn't within -90 to 90.
+    pub fn set_latitude_deg(&mut self, latitude_deg: Option<f64>) -> Result<(), VitaError> {
+        self.latitude = encode_i32::<U22>(latitude_deg, -90.0..=90.0)?;
+        Ok(())
+    }
+
+    /// Gets the longitude (degrees).
+    pub fn longitude_deg(&self) -> Option<f64> {
+        decode_i32::<U22>(self.longitude)
+    }
+
+    /// Sets the longitude (degrees). If `None` is passed, the
+    /// longitude will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value isn't within -180 to 180.
+    pub fn set_longitude_deg(&mut self, longitude_deg: Option<f64>) -> Result<(), VitaError> {
+        self.longitude = encode_i32::<U22>(longitude_deg, -180.0..=180.0)?;
+        Ok(())
+    }
+
+    /// Gets the altitude (meters).
+    pub fn altitude_m(&self) -> Option<f64> {
+        decode_i32::<U5>(self.altitude)
+    }
+
+    /// Sets the altitude (meters). If `None` is passed, the
+    /// altitude will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+    pub fn set_altitude_m(&mut self, altitude_m: Option<f64>) -> Result<(), VitaError> {
+        self.altitude = encode_i32::<U5>(altitude_m, ..)?;
+        Ok(())
+    }
+
+    /// Gets the speed over ground (meters/second).
+    pub fn speed_over_ground_mps(&self) -> Option<f64> {
+        decode_i32::<U16>(self.speed_over_ground)
+    }
+
+    /// Sets the speed over ground (meters/second). If `None` is passed,
+    /// the speed will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value is negative or can't
+    /// be represented.
+    pub fn set_speed_over_ground_mps(&mut self, speed_mps: Option<f64>) -> Result<(), VitaError> {
+        self.speed_over_ground = encode_i32::<U16>(speed_mps, 0.0..)?;
+        Ok(())
+    }
+
+    /// Gets the heading angle (degrees).
+    pub fn heading_angle_deg(&self) -> Option<f64> {
+        decode_i32::<U22>(self.heading_angle)
+    }
+
+    /// Sets the heading angle (degrees). If `None` is passed, the
+    /// heading will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value isn't within 0 to 360
+    /// (exclusive).
+    pub fn set_heading_angle_deg(&mut self, heading_deg: Option<f64>) -> Result<(), VitaError> {
+        self.heading_angle = encode_i32::<U22>(heading_deg, 0.0..360.0)?;
+        Ok(())
+    }
+
+    /// Gets the track angle (degrees).
+    pub fn track_angle_deg(&self) -> Option<f64> {
+        decode_i32::<U22>(self.track_angle)
+    }
+
+    /// Sets the track angle (degrees). If `None` is passed, the
+    /// track angle will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value isn't within 0 to 360
+    /// (exclusive).
+    pub fn set_track_angle_deg(&mut self, track_deg: Option<f64>) -> Result<(), VitaError> {
+        self.track_angle = encode_i32::<U22>(track_deg, 0.0..360.0)?;
+        Ok(())
+    }
+
+    /// Gets the magnetic variation (degrees).
+    pub fn magnetic_variation_deg(&self) -> Option<f64> {
+        decode_i32::<U22>(self.magnetic_variation)
+    }
+
+    /// Sets the magnetic variation (degrees). If `None` is passed, the
+    /// magnetic variation will be unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value isn't within -180 to 180.
+    pub fn set_magnetic_variation_deg(
+        &mut self,
+        variation_deg: Option<f64>,
+    ) -> Result<(), VitaError> {
+        self.magnetic_variation = encode_i32::<U22>(variation_deg, -180.0..=180.0)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for FormattedGps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Formatted GPS/INS:")?;
+        writeln!(f, "  Manufacturer OUI: {:#x}", self.manufacturer_oui())?;
+        writeln!(f, "  Fix timestamp: {:?}", self.fix_timestamp())?;
+        writeln!(f, "  Latitude (deg): {:?}", self.latitude_deg())?;
+        writeln!(f, "  Longitude (deg): {:?}", self.longitude_deg())?;
+        writeln!(f, "  Altitude (m): {:?}", self.altitude_m())?;
+        writeln!(
+            f,
+            "  Speed over ground (m/s): {:?}",
+            self.speed_over_ground_mps()
+        )?;
+        writeln!(f, "  Heading angle (deg): {:?}", self.heading_angle_deg())?;
+        writeln!(f, "  Track angle (deg): {:?}", self.track_angle_deg())?;
+        writeln!(
+            f,
+            "  Magnetic variation (deg): {:?}",
+            self.magnetic_variation_deg()
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn wire_round_trip() {
+        let mut gps = FormattedGps::new(0x12_3456);
+        let ts = VitaTimestamp::new(Tsi::Gps, Some(1_000), Tsf::RealTimePs, Some(5)).unwrap();
+        gps.set_fix_timestamp(Some(ts));
+        gps.set_latitude_deg(Some(-45.5)).unwrap();
+        gps.set_longitude_deg(Some(179.25)).unwrap();
+        gps.set_altitude_m(Some(-10.0)).unwrap();
+        gps.set_speed_over_ground_mps(Some(3.5)).unwrap();
+        gps.set_heading_angle_deg(Some(359.0)).unwrap();
+
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_formatted_gps(Some(gps));
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+        // TSI/TSF modes and the OUI share the first word
+        let w1_offset = 4 * (packet.header().packet_size() as usize - 11);
+        assert_eq!(bytes[w1_offset..w1_offset + 4], [0b1010, 0x12, 0x34, 0x56]);
+
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        let parsed = parsed.payload().context().unwrap().formatted_gps().unwrap();
+        assert_eq!(*parsed, gps);
+        assert_eq!(parsed.fix_timestamp(), Some(ts));
+        assert_eq!(parsed.latitude_deg(), Some(-45.5));
+        assert_eq!(parsed.track_angle_deg(), None);
+        assert_eq!(parsed.magnetic_variation_deg(), None);
+    }
+
+    #[test]
+    fn ranges_and_unspecified() {
+        let mut gps = FormattedGps::default();
+        for (result, what) in [
+            (gps.set_latitude_deg(Some(90.5)), "latitude"),
+            (gps.set_longitude_deg(Some(-180.5)), "longitude"),
+            (gps.set_speed_over_ground_mps(Some(-1.0)), "speed"),
+            (gps.set_heading_angle_deg(Some(360.0)), "heading"),
+            (gps.set_track_angle_deg(Some(-0.1)), "track"),
+            (gps.set_magnetic_variation_deg(Some(181.0)), "variation"),
+            (gps.set_altitude_m(Some(1e9)), "altitude"),
+        ] {
+            assert!(matches!(result, Err(VitaError::OutOfRange)), "{what}");
+        }
+        // Rejected values leave the subfields unspecified
+        assert_eq!(gps, FormattedGps::default());
+        assert_eq!(gps.fix_timestamp(), None);
+        assert_eq!(gps.latitude_deg(), None);
+
+        gps.set_altitude_m(Some(2.0)).unwrap();
+        gps.set_altitude_m(None).unwrap();
+        assert_eq!(gps, FormattedGps::default());
+    }
 }
@@ -4,10 +4,24 @@
 /*!
 Data structures and methods related to the ASCII GPS format
 (ANSI/VITA-49.2-2017 section 9.4.7).
+
+The field carries one or more NMEA-0183 sentences as ASCII text,
+packed four characters per 32-bit word (first character in the most
+significant byte) and padded with null characters to a whole word.
+
+With the `nmea` feature enabled, [`GpsAscii::nmea_fix()`] parses the
+GGA, RMC, and ZDA sentences into an [`NmeaFix`].
 */
 
+use crate::VitaError;
 use deku::prelude::*;
 
+/// Words every context packet carrying the field needs besides the
+/// field itself (header, stream ID, and CIF0).
+const MIN_CONTEXT_PACKET_WORDS: usize = 3;
+/// Words of the field that precede the ASCII text (OUI and word count).
+const HEADER_WORDS: usize = 2;
+
 /// Base ASCII GPS data structure.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
@@ -20,10 +34,514 @@ pub struct GpsAscii {
 }
 
 impl GpsAscii {
+    /// Create a new ASCII GPS field from a list of NMEA sentences. A
+    /// `"\r\n"` terminator is appended to any sentence that doesn't
+    /// already end with one.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::NonAscii`] if any sentence contains non-ASCII
+    /// characters and [`VitaError::OutOfRange`] if the text is too long to
+    /// fit in a context packet (even one with no other optional words or
+    /// fields; anything else added to the packet counts against the same
+    /// 65,535-word limit).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::GpsAscii;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let gps = GpsAscii::from_sentences(
+    ///     0x12_3456,
+    ///     &["$GPZDA,201530.00,04,07,2002,00,00*60"],
+    /// )?;
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_gps_ascii(Some(gps));
+    ///
+    /// let gps = context.gps_ascii().unwrap();
+    /// assert_eq!(gps.manufacturer_oui(), 0x12_3456);
+    /// assert_eq!(gps.sentences(), vec!["$GPZDA,201530.00,04,07,2002,00,00*60"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_sentences(
+        manufacturer_oui: u32,
+        sentences: &[&str],
+    ) -> Result<GpsAscii, VitaError> {
+        let mut bytes = Vec::new();
+        for sentence in sentences {
+            if !sentence.is_ascii() {
+                return Err(VitaError::NonAscii);
+            }
+            bytes.extend_from_slice(sentence.as_bytes());
+            if !sentence.ends_with("\r\n") {
+                bytes.extend_from_slice(b"\r\n");
+            }
+        }
+        let ascii: Vec<u32> = bytes
+            .chunks(4)
+            .map(|c| {
+                let mut word = [0u8; 4];
+                word[..c.len()].copy_from_slice(c);
+                u32::from_be_bytes(word)
+            })
+            .collect();
+        // The field has to fit in a context packet along with its own
+        // header words and the packet's mandatory words.
+        if ascii.len() > u16::MAX as usize - HEADER_WORDS - MIN_CONTEXT_PACKET_WORDS {
+            return Err(VitaError::OutOfRange);
+        }
+        Ok(GpsAscii {
+            w1: manufacturer_oui & 0xFF_FFFF,
+            num_words: ascii.len() as u32,
+            ascii,
+        })
+    }
+
     /// Gets the size of the ASCII GPS field in 32-bit words.
     pub fn size_words(&self) -> u16 {
         (((std::mem::size_of_val(&self.w1) + std::mem::size_of_val(&self.num_words))
             / std::mem::size_of::<u32>())
             + self.num_words as usize) as u16
     }
+
+    /// Gets the manufacturer Organizational Unique Identifier (OUI).
+    pub fn manufacturer_oui(&self) -> u32 {
+        self.w1 & 0xFF_FFFF
+    }
+
+    /// Sets the manufacturer Organizational Unique Identifier (OUI).
+    ///
+    /// Note: while this API takes a 32-bit integer, only the least
+    /// significant 24 bits are used.
+    pub fn set_manufacturer_oui(&mut self, oui: u32) {
+        self.w1 = self.w1 & !0xFF_FFFF | (oui & 0xFF_FFFF);
+    }
+
+    /// Gets the raw ASCII text (with padding removed). Bytes outside the
+    /// ASCII range (which a well-formed field never contains) are mapped
+    /// to the Latin-1 character with the same value.
+    pub fn text(&self) -> String {
+        self.ascii
+            .iter()
+            .flat_map(|w| w.to_be_bytes())
+            .filter(|b| *b != 0)
+            .map(char::from)
+            .collect()
+    }
+
+    /// Gets the individual NMEA sentences (without line terminators).
+    pub fn sentences(&self) -> Vec<String> {
+        self.text()
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect()
+    }
+
+    /// Parses the GGA, RMC, and ZDA sentences in the field into a
+    /// single [`NmeaFix`]. Other sentence types are ignored. When a
+    /// value appears in more than one sentence, the last one wins.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::InvalidNmea`] if a supported sentence is
+    /// malformed or fails its checksum, or [`VitaError::NonAscii`] if a
+    /// sentence contains non-ASCII characters.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::{FixQuality, GpsAscii, VitaError};
+    /// # fn main() -> Result<(), VitaError> {
+    /// let gps = GpsAscii::from_sentences(
+    ///     0,
+    ///     &["$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47"],
+    /// )?;
+    /// let fix = gps.nmea_fix()?;
+    /// assert_eq!(fix.fix_quality, Some(FixQuality::Gps));
+    /// assert_eq!(fix.num_satellites, Some(8));
+    /// assert!((fix.latitude_deg.unwrap() - 48.1173).abs() < 1e-9);
+    /// assert!((fix.longitude_deg.unwrap() - 11.516_666_666).abs() < 1e-6);
+    /// assert_eq!(fix.altitude_m, Some(545.4));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "nmea")]
+    pub fn nmea_fix(&self) -> Result<NmeaFix, VitaError> {
+        let mut fix = NmeaFix::default();
+        for sentence in self.sentences() {
+            fix.update(&sentence)?;
+        }
+        Ok(fix)
+    }
+}
+
+/// GPS fix quality (from the GGA sentence).
+#[cfg(feature = "nmea")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FixQuality {
+    /// No fix.
+    Invalid,
+    /// Standard GPS fix.
+    Gps,
+    /// Differential GPS fix.
+    Dgps,
+    /// PPS fix.
+    Pps,
+    /// Real-time kinematic fix.
+    Rtk,
+    /// Float real-time kinematic fix.
+    FloatRtk,
+    /// Estimated (dead reckoning) fix.
+    Estimated,
+    /// Manually entered position.
+    Manual,
+    /// Simulated position.
+    Simulation,
+    /// Any other (vendor-specific) value.
+    Other(u8),
+}
+
+#[cfg(feature = "nmea")]
+impl From<u8> for FixQuality {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => FixQuality::Invalid,
+            1 => FixQuality::Gps,
+            2 => FixQuality::Dgps,
+            3 => FixQuality::Pps,
+            4 => FixQuality::Rtk,
+            5 => FixQuality::FloatRtk,
+            6 => FixQuality::Estimated,
+            7 => FixQuality::Manual,
+            8 => FixQuality::Simulation,
+            x => FixQuality::Other(x),
+        }
+    }
+}
+
+/// UTC time of day reported by an NMEA sentence.
+#[cfg(feature = "nmea")]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NmeaTime {
+    /// Hour (0-23).
+    pub hour: u8,
+    /// Minute (0-59).
+    pub minute: u8,
+    /// Seconds (including any fractional part).
+    pub second: f64,
+}
+
+/// UTC date reported by an NMEA sentence.
+#[cfg(feature = "nmea")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NmeaDate {
+    /// Year (four digits).
+    pub year: u16,
+    /// Month (1-12).
+    pub month: u8,
+    /// Day of the month (1-31).
+    pub day: u8,
+}
+
+/// Position, time, and fix information gathered from NMEA sentences.
+#[cfg(feature = "nmea")]
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NmeaFix {
+    /// UTC time of the fix (GGA, RMC, ZDA).
+    pub time: Option<NmeaTime>,
+    /// UTC date of the fix (RMC, ZDA).
+    pub date: Option<NmeaDate>,
+    /// Latitude in degrees, positive north (GGA, RMC).
+    pub latitude_deg: Option<f64>,
+    /// Longitude in degrees, positive east (GGA, RMC).
+    pub longitude_deg: Option<f64>,
+    /// Altitude above mean sea level in meters (GGA).
+    pub altitude_m: Option<f64>,
+    /// Fix quality (GGA).
+    pub fix_quality: Option<FixQuality>,
+    /// Number of satellites in use (GGA).
+    pub num_satellites: Option<u8>,
+    /// Horizontal dilution of precision (GGA).
+    pub hdop: Option<f64>,
+    /// Whether the receiver reports the data as valid (RMC).
+    pub valid: Option<bool>,
+    /// Speed over ground in meters/second (RMC).
+    pub speed_over_ground_mps: Option<f64>,
+    /// Track angle in degrees true (RMC).
+    pub track_angle_deg: Option<f64>,
+}
+
+#[cfg(feature = "nmea")]
+impl NmeaFix {
+    /// Updates the fix with the data carried by a single NMEA sentence.
+    /// Sentence types other than GGA, RMC, and ZDA are ignored.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::InvalidNmea`] if a supported sentence is
+    /// malformed or fails its checksum, or [`VitaError::NonAscii`] if
+    /// the sentence contains non-ASCII characters.
+    pub fn update(&mut self, sentence: &str) -> Result<(), VitaError> {
+        let fields = nmea::split_checked(sentence)?;
+        let Some(address) = fields.first() else {
+            return Err(VitaError::InvalidNmea);
+        };
+        let field = |i: usize| fields.get(i).copied().unwrap_or("");
+        // Skip the two-character talker ID (GP, GN, GL, ...)
+        match address.get(2..) {
+            Some("GGA") => {
+                self.time = nmea::time(field(1))?.or(self.time);
+                if let Some(lat) = nmea::coordinate(field(2), field(3))? {
+                    self.latitude_deg = Some(lat);
+                }
+                if let Some(lon) = nmea::coordinate(field(4), field(5))? {
+                    self.longitude_deg = Some(lon);
+                }
+                if let Some(q) = nmea::number::<u8>(field(6))? {
+                    self.fix_quality = Some(q.into());
+                }
+                self.num_satellites = nmea::number(field(7))?.or(self.num_satellites);
+                self.hdop = nmea::number(field(8))?.or(self.hdop);
+                self.altitude_m = nmea::number(field(9))?.or(self.altitude_m);
+            }
+            Some("RMC") => {
+                self.time = nmea::time(field(1))?.or(self.time);
+                match field(2) {
+                    "A" => self.valid = Some(true),
+                    "V" => self.valid = Some(false),
+                    "" => (),
+                    _ => return Err(VitaError::InvalidNmea),
+                }
+                if let Some(lat) = nmea::coordinate(field(3), field(4))? {
+                    self.latitude_deg = Some(lat);
+                }
+                if let Some(lon) = nmea::coordinate(field(5), field(6))? {
+                    self.longitude_deg = Some(lon);
+                }
+                if let Some(knots) = nmea::number::<f64>(field(7))? {
+                    self.speed_over_ground_mps = Some(knots * nmea::MPS_PER_KNOT);
+                }
+                self.track_angle_deg = nmea::number(field(8))?.or(self.track_angle_deg);
+                self.date = nmea::ddmmyy(field(9))?.or(self.date);
+            }
+            Some("ZDA") => {
+                self.time = nmea::time(field(1))?.or(self.time);
+                let day = nmea::number::<u8>(field(2))?;
+                let month = nmea::number::<u8>(field(3))?;
+                let year = nmea::number::<u16>(field(4))?;
+                if let (Some(day), Some(month), Some(year)) = (day, month, year) {
+                    self.date = Some(nmea::date(year, month, day)?);
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+/// Low-level NMEA-0183 field parsing helpers.
+#[cfg(feature = "nmea")]
+mod nmea {
+    use super::{NmeaDate, NmeaTime};
+    use crate::VitaError;
+    use core::str::FromStr;
+
+    /// Meters/second in one knot.
+    pub(super) const MPS_PER_KNOT: f64 = 1852.0 / 3600.0;
+
+    /// Verifies the checksum (if present) and splits the sentence body
+    /// into its comma-separated fields.
+    pub(super) fn split_checked(sentence: &str) -> Result<Vec<&str>, VitaError> {
+        if !sentence.is_ascii() {
+            return Err(VitaError::NonAscii);
+        }
+        let sentence = sentence.trim();
+        let body = sentence
+            .strip_prefix('$')
+            .or_else(|| sentence.strip_prefix('!'))
+            .ok_or(VitaError::InvalidNmea)?;
+        let body = match body.split_once('*') {
+            Some((body, checksum)) => {
+                let expected =
+                    u8::from_str_radix(checksum, 16).map_err(|_| VitaError::InvalidNmea)?;
+                if body.bytes().fold(0, |acc, b| acc ^ b) != expected {
+                    return Err(VitaError::InvalidNmea);
+                }
+                body
+            }
+            None => body,
+        };
+        Ok(body.split(',').collect())
+    }
+
+    /// Parses an optional numeric field.
+    pub(super) fn number<T: FromStr>(field: &str) -> Result<Option<T>, VitaError> {
+        if field.is_empty() {
+            return Ok(None);
+        }
+        field.parse().map(Some).map_err(|_| VitaError::InvalidNmea)
+    }
+
+    /// Parses a `hhmmss.ss` time field.
+    pub(super) fn time(field: &str) -> Result<Option<NmeaTime>, VitaError> {
+        if field.is_empty() {
+            return Ok(None);
+        }
+        let (Some(hour), Some(minute), Some(second)) =
+            (field.get(0..2), field.get(2..4), field.get(4..))
+        else {
+            return Err(VitaError::InvalidNmea);
+        };
+        let time = NmeaTime {
+            hour: hour.parse().map_err(|_| VitaError::InvalidNmea)?,
+            minute: minute.parse().map_err(|_| VitaError::InvalidNmea)?,
+            second: second.parse().map_err(|_| VitaError::InvalidNmea)?,
+        };
+        if time.hour > 23 || time.minute > 59 || !(0.0..61.0).contains(&time.second) {
+            return Err(VitaError::InvalidNmea);
+        }
+        Ok(Some(time))
+    }
+
+    /// Parses a `ddmmyy` date field.
+    pub(super) fn ddmmyy(field: &str) -> Result<Option<NmeaDate>, VitaError> {
+        if field.is_empty() {
+            return Ok(None);
+        }
+        if field.len() != 6 {
+            return Err(VitaError::InvalidNmea);
+        }
+        let parse = |range| {
+            field
+                .get(range)
+                .and_then(|s: &str| s.parse::<u8>().ok())
+                .ok_or(VitaError::InvalidNmea)
+        };
+        let yy = parse(4..6)? as u16;
+        // Two-digit years follow the usual GPS receiver pivot
+        let year = if yy < 80 { 2000 + yy } else { 1900 + yy };
+        date(year, parse(2..4)?, parse(0..2)?).map(Some)
+    }
+
+    /// Builds a validated date.
+    pub(super) fn date(year: u16, month: u8, day: u8) -> Result<NmeaDate, VitaError> {
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(VitaError::InvalidNmea);
+        }
+        Ok(NmeaDate { year, month, day })
+    }
+
+    /// Parses a `(d)ddmm.mmmm` coordinate and its hemisphere field
+    /// into signed degrees.
+    pub(super) fn coordinate(value: &str, hemisphere: &str) -> Result<Option<f64>, VitaError> {
+        if value.is_empty() {
+            return Ok(None);
+        }
+        let dot = value.find('.').unwrap_or(value.len());
+        if dot < 2 {
+            return Err(VitaError::InvalidNmea);
+        }
+        let (Some(degrees), Some(minutes)) = (value.get(..dot - 2), value.get(dot - 2..)) else {
+            return Err(VitaError::InvalidNmea);
+        };
+        let degrees: f64 = degrees.parse().map_err(|_| VitaError::InvalidNmea)?;
+        let minutes: f64 = minutes.parse().map_err(|_| VitaError::InvalidNmea)?;
+        let unsigned = degrees + minutes / 60.0;
+        match hemisphere {
+            "N" | "E" => Ok(Some(unsigned)),
+            "S" | "W" => Ok(Some(-unsigned)),
+            _ => Err(VitaError::InvalidNmea),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "nmea"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn parse_rmc_and_zda() {
+        let gps = GpsAscii::from_sentences(
+            0,
+            &[
+                "$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*68",
+                "$GPZDA,201530.00,04,07,2002,00,00*60",
+            ],
+        )
+        .unwrap();
+        let fix = gps.nmea_fix().unwrap();
+        assert_eq!(fix.valid, Some(true));
+        assert!((fix.latitude_deg.unwrap() - 49.274_166_666).abs() < 1e-6);
+        assert!((fix.longitude_deg.unwrap() + 123.185_333_333).abs() < 1e-6);
+        assert!((fix.speed_over_ground_mps.unwrap() - 0.257_222).abs() < 1e-6);
+        assert_eq!(fix.track_angle_deg, Some(54.7));
+        // ZDA comes last, so its time and date win
+        assert_eq!(
+            fix.date,
+            Some(NmeaDate {
+                year: 2002,
+                month: 7,
+                day: 4
+            })
+        );
+        let time = fix.time.unwrap();
+        assert_eq!((time.hour, time.minute, time.second), (20, 15, 30.0));
+    }
+
+    #[test]
+    fn reject_bad_checksum() {
+        let gps = GpsAscii::from_sentences(0, &["$GPZDA,201530.00,04,07,2002,00,00*61"]).unwrap();
+        assert!(matches!(gps.nmea_fix(), Err(VitaError::InvalidNmea)));
+        // Unsupported sentences are ignored
+        let gps = GpsAscii::from_sentences(0, &["$GPGSV,1,1,00*79"]).unwrap();
+        assert_eq!(gps.nmea_fix().unwrap(), NmeaFix::default());
+    }
+
+    #[test]
+    fn reject_non_ascii() {
+        // Valid checksum, but a multi-byte character in the date field
+        let mut fix = NmeaFix::default();
+        assert!(matches!(
+            fix.update("$GPRMC,123519,A,,,,,,,1é203*41"),
+            Err(VitaError::NonAscii)
+        ));
+        assert_eq!(fix, NmeaFix::default());
+
+        // High bytes read off the wire end up as non-ASCII characters
+        let mut gps = GpsAscii::from_sentences(0, &["$GPRMC,123519,A,,,,,,,1x203*41"]).unwrap();
+        let text = gps.text();
+        let pos = text.find('x').unwrap();
+        let (word, byte) = (pos / 4, pos % 4);
+        let mut bytes = gps.ascii[word].to_be_bytes();
+        bytes[byte] = 0xE9;
+        gps.ascii[word] = u32::from_be_bytes(bytes);
+        assert!(gps.text().contains('é'));
+        assert!(matches!(gps.nmea_fix(), Err(VitaError::NonAscii)));
+    }
+
+    #[test]
+    fn size_limit() {
+        let max_words = u16::MAX as usize - HEADER_WORDS - MIN_CONTEXT_PACKET_WORDS;
+        // "$" plus "\r\n" plus padding to a word boundary
+        let sentence = |words: usize| format!("${}", "A".repeat(words * 4 - 3));
+        let gps = GpsAscii::from_sentences(0, &[sentence(max_words).as_str()]).unwrap();
+        assert_eq!(gps.size_words() as usize, max_words + HEADER_WORDS);
+        let mut packet = crate::Vrt::new_context_packet();
+        packet
+            .payload_mut()
+            .context_mut()
+            .unwrap()
+            .set_gps_ascii(Some(gps));
+        packet.update_packet_size();
+        assert_eq!(packet.header().packet_size(), u16::MAX);
+        assert!(matches!(
+            GpsAscii::from_sentences(0, &[sentence(max_words + 1).as_str()]),
+            Err(VitaError::OutOfRange)
+        ));
+    }
 }
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the health status field
+(ANSI/VITA-49.2-2017 section 9.10.2).
+
+The health status field conveys a device-specific status code in its
+lower 16 bits. The upper 16 bits are reserved.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+/// Base health status data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HealthStatus(u32);
+
+impl HealthStatus {
+    /// Create a new `HealthStatus` object given a status code.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::HealthStatus;
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_health_status(Some(HealthStatus::new(0x0102)));
+    /// assert_eq!(context.health_status().unwrap().code(), 0x0102);
+    /// ```
+    pub fn new(code: u16) -> HealthStatus {
+        HealthStatus(code as u32)
+    }
+
+    /// Gets the size of the health status structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the raw 32-bit value of the field.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the device-specific status code.
+    pub fn code(&self) -> u16 {
+        (self.0 & 0xFFFF) as u16
+    }
+
+    /// Sets the device-specific status code.
+    pub fn set_code(&mut self, code: u16) {
+        self.0 = (self.0 & 0xFFFF_0000) | code as u32;
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "Health status code: {:#06x}", self.code())
+    }
+}
@@ -0,0 +1,264 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the index list field
+(ANSI/VITA-49.2-2017 section 9.3.2).
+
+The index list carries a list of 8, 16, or 32-bit entries (e.g. the
+indices of the channels or bins a packet refers to). The first word
+holds the total size of the field in words, and the second word holds
+the entry size and number of entries. Entries are packed into the
+remaining words, most significant bits first, and the final word is
+zero-padded.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::VitaError;
+
+/// Maximum number of entries (20-bit field).
+const MAX_ENTRIES: usize = 0xF_FFFF;
+
+/// Size of each index list entry.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndexEntrySize {
+    /// 8-bit entries.
+    Bits8,
+    /// 16-bit entries.
+    Bits16,
+    /// 32-bit entries.
+    Bits32,
+}
+
+impl IndexEntrySize {
+    /// Gets the entry size in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            IndexEntrySize::Bits8 => 8,
+            IndexEntrySize::Bits16 => 16,
+            IndexEntrySize::Bits32 => 32,
+        }
+    }
+
+    fn entries_per_word(&self) -> usize {
+        (32 / self.bits()) as usize
+    }
+
+    fn max_entry(&self) -> u32 {
+        u32::MAX >> (32 - self.bits())
+    }
+}
+
+/// Base index list structure.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexList {
+    total_size: u32,
+    w2: u32,
+    #[deku(count = "(*total_size as usize).saturating_sub(2)")]
+    words: Vec<u32>,
+}
+
+impl Default for IndexList {
+    fn default() -> Self {
+        IndexList {
+            total_size: 2,
+            w2: 4 << 28,
+            words: Vec::new(),
+        }
+    }
+}
+
+impl IndexList {
+    /// Create a new index list with the given entry size and entries.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if an entry doesn't fit in the
+    /// entry size, there are more than 1048575 entries, or the field
+    /// would be larger than 65535 words.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{IndexEntrySize, IndexList};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let list = IndexList::new(IndexEntrySize::Bits16, &[3, 7, 12])?;
+    /// assert_eq!(list.size_words(), 4);
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_index_list(Some(list));
+    /// packet.update_packet_size();
+    ///
+    /// let parsed = Vrt::try_from(&packet.to_bytes()?[..])?;
+    /// let list = parsed.payload().context().unwrap().index_list().unwrap();
+    /// assert_eq!(list.entry_size(), Some(IndexEntrySize::Bits16));
+    /// assert_eq!(list.entries(), vec![3, 7, 12]);
+    /// assert!(IndexList::new(IndexEntrySize::Bits8, &[256]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(entry_size: IndexEntrySize, entries: &[u32]) -> Result<IndexList, VitaError> {
+        if entries.len() > MAX_ENTRIES || entries.iter().any(|e| *e > entry_size.max_entry()) {
+            return Err(VitaError::OutOfRange);
+        }
+        let bits = entry_size.bits();
+        let words: Vec<u32> = entries
+            .chunks(entry_size.entries_per_word())
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |acc, (i, e)| acc | (e << (32 - bits * (i as u32 + 1))))
+            })
+            .collect();
+        // Packet sizes are 16-bit word counts
+        if 2 + words.len() > u16::MAX as usize {
+            return Err(VitaError::OutOfRange);
+        }
+        Ok(IndexList {
+            total_size: 2 + words.len() as u32,
+            w2: ((bits / 8) << 28) | entries.len() as u32,
+            words,
+        })
+    }
+
+    /// Create a builder for an index list with the given entry size.
+    pub fn builder(entry_size: IndexEntrySize) -> IndexListBuilder {
+        IndexListBuilder {
+            entry_size,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Gets the size of the index list in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (2 + self.words.len()) as u16
+    }
+
+    /// Gets the entry size, or `None` if the field holds a reserved value.
+    pub fn entry_size(&self) -> Option<IndexEntrySize> {
+        match self.w2 >> 28 {
+            1 => Some(IndexEntrySize::Bits8),
+            2 => Some(IndexEntrySize::Bits16),
+            4 => Some(IndexEntrySize::Bits32),
+            _ => None,
+        }
+    }
+
+    /// Gets the number of entries.
+    pub fn len(&self) -> usize {
+        (self.w2 & MAX_ENTRIES as u32) as usize
+    }
+
+    /// Returns true if the list holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the entry at `index`, if there is one.
+    pub fn entry(&self, index: usize) -> Option<u32> {
+        let entry_size = self.entry_size()?;
+        if index >= self.len() {
+            return None;
+        }
+        let per_word = entry_size.entries_per_word();
+        let word = self.words.get(index / per_word)?;
+        let shift = 32 - entry_size.bits() * ((index % per_word) as u32 + 1);
+        Some((word >> shift) & entry_size.max_entry())
+    }
+
+    /// Gets all entries. Returns an empty list if the entry size is
+    /// a reserved value.
+    pub fn entries(&self) -> Vec<u32> {
+        (0..self.len()).map_while(|i| self.entry(i)).collect()
+    }
+}
+
+impl fmt::Display for IndexList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self.entry_size() {
+            Some(size) => write!(f, "{} {}-bit entries: ", self.len(), size.bits())?,
+            None => write!(f, "{} entries (reserved size): ", self.len())?,
+        }
+        write!(f, "{:?}", self.entries())
+    }
+}
+
+/// Builder for [`IndexList`].
+#[derive(Clone, Debug)]
+pub struct IndexListBuilder {
+    entry_size: IndexEntrySize,
+    entries: Vec<u32>,
+}
+
+impl IndexListBuilder {
+    /// Appends an entry.
+    pub fn entry(mut self, entry: u32) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Appends several entries.
+    pub fn entries(mut self, entries: impl IntoIterator<Item = u32>) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    /// Builds the index list.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if an entry doesn't fit in the
+    /// entry size, there are more than 1048575 entries, or the field
+    /// would be larger than 65535 words.
+    pub fn build(self) -> Result<IndexList, VitaError> {
+        IndexList::new(self.entry_size, &self.entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packing() {
+        let list = IndexList::builder(IndexEntrySize::Bits8)
+            .entries([0x01, 0x02, 0x03, 0x04, 0x05])
+            .build()
+            .unwrap();
+        assert_eq!(list.words, vec![0x0102_0304, 0x0500_0000]);
+        assert_eq!(list.total_size, 4);
+        assert_eq!(list.w2, (1 << 28) | 5);
+        assert_eq!(list.entry(4), Some(5));
+        assert_eq!(list.entry(5), None);
+
+        let list = IndexList::new(IndexEntrySize::Bits32, &[u32::MAX, 0]).unwrap();
+        assert_eq!(list.entries(), vec![u32::MAX, 0]);
+        assert_eq!(list.size_words(), 4);
+    }
+
+    #[test]
+    fn size_limit() {
+        let max_words = u16::MAX as usize - 2;
+        let list = IndexList::new(IndexEntrySize::Bits32, &vec![1; max_words]).unwrap();
+        assert_eq!(list.size_words(), u16::MAX);
+        assert!(matches!(
+            IndexList::new(IndexEntrySize::Bits32, &vec![1; max_words + 1]),
+            Err(VitaError::OutOfRange)
+        ));
+        // Smaller entries pack more per word
+        let list = IndexList::builder(IndexEntrySize::Bits8)
+            .entries(vec![1; max_words * 4])
+            .build()
+            .unwrap();
+        assert_eq!(list.size_words(), u16::MAX);
+        assert!(IndexList::builder(IndexEntrySize::Bits8)
+            .entries(vec![1; max_words * 4 + 1])
+            .build()
+            .is_err());
+    }
+}
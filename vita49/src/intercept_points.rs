@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the second and third order
+intercept points field (ANSI/VITA-49.2-2017 section 9.5.6).
+
+The field conveys the input-referred second order (IIP2) and third
+order (IIP3) intercept points of the device, in dBm.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+use fixed::{types::extra::U7, FixedI16};
+
+use crate::VitaError;
+
+/// Base intercept points data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterceptPoints(i32);
+
+/// Converts a power level in dBm to its 16-bit fixed-point form.
+fn to_bits(dbm: f32) -> Result<i32, VitaError> {
+    let v = FixedI16::<U7>::checked_from_num(dbm).ok_or(VitaError::OutOfRange)?;
+    Ok(v.to_bits() as u16 as i32)
+}
+
+impl InterceptPoints {
+    /// Create a new `InterceptPoints` object given IIP2 and IIP3 in dBm.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if either value can't be
+    /// represented (-256 to just under 256 dBm).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::InterceptPoints;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_second_and_third_order_intercept_points(Some(InterceptPoints::new(45.5, 20.25)?));
+    /// let ip = context.second_and_third_order_intercept_points().unwrap();
+    /// assert_eq!(ip.iip2_dbm(), 45.5);
+    /// assert_eq!(ip.iip3_dbm(), 20.25);
+    /// assert!(InterceptPoints::new(300.0, 0.0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(iip2_dbm: f32, iip3_dbm: f32) -> Result<InterceptPoints, VitaError> {
+        Ok(InterceptPoints(
+            (to_bits(iip2_dbm)? << 16) | to_bits(iip3_dbm)?,
+        ))
+    }
+
+    /// Gets the size of the intercept points structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the second order input intercept point (dBm).
+    pub fn iip2_dbm(&self) -> f32 {
+        FixedI16::<U7>::from_bits((self.0 >> 16) as i16).to_num()
+    }
+
+    /// Sets the second order input intercept point (dBm).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+    pub fn set_iip2_dbm(&mut self, iip2_dbm: f32) -> Result<(), VitaError> {
+        self.0 = (self.0 & 0x0000_FFFF) | (to_bits(iip2_dbm)? << 16);
+        Ok(())
+    }
+
+    /// Gets the third order input intercept point (dBm).
+    pub fn iip3_dbm(&self) -> f32 {
+        FixedI16::<U7>::from_bits((self.0 & 0xFFFF) as i16).to_num()
+    }
+
+    /// Sets the third order input intercept point (dBm).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+    pub fn set_iip3_dbm(&mut self, iip3_dbm: f32) -> Result<(), VitaError> {
+        self.0 = (self.0 & (0xFFFF_0000u32 as i32)) | to_bits(iip3_dbm)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for InterceptPoints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "IIP2: {} dBm, IIP3: {} dBm",
+            self.iip2_dbm(),
+            self.iip3_dbm()
+        )
+    }
+}
@@ -11,63 +11,132 @@
 
 mod ack;
 mod ack_response;
+mod array_of_cifs;
+mod array_of_records;
+mod beam_widths;
+mod buffer_size;
 mod cancellation;
 mod cif0;
 mod cif1;
 mod cif2;
 mod cif3;
 mod cif7;
+#[cfg(feature = "cif7")]
+mod cif7_accumulator;
+mod cif_payload;
 mod class_id;
 mod command;
 mod command_payload;
 mod context;
 mod context_association_lists;
+mod context_delta;
 mod control;
 mod control_ack_mode;
+mod controllee;
+mod country_code;
 mod device_id;
+mod eb_no_ber;
 mod ecef_ephemeris;
+mod ems_device_class;
 mod errors;
+mod field_access;
+mod field_timestamp;
+mod fix_prologue;
 mod formatted_gps;
 mod gain;
 mod gps_ascii;
+mod health_status;
+mod index_list;
+mod intercept_points;
+mod optional_db;
+mod packed_ascii;
 mod packet_header;
 mod payload;
+mod pointing_vector;
+mod pointing_vector_struct;
+mod polarization;
 mod query_ack;
+mod sector_scan;
 mod signal_data;
+mod snr_noise_figure;
+mod spec_compliance;
 mod spectrum;
+mod state_event_indicators;
+mod stream_topology;
 mod threshold;
+mod time_interval;
+mod timestamp;
+mod timestamp_details;
 mod trailer;
+mod version_build_code;
 mod vrt;
 
 // Public exports
 pub use crate::ack::{Ack, AckLevel};
 pub use crate::ack_response::AckResponse;
+pub use crate::array_of_cifs::{ArrayOfCifs, CifRecord};
+pub use crate::array_of_records::ArrayOfRecordsHeader;
+pub use crate::beam_widths::BeamWidths;
+pub use crate::buffer_size::BufferSize;
 pub use crate::cancellation::Cancellation;
 pub use crate::cif0::*;
 pub use crate::cif1::*;
 pub use crate::cif2::*;
 pub use crate::cif3::*;
-pub use crate::cif7::Cif7;
+pub use crate::cif7::{Cif7, Cif7Attr, Cif7Belief, Cif7Manipulators, Cif7Probability};
+#[cfg(feature = "cif7")]
+pub use crate::cif7_accumulator::Cif7Accumulator;
+pub use crate::cif_payload::CifPayload;
 pub use crate::class_id::ClassIdentifier;
 pub use crate::command::Command;
 pub use crate::command_payload::CommandPayload;
 pub use crate::context::Context;
-pub use crate::context_association_lists::ContextAssociationLists;
+pub use crate::context_association_lists::{
+    Association, AssociationKind, ContextAssociationGraph, ContextAssociationLists,
+    ContextAssociationListsBuilder,
+};
+pub use crate::context_delta::{ContextDelta, FieldChange};
 pub use crate::control::Control;
 pub use crate::control_ack_mode::*;
+pub use crate::controllee::{Controllee, ControlleeHandler};
+pub use crate::country_code::CountryCode;
 pub use crate::device_id::DeviceId;
-pub use crate::ecef_ephemeris::EcefEphemeris;
+pub use crate::eb_no_ber::EbNoBer;
+pub use crate::ecef_ephemeris::{EcefEphemeris, GeodeticPosition};
+pub use crate::ems_device_class::EmsDeviceClass;
 pub use crate::errors::VitaError;
+pub use crate::field_access::{CifField, CifFieldAccess, FieldValue};
+pub use crate::field_timestamp::FieldTimestamp;
 pub use crate::formatted_gps::FormattedGps;
 pub use crate::gain::Gain;
 pub use crate::gps_ascii::GpsAscii;
+#[cfg(feature = "nmea")]
+pub use crate::gps_ascii::{FixQuality, NmeaDate, NmeaFix, NmeaTime};
+pub use crate::health_status::HealthStatus;
+pub use crate::index_list::{IndexEntrySize, IndexList, IndexListBuilder};
+pub use crate::intercept_points::InterceptPoints;
+pub use crate::packed_ascii::{Operator, PlatformDisplay};
 pub use crate::packet_header::*;
 pub use crate::payload::Payload;
+pub use crate::pointing_vector::PointingVector;
+pub use crate::pointing_vector_struct::{
+    PointingVectorRecord, PointingVectorStruct, PointingVectorStructBuilder,
+};
+pub use crate::polarization::Polarization;
 pub use crate::query_ack::QueryAck;
+pub use crate::sector_scan::{SectorScan, SectorScanBuilder, SectorScanRecord};
 pub use crate::signal_data::SignalData;
+pub use crate::snr_noise_figure::SnrNoiseFigure;
+pub use crate::spec_compliance::V49SpecCompliance;
 pub use crate::spectrum::*;
+pub use crate::state_event_indicators::StateEventIndicators;
+pub use crate::stream_topology::{StreamEdge, StreamRelation, StreamTopology};
 pub use crate::threshold::Threshold;
+pub use crate::time_interval::TimeInterval;
+pub use crate::timestamp::VitaTimestamp;
+pub use crate::timestamp_details::{TimestampDetails, TimestampEpoch};
 pub use crate::trailer::Trailer;
+pub use crate::version_build_code::VersionBuildCode;
 pub use crate::vrt::Vrt;
 
 /// Standard imports for the most commonly used structures and
@@ -77,13 +146,14 @@ pub mod prelude {
     pub use crate::cif1::{Cif1, Cif1Fields, Cif1Manipulators};
     pub use crate::cif2::{Cif2, Cif2Fields, Cif2Manipulators};
     pub use crate::cif3::{Cif3, Cif3Fields, Cif3Manipulators};
-    pub use crate::cif7::Cif7;
+    pub use crate::cif7::{Cif7, Cif7Attr, Cif7Manipulators};
     pub use crate::class_id::ClassIdentifier;
     pub use crate::context::Context;
     pub use crate::errors::VitaError;
     pub use crate::packet_header::*;
     pub use crate::payload::Payload;
     pub use crate::signal_data::SignalData;
+    pub use crate::timestamp::VitaTimestamp;
     pub use crate::vrt::Vrt;
     pub use deku::writer::Writer;
     pub use deku::{DekuContainerRead, DekuContainerWrite, DekuReader, DekuWriter};
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Shared encoding for the paired 16-bit dB subfields of the SNR/noise
+figure (ANSI/VITA-49.2-2017 section 9.5.7) and Eb/No/BER (section
+9.5.17) fields.
+
+Each subfield is a signed fixed-point value with a radix point to the
+right of bit 7. The value 0x7FFF means the subfield is unspecified.
+*/
+
+use fixed::{types::extra::U7, FixedI16};
+
+use crate::VitaError;
+
+/// Subfield value indicating an unspecified measurement.
+const UNSPECIFIED: i16 = 0x7FFF;
+
+/// Field value with both subfields unspecified.
+pub(crate) const UNSPECIFIED_PAIR: u32 = ((UNSPECIFIED as u32) << 16) | UNSPECIFIED as u32;
+
+/// Decodes a subfield, returning `None` if it's unspecified.
+pub(crate) fn decode(bits: u16) -> Option<f32> {
+    let bits = bits as i16;
+    if bits == UNSPECIFIED {
+        None
+    } else {
+        Some(FixedI16::<U7>::from_bits(bits).to_num())
+    }
+}
+
+/// Encodes a subfield into the low 16 bits of the result. `None` encodes
+/// as unspecified.
+///
+/// # Errors
+/// Returns [`VitaError::OutOfRange`] if the value can't be represented
+/// or would collide with the unspecified value.
+pub(crate) fn encode(db: Option<f32>) -> Result<u32, VitaError> {
+    let bits = match db {
+        Some(v) => {
+            let bits = FixedI16::<U7>::checked_from_num(v)
+                .ok_or(VitaError::OutOfRange)?
+                .to_bits();
+            if bits == UNSPECIFIED {
+                return Err(VitaError::OutOfRange);
+            }
+            bits
+        }
+        None => UNSPECIFIED,
+    };
+    Ok(bits as u16 as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode() {
+        assert_eq!(encode(None).unwrap(), 0x7FFF);
+        assert_eq!(decode(0x7FFF), None);
+        assert_eq!(encode(Some(-1.0)).unwrap(), 0xFF80);
+        assert_eq!(decode(0xFF80), Some(-1.0));
+        assert_eq!(decode(encode(Some(-256.0)).unwrap() as u16), Some(-256.0));
+        // The largest value is the sentinel, so it can't be a measurement
+        assert!(matches!(
+            encode(Some(FixedI16::<U7>::MAX.to_num())),
+            Err(VitaError::OutOfRange)
+        ));
+        assert!(matches!(encode(Some(-256.5)), Err(VitaError::OutOfRange)));
+        assert!(matches!(encode(Some(f32::NAN)), Err(VitaError::OutOfRange)));
+    }
+}
@@ -0,0 +1,161 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the operator and platform
+display fields (ANSI/VITA-49.2-2017 sections 9.8.2 and 9.8.5).
+
+Both fields pack up to four ASCII characters into a single word, most
+significant byte first. Unused trailing bytes are zero.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::VitaError;
+
+/// Pack up to four ASCII characters into a word, MSB first.
+fn pack(text: &str) -> Result<u32, VitaError> {
+    if !text.is_ascii() {
+        return Err(VitaError::NonAscii);
+    }
+    if text.len() > 4 {
+        return Err(VitaError::OutOfRange);
+    }
+    let mut bytes = [0; 4];
+    bytes[..text.len()].copy_from_slice(text.as_bytes());
+    Ok(u32::from_be_bytes(bytes))
+}
+
+/// Unpack a word into text, dropping zero padding.
+fn unpack(word: u32) -> String {
+    word.to_be_bytes()
+        .iter()
+        .filter(|b| **b != 0)
+        .map(|b| char::from(*b))
+        .collect()
+}
+
+macro_rules! packed_ascii_field {
+    ($(#[$meta:meta])* $name:ident, $field:literal) => {
+        $(#[$meta])*
+        #[derive(
+            Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+        )]
+        #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name(u32);
+
+        impl $name {
+            #[doc = concat!("Create a new ", $field, " from up to four ASCII characters.")]
+            ///
+            /// # Errors
+            /// Returns [`VitaError::NonAscii`] if the text isn't ASCII, or
+            /// [`VitaError::OutOfRange`] if it's longer than four characters.
+            pub fn new(text: &str) -> Result<$name, VitaError> {
+                Ok($name(pack(text)?))
+            }
+
+            #[doc = concat!("Gets the size of the ", $field, " structure in 32-bit words.")]
+            pub fn size_words(&self) -> u16 {
+                (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+            }
+
+            /// Gets the raw 32-bit value of the field.
+            pub fn as_u32(&self) -> u32 {
+                self.0
+            }
+
+            /// Gets the packed characters (with padding removed).
+            pub fn text(&self) -> String {
+                unpack(self.0)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                write!(f, "{}", self.text())
+            }
+        }
+    };
+}
+
+packed_ascii_field!(
+    /// Base operator data structure.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::Operator;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_operator(Some(Operator::new("NAVY")?));
+    /// assert_eq!(context.operator().unwrap().text(), "NAVY");
+    /// assert!(Operator::new("ARMY1").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    Operator,
+    "operator"
+);
+
+packed_ascii_field!(
+    /// Base platform display data structure.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::PlatformDisplay;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_platform_display(Some(PlatformDisplay::new("F16")?));
+    /// assert_eq!(context.platform_display().unwrap().text(), "F16");
+    /// assert_eq!(context.platform_display().unwrap().as_u32(), 0x4631_3600);
+    /// # Ok(())
+    /// # }
+    /// ```
+    PlatformDisplay,
+    "platform display"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn round_trip() {
+        let operator = Operator::new("AB").unwrap();
+        let display = PlatformDisplay::new("WXYZ").unwrap();
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_operator(Some(operator));
+        context.set_platform_display(Some(display));
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+        // Operator comes before platform display, both MSB first
+        assert!(bytes.ends_with(b"AB\0\0WXYZ"));
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        let context = parsed.payload().context().unwrap();
+        assert_eq!(context.operator(), Some(&operator));
+        assert_eq!(context.operator().unwrap().text(), "AB");
+        assert_eq!(context.platform_display().unwrap().to_string(), "WXYZ");
+        assert_eq!(Operator::new("").unwrap().as_u32(), 0);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(Operator::new("ABCDE"), Err(VitaError::OutOfRange)));
+        assert!(matches!(Operator::new("é"), Err(VitaError::NonAscii)));
+        assert!(matches!(
+            PlatformDisplay::new("ABCDE"),
+            Err(VitaError::OutOfRange)
+        ));
+        assert!(matches!(
+            PlatformDisplay::new("F-1é"),
+            Err(VitaError::NonAscii)
+        ));
+    }
+}
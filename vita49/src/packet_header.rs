@@ -8,7 +8,7 @@ Data structures and methods related to the packet header format
 
 use deku::prelude::*;
 
-use crate::VitaError;
+use crate::{ClassIdentifier, VitaError};
 
 /// Base packet header data structure.
 #[derive(
@@ -463,6 +463,102 @@ impl PacketHeader {
         ret
     }
 
+    /// Decodes just the packet header from the first 32-bit word of
+    /// a raw (big-endian) VRT packet without touching the rest of
+    /// the buffer.
+    ///
+    /// Unlike a full parse, a reserved packet type is reported as an
+    /// error here rather than causing a panic later on.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// packet.set_stream_id(Some(0xDEADBEEF));
+    /// let bytes = packet.to_bytes()?;
+    /// let header = PacketHeader::peek(&bytes)?;
+    /// assert_eq!(header.packet_type(), PacketType::Context);
+    /// assert_eq!(header.packet_size() as usize * 4, bytes.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn peek(buf: &[u8]) -> Result<PacketHeader, VitaError> {
+        let word = read_word(buf, 0)?;
+        let header = PacketHeader {
+            hword_1: (word >> 16) as u16,
+            packet_size: (word & 0xFFFF) as u16,
+        };
+        PacketType::try_from(((header.hword_1 >> 12) & 0b1111) as u8)
+            .map_err(|_| VitaError::ReservedPacketType)?;
+        Ok(header)
+    }
+
+    /// Decodes the packet prologue (header, stream ID, class ID, and
+    /// timestamps) from a raw (big-endian) VRT packet. The payload
+    /// and trailer are never read, so the buffer only needs to be long
+    /// enough to hold the prologue itself. This makes it well suited to
+    /// routing and filtering decisions where a full parse with
+    /// `Vrt::try_from()` would be wasted effort.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut packet = Vrt::new_signal_data_packet();
+    /// packet.set_stream_id(Some(0xDEADBEEF));
+    /// packet.set_integer_timestamp(Some(1_700_000_000), Tsi::Utc)?;
+    /// packet.set_signal_payload(&vec![0; 1024])?;
+    /// let bytes = packet.to_bytes()?;
+    ///
+    /// let prologue = PacketHeader::peek_prologue(&bytes)?;
+    /// assert_eq!(prologue.packet_type(), PacketType::SignalData);
+    /// assert_eq!(prologue.stream_id, Some(0xDEADBEEF));
+    /// assert_eq!(prologue.tsi(), Tsi::Utc);
+    /// assert_eq!(prologue.integer_timestamp, Some(1_700_000_000));
+    /// assert_eq!(prologue.fractional_timestamp, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn peek_prologue(buf: &[u8]) -> Result<Prologue, VitaError> {
+        let header = PacketHeader::peek(buf)?;
+        let mut offset = 4;
+        let stream_id = if header.stream_id_included() {
+            let sid = read_word(buf, offset)?;
+            offset += 4;
+            Some(sid)
+        } else {
+            None
+        };
+        let class_id = if header.class_id_included() {
+            let cid =
+                ClassIdentifier::from_words(read_word(buf, offset)?, read_word(buf, offset + 4)?);
+            offset += 8;
+            Some(cid)
+        } else {
+            None
+        };
+        let integer_timestamp = if header.integer_timestamp_included() {
+            let ts = read_word(buf, offset)?;
+            offset += 4;
+            Some(ts)
+        } else {
+            None
+        };
+        let fractional_timestamp = if header.fractional_timestamp_included() {
+            Some(((read_word(buf, offset)? as u64) << 32) | read_word(buf, offset + 4)? as u64)
+        } else {
+            None
+        };
+        Ok(Prologue {
+            header,
+            stream_id,
+            class_id,
+            integer_timestamp,
+            fractional_timestamp,
+        })
+    }
+
     /// Creates a new signal data packet header with some sane defaults.
     pub fn new_signal_data_header() -> PacketHeader {
         let mut ret = PacketHeader {
@@ -526,6 +622,56 @@ impl PacketHeader {
     }
 }
 
+/// Reads a big-endian 32-bit word at the given byte offset.
+fn read_word(buf: &[u8], offset: usize) -> Result<u32, VitaError> {
+    buf.get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(VitaError::BufferTooShort)
+}
+
+/// The packet prologue: every field that precedes the payload
+/// (ANSI/VITA-49.2-2017 section 5.1). Produced by
+/// [`PacketHeader::peek_prologue()`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Prologue {
+    /// Packet header.
+    pub header: PacketHeader,
+    /// Stream identifier (if included).
+    pub stream_id: Option<u32>,
+    /// Class identifier (if included).
+    pub class_id: Option<ClassIdentifier>,
+    /// Integer timestamp (if included).
+    pub integer_timestamp: Option<u32>,
+    /// Fractional timestamp (if included).
+    pub fractional_timestamp: Option<u64>,
+}
+
+impl Prologue {
+    /// Gets the packet type.
+    pub fn packet_type(&self) -> PacketType {
+        self.header.packet_type()
+    }
+    /// Gets the packet size (32-bit words).
+    pub fn packet_size(&self) -> u16 {
+        self.header.packet_size()
+    }
+    /// Gets the TimeStamp-Integer (TSI) field.
+    pub fn tsi(&self) -> Tsi {
+        self.header.tsi()
+    }
+    /// Gets the TimeStamp-Fractional (TSF) field.
+    pub fn tsf(&self) -> Tsf {
+        self.header.tsf()
+    }
+    /// Gets the size of the prologue in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        1 + self.stream_id.map_or(0, |_| 1)
+            + self.class_id.map_or(0, |_| 2)
+            + self.integer_timestamp.map_or(0, |_| 1)
+            + self.fractional_timestamp.map_or(0, |_| 2)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -553,4 +699,51 @@ mod tests {
         // Now the class_id_included bit should be true
         assert!(packet.header().class_id_included());
     }
+
+    #[test]
+    fn peek_prologue_matches_full_parse() {
+        use crate::prelude::*;
+        let mut packet = Vrt::new_context_packet();
+        packet.set_stream_id(Some(0x1234_5678));
+        let mut cid = ClassIdentifier::default();
+        cid.set_oui(0xAB_CDEF);
+        cid.set_information_class_code(0x1122);
+        cid.set_packet_class_code(0x3344);
+        packet.set_class_id(Some(cid));
+        packet.set_integer_timestamp(Some(42), Tsi::Gps).unwrap();
+        packet
+            .set_fractional_timestamp(Some(0x0102_0304_0506_0708), Tsf::RealTimePs)
+            .unwrap();
+        packet.update_packet_size();
+        let bytes = packet.to_bytes().unwrap();
+
+        let parsed = Vrt::try_from(&bytes[..]).unwrap();
+        let prologue = PacketHeader::peek_prologue(&bytes).unwrap();
+        assert_eq!(prologue.header, *parsed.header());
+        assert_eq!(prologue.stream_id, parsed.stream_id());
+        assert_eq!(prologue.class_id.as_ref(), parsed.class_id());
+        assert_eq!(prologue.integer_timestamp, parsed.integer_timestamp());
+        assert_eq!(prologue.fractional_timestamp, parsed.fractional_timestamp());
+        assert_eq!(prologue.size_words(), 7);
+
+        // Only the prologue needs to be present
+        assert!(PacketHeader::peek_prologue(&bytes[..28]).is_ok());
+        assert!(matches!(
+            PacketHeader::peek_prologue(&bytes[..27]),
+            Err(VitaError::BufferTooShort)
+        ));
+    }
+
+    #[test]
+    fn peek_rejects_reserved_packet_type() {
+        use crate::prelude::*;
+        assert!(matches!(
+            PacketHeader::peek(&[0xF0, 0, 0, 1]),
+            Err(VitaError::ReservedPacketType)
+        ));
+        assert!(matches!(
+            PacketHeader::peek(&[0x10, 0]),
+            Err(VitaError::BufferTooShort)
+        ));
+    }
 }
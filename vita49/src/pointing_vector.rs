@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the 3-D pointing vector
+field (ANSI/VITA-49.2-2017 section 9.4.1.1).
+
+The pointing vector is described by an elevation angle (relative to
+the horizon) and an azimuthal angle (clockwise from the reference
+direction), both in degrees.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+use fixed::{types::extra::U7, FixedI16, FixedU16};
+
+use crate::VitaError;
+
+/// Base 3-D pointing vector data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointingVector(i32);
+
+impl PointingVector {
+    /// Create a new `PointingVector` object given the elevation and
+    /// azimuthal angles in degrees.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the elevation isn't within ±90
+    /// degrees or the azimuth isn't within 0 to 360 degrees (exclusive).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::PointingVector;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_three_d_pointing_vector(Some(PointingVector::new(-12.5, 270.25)?));
+    /// let pv = context.three_d_pointing_vector().unwrap();
+    /// assert_eq!(pv.elevation_deg(), -12.5);
+    /// assert_eq!(pv.azimuth_deg(), 270.25);
+    /// assert!(PointingVector::new(0.0, 360.0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(elevation_deg: f32, azimuth_deg: f32) -> Result<PointingVector, VitaError> {
+        let mut ret = PointingVector::default();
+        ret.set_elevation_deg(elevation_deg)?;
+        ret.set_azimuth_deg(azimuth_deg)?;
+        Ok(ret)
+    }
+
+    /// Gets the size of the pointing vector structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the elevation angle (degrees).
+    pub fn elevation_deg(&self) -> f32 {
+        let elevation = ((self.0 >> 16) & 0xFFFF) as i16;
+        FixedI16::<U7>::from_bits(elevation).to_num()
+    }
+
+    /// Sets the elevation angle (degrees).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the angle isn't within ±90 degrees.
+    pub fn set_elevation_deg(&mut self, elevation_deg: f32) -> Result<(), VitaError> {
+        if !(-90.0..=90.0).contains(&elevation_deg) {
+            return Err(VitaError::OutOfRange);
+        }
+        let elevation = FixedI16::<U7>::from_num(elevation_deg).to_bits() as u16 as i32;
+        self.0 = (self.0 & 0x0000_FFFF) | (elevation << 16);
+        Ok(())
+    }
+
+    /// Gets the azimuthal angle (degrees).
+    pub fn azimuth_deg(&self) -> f32 {
+        let azimuth = (self.0 & 0xFFFF) as u16;
+        FixedU16::<U7>::from_bits(azimuth).to_num()
+    }
+
+    /// Sets the azimuthal angle (degrees).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the angle isn't within 0 to 360
+    /// degrees (exclusive).
+    pub fn set_azimuth_deg(&mut self, azimuth_deg: f32) -> Result<(), VitaError> {
+        if !(0.0..360.0).contains(&azimuth_deg) {
+            return Err(VitaError::OutOfRange);
+        }
+        let azimuth = FixedU16::<U7>::from_num(azimuth_deg).to_bits() as i32;
+        self.0 = (self.0 & (0xFFFF_0000u32 as i32)) | azimuth;
+        Ok(())
+    }
+}
+
+impl fmt::Display for PointingVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Elevation: {} deg, Azimuth: {} deg",
+            self.elevation_deg(),
+            self.azimuth_deg()
+        )
+    }
+}
@@ -0,0 +1,334 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the 3-D pointing vector
+structure field (ANSI/VITA-49.2-2017 section 9.4.1.2).
+
+The field uses the array-of-records format so that a single context
+packet can describe several beams (e.g. for a beam-steering array).
+Each record may carry an elevation/azimuth pointing vector and a
+steering mode. Every record in the array carries the same subfields.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::array_of_records::{ArrayOfRecordsHeader, Record};
+use crate::{PointingVector, VitaError};
+
+/// Record indicator bit for the elevation/azimuth subfield.
+const POINTING_VECTOR_BIT: u32 = 1 << 31;
+/// Record indicator bit for the steering mode subfield.
+const STEERING_MODE_BIT: u32 = 1 << 30;
+/// All record indicator bits understood by this crate.
+const KNOWN_BITS: u32 = POINTING_VECTOR_BIT | STEERING_MODE_BIT;
+
+/// Number of words taken by the known subfields flagged in `indicators`.
+fn known_words(indicators: u32) -> usize {
+    (indicators & KNOWN_BITS).count_ones() as usize
+}
+
+/// A single 3-D pointing vector structure record.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
+#[deku(
+    endian = "endian",
+    ctx = "endian: deku::ctx::Endian, indicators: u32, words_per_record: u16"
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointingVectorRecord {
+    #[deku(cond = "indicators & POINTING_VECTOR_BIT != 0")]
+    pointing_vector: Option<PointingVector>,
+    #[deku(cond = "indicators & STEERING_MODE_BIT != 0")]
+    steering_mode: Option<u32>,
+    #[deku(count = "(words_per_record as usize).saturating_sub(known_words(indicators))")]
+    extra_words: Vec<u32>,
+}
+
+impl PointingVectorRecord {
+    /// Create a new record carrying only a pointing vector.
+    pub fn new(pointing_vector: PointingVector) -> PointingVectorRecord {
+        PointingVectorRecord {
+            pointing_vector: Some(pointing_vector),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the record with the given steering mode set.
+    pub fn with_steering_mode(mut self, steering_mode: u32) -> PointingVectorRecord {
+        self.steering_mode = Some(steering_mode);
+        self
+    }
+
+    /// Gets the size of the record in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (self.pointing_vector.is_some() as usize
+            + self.steering_mode.is_some() as usize
+            + self.extra_words.len()) as u16
+    }
+
+    /// Gets the pointing vector (elevation/azimuth) subfield.
+    pub fn pointing_vector(&self) -> Option<PointingVector> {
+        self.pointing_vector
+    }
+
+    /// Sets the pointing vector (elevation/azimuth) subfield.
+    pub fn set_pointing_vector(&mut self, pointing_vector: Option<PointingVector>) {
+        self.pointing_vector = pointing_vector;
+    }
+
+    /// Gets the steering mode subfield.
+    pub fn steering_mode(&self) -> Option<u32> {
+        self.steering_mode
+    }
+
+    /// Sets the steering mode subfield.
+    pub fn set_steering_mode(&mut self, steering_mode: Option<u32>) {
+        self.steering_mode = steering_mode;
+    }
+
+    /// Gets any trailing record words this crate doesn't interpret.
+    pub fn extra_words(&self) -> &[u32] {
+        &self.extra_words
+    }
+}
+
+impl Record for PointingVectorRecord {
+    fn known_indicators(&self) -> u32 {
+        let mut ret = 0;
+        if self.pointing_vector.is_some() {
+            ret |= POINTING_VECTOR_BIT;
+        }
+        if self.steering_mode.is_some() {
+            ret |= STEERING_MODE_BIT;
+        }
+        ret
+    }
+    fn size_words(&self) -> u16 {
+        self.size_words()
+    }
+    fn extra_words(&self) -> &[u32] {
+        &self.extra_words
+    }
+}
+
+impl fmt::Display for PointingVectorRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match &self.pointing_vector {
+            Some(pv) => write!(f, "{pv}")?,
+            None => write!(f, "No pointing vector")?,
+        }
+        if let Some(mode) = self.steering_mode {
+            write!(f, ", Steering mode: {mode}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Base 3-D pointing vector structure.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointingVectorStruct {
+    #[deku(assert = "header.records_fit(known_words(header.indicators()))")]
+    header: ArrayOfRecordsHeader,
+    #[deku(
+        count = "header.num_records()",
+        ctx = "header.indicators(), header.words_per_record()"
+    )]
+    records: Vec<PointingVectorRecord>,
+}
+
+impl PointingVectorStruct {
+    /// Create a builder for a 3-D pointing vector structure.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{PointingVector, PointingVectorRecord, PointingVectorStruct};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pvs = PointingVectorStruct::builder()
+    ///     .record(PointingVectorRecord::new(PointingVector::new(10.0, 45.0)?).with_steering_mode(1))
+    ///     .record(PointingVectorRecord::new(PointingVector::new(-5.5, 300.0)?).with_steering_mode(2))
+    ///     .build()?;
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_three_d_pointing_vector_struct(Some(pvs));
+    /// packet.update_packet_size();
+    ///
+    /// let parsed = Vrt::try_from(&packet.to_bytes()?[..])?;
+    /// let pvs = parsed.payload().context().unwrap().three_d_pointing_vector_struct().unwrap();
+    /// assert_eq!(pvs.records().len(), 2);
+    /// let beam = &pvs.records()[1];
+    /// assert_eq!(beam.pointing_vector().unwrap().elevation_deg(), -5.5);
+    /// assert_eq!(beam.pointing_vector().unwrap().azimuth_deg(), 300.0);
+    /// assert_eq!(beam.steering_mode(), Some(2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> PointingVectorStructBuilder {
+        PointingVectorStructBuilder::default()
+    }
+
+    /// Gets the size of the structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        self.header.size_words() + self.records.iter().map(|r| r.size_words()).sum::<u16>()
+    }
+
+    /// Gets the array-of-records header.
+    pub fn header(&self) -> &ArrayOfRecordsHeader {
+        &self.header
+    }
+
+    /// Gets the pointing vector records.
+    pub fn records(&self) -> &[PointingVectorRecord] {
+        &self.records
+    }
+
+    /// Sets the pointing vector records, updating the header to match.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::MismatchedRecords`] if the records don't all
+    /// carry the same subfields, or [`VitaError::OutOfRange`] if there
+    /// are more than 4095 records.
+    pub fn set_records(&mut self, records: Vec<PointingVectorRecord>) -> Result<(), VitaError> {
+        let app_specific_header = self.header.app_specific_header().to_vec();
+        self.header = ArrayOfRecordsHeader::for_records(
+            &records,
+            KNOWN_BITS,
+            self.header.indicators(),
+            app_specific_header,
+        )?;
+        self.records = records;
+        Ok(())
+    }
+}
+
+impl fmt::Display for PointingVectorStruct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.header)?;
+        for (i, record) in self.records.iter().enumerate() {
+            write!(f, "\n  Record {i}: {record}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`PointingVectorStruct`].
+#[derive(Clone, Debug, Default)]
+pub struct PointingVectorStructBuilder {
+    app_specific_header: Vec<u32>,
+    records: Vec<PointingVectorRecord>,
+}
+
+impl PointingVectorStructBuilder {
+    /// Sets the application-specific header words.
+    pub fn app_specific_header(mut self, words: Vec<u32>) -> Self {
+        self.app_specific_header = words;
+        self
+    }
+
+    /// Appends a record.
+    pub fn record(mut self, record: PointingVectorRecord) -> Self {
+        self.records.push(record);
+        self
+    }
+
+    /// Appends several records.
+    pub fn records(mut self, records: impl IntoIterator<Item = PointingVectorRecord>) -> Self {
+        self.records.extend(records);
+        self
+    }
+
+    /// Builds the structure.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::MismatchedRecords`] if the records don't all
+    /// carry the same subfields, or [`VitaError::OutOfRange`] if there
+    /// are more than 4095 records or 252 application-specific header words.
+    pub fn build(self) -> Result<PointingVectorStruct, VitaError> {
+        let header = ArrayOfRecordsHeader::for_records(
+            &self.records,
+            KNOWN_BITS,
+            0,
+            self.app_specific_header,
+        )?;
+        Ok(PointingVectorStruct {
+            header,
+            records: self.records,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn mismatched_records_rejected() {
+        let pv = PointingVector::new(1.0, 2.0).unwrap();
+        let res = PointingVectorStruct::builder()
+            .record(PointingVectorRecord::new(pv))
+            .record(PointingVectorRecord::new(pv).with_steering_mode(3))
+            .build();
+        assert!(matches!(res, Err(VitaError::MismatchedRecords)));
+    }
+
+    #[test]
+    fn unknown_subfields_preserved() {
+        // Header: 4 words (1 app-specific), 3 words per record, 1 record,
+        // pointing vector + an unknown subfield (bit 0).
+        let words: [u32; 7] = [
+            7,
+            (4 << 24) | (3 << 12) | 1,
+            POINTING_VECTOR_BIT | 1,
+            0xDEAD_BEEF,
+            (45 << 7 << 16) | (90 << 7),
+            0x1234_5678,
+            0x9ABC_DEF0,
+        ];
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_be_bytes()).collect();
+        let mut cursor = std::io::Cursor::new(&bytes);
+        let mut reader = deku::reader::Reader::new(&mut cursor);
+        let pvs = PointingVectorStruct::from_reader_with_ctx(&mut reader, deku::ctx::Endian::Big)
+            .unwrap();
+        assert_eq!(pvs.header().app_specific_header(), &[0xDEAD_BEEF]);
+        assert_eq!(pvs.records().len(), 1);
+        let record = &pvs.records()[0];
+        assert_eq!(record.pointing_vector().unwrap().elevation_deg(), 45.0);
+        assert_eq!(record.steering_mode(), None);
+        assert_eq!(record.extra_words(), &[0x1234_5678, 0x9ABC_DEF0]);
+        assert_eq!(pvs.size_words(), 7);
+
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_three_d_pointing_vector_struct(Some(pvs));
+        packet.update_packet_size();
+        assert!(packet.to_bytes().unwrap().ends_with(&bytes));
+    }
+
+    #[test]
+    fn records_too_small_rejected() {
+        // Pointing vector and steering mode need 2 words, but the header
+        // says each record is only 1 word.
+        // The trailing words keep the read itself from running short.
+        let words: [u32; 7] = [
+            5,
+            (3 << 24) | (1 << 12) | 2,
+            POINTING_VECTOR_BIT | STEERING_MODE_BIT,
+            0,
+            0,
+            0,
+            0,
+        ];
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_be_bytes()).collect();
+        let mut cursor = std::io::Cursor::new(&bytes);
+        let mut reader = deku::reader::Reader::new(&mut cursor);
+        assert!(
+            PointingVectorStruct::from_reader_with_ctx(&mut reader, deku::ctx::Endian::Big)
+                .is_err()
+        );
+    }
+}
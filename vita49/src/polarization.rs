@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the polarization field
+(ANSI/VITA-49.2-2017 section 9.4.8).
+
+The polarization of the signal is described by a tilt angle and an
+ellipticity angle, both in radians.
+*/
+
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+use core::fmt;
+use deku::prelude::*;
+use fixed::{types::extra::U13, FixedI16};
+
+use crate::VitaError;
+
+/// Base polarization data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polarization(i32);
+
+impl Polarization {
+    /// Create a new `Polarization` object given the tilt and ellipticity
+    /// angles in radians.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the tilt angle isn't within
+    /// ±π/2 or the ellipticity angle isn't within ±π/4.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::Polarization;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_polarization(Some(Polarization::new(0.5, -0.25)?));
+    /// let pol = context.polarization().unwrap();
+    /// assert_eq!(pol.tilt_angle_rad(), 0.5);
+    /// assert_eq!(pol.ellipticity_angle_rad(), -0.25);
+    /// assert!(Polarization::new(2.0, 0.0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(tilt_angle_rad: f32, ellipticity_angle_rad: f32) -> Result<Polarization, VitaError> {
+        let mut ret = Polarization::default();
+        ret.set_tilt_angle_rad(tilt_angle_rad)?;
+        ret.set_ellipticity_angle_rad(ellipticity_angle_rad)?;
+        Ok(ret)
+    }
+
+    /// Gets the size of the polarization structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the tilt angle (radians).
+    pub fn tilt_angle_rad(&self) -> f32 {
+        let tilt = ((self.0 >> 16) & 0xFFFF) as i16;
+        FixedI16::<U13>::from_bits(tilt).to_num()
+    }
+
+    /// Sets the tilt angle (radians).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the angle isn't within ±π/2.
+    pub fn set_tilt_angle_rad(&mut self, tilt_angle_rad: f32) -> Result<(), VitaError> {
+        if !(-FRAC_PI_2..=FRAC_PI_2).contains(&tilt_angle_rad) {
+            return Err(VitaError::OutOfRange);
+        }
+        let tilt = FixedI16::<U13>::from_num(tilt_angle_rad).to_bits() as u16 as i32;
+        self.0 = (self.0 & 0x0000_FFFF) | (tilt << 16);
+        Ok(())
+    }
+
+    /// Gets the ellipticity angle (radians).
+    pub fn ellipticity_angle_rad(&self) -> f32 {
+        let ellipticity = (self.0 & 0xFFFF) as i16;
+        FixedI16::<U13>::from_bits(ellipticity).to_num()
+    }
+
+    /// Sets the ellipticity angle (radians).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the angle isn't within ±π/4.
+    pub fn set_ellipticity_angle_rad(
+        &mut self,
+        ellipticity_angle_rad: f32,
+    ) -> Result<(), VitaError> {
+        if !(-FRAC_PI_4..=FRAC_PI_4).contains(&ellipticity_angle_rad) {
+            return Err(VitaError::OutOfRange);
+        }
+        let ellipticity = FixedI16::<U13>::from_num(ellipticity_angle_rad).to_bits() as u16 as i32;
+        self.0 = (self.0 & (0xFFFF_0000u32 as i32)) | ellipticity;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Polarization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Tilt: {} rad, Ellipticity: {} rad",
+            self.tilt_angle_rad(),
+            self.ellipticity_angle_rad()
+        )
+    }
+}
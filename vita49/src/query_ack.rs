@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{cif7::Cif7Opts, prelude::*};
+use crate::{cif7::Cif7Opts, prelude::*, CommandPayload};
 use deku::prelude::*;
 use std::fmt;
 
@@ -72,6 +72,21 @@ impl QueryAck {
     }
 }
 
+impl TryFrom<CommandPayload> for QueryAck {
+    type Error = CommandPayload;
+
+    fn try_from(value: CommandPayload) -> Result<Self, Self::Error> {
+        match value {
+            CommandPayload::QueryAck(c) => Ok(c),
+            a => Err(a),
+        }
+    }
+}
+
+crate::cif_payload::cif_payload!(QueryAck);
+
+crate::cif7::cif7_manipulators!(QueryAck);
+
 impl Cif0Manipulators for QueryAck {
     fn cif0(&self) -> &Cif0 {
         &self.cif0
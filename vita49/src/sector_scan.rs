@@ -0,0 +1,464 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the sector step/scan field
+(ANSI/VITA-49.2-2017 section 9.6.2).
+
+The field uses the array-of-records format to describe a set of
+sectors that a receiver steps or scans through. The header's bit-mapped
+indicator word (the "subfield CIF") selects which subfields every record
+carries.
+
+Subfields use the formats of the matching context fields: frequencies
+and the resolution bandwidth are 64-bit values in Hz with a 20-bit radix
+(like the CIF0 frequency fields), the dwell time is a 64-bit femtosecond
+count (like the CIF3 dwell field), and the start time is an integer and
+fractional timestamp pair (like the packet timestamp). Records don't
+carry their own TSI/TSF codes, so the start time is read using the modes
+of the packet that carries the field.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+use fixed::{types::extra::U20, FixedI64};
+
+use crate::array_of_records::{ArrayOfRecordsHeader, Record};
+use crate::{Gain, Threshold, TimeInterval, Tsf, Tsi, VitaError, VitaTimestamp};
+
+/// Record indicator bit for the sector number subfield.
+const SECTOR_NUMBER_BIT: u32 = 1 << 31;
+/// Record indicator bit for the F1 start frequency subfield.
+const F1_START_FREQ_BIT: u32 = 1 << 30;
+/// Record indicator bit for the F2 stop frequency subfield.
+const F2_STOP_FREQ_BIT: u32 = 1 << 29;
+/// Record indicator bit for the resolution bandwidth subfield.
+const RESOLUTION_BW_BIT: u32 = 1 << 28;
+/// Record indicator bit for the tune step size subfield.
+const TUNE_STEP_SIZE_BIT: u32 = 1 << 27;
+/// Record indicator bit for the number of points subfield.
+const NUM_POINTS_BIT: u32 = 1 << 26;
+/// Record indicator bit for the default gain subfield.
+const DEFAULT_GAIN_BIT: u32 = 1 << 25;
+/// Record indicator bit for the threshold subfield.
+const THRESHOLD_BIT: u32 = 1 << 24;
+/// Record indicator bit for the dwell time subfield.
+const DWELL_TIME_BIT: u32 = 1 << 23;
+/// Record indicator bit for the start time (integer) subfield.
+const START_TIME_INTEGER_BIT: u32 = 1 << 22;
+/// Record indicator bit for the start time (fractional) subfield.
+const START_TIME_FRACTIONAL_BIT: u32 = 1 << 21;
+/// All record indicator bits understood by this crate.
+const KNOWN_BITS: u32 = 0xFFE0_0000;
+/// Record indicator bits for 64-bit subfields.
+const TWO_WORD_BITS: u32 = F1_START_FREQ_BIT
+    | F2_STOP_FREQ_BIT
+    | RESOLUTION_BW_BIT
+    | TUNE_STEP_SIZE_BIT
+    | DWELL_TIME_BIT
+    | START_TIME_FRACTIONAL_BIT;
+
+/// Number of words taken by the known subfields flagged in `indicators`.
+fn known_words(indicators: u32) -> usize {
+    ((indicators & KNOWN_BITS).count_ones() + (indicators & TWO_WORD_BITS).count_ones()) as usize
+}
+
+/// A single sector step/scan record.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
+#[deku(
+    endian = "endian",
+    ctx = "endian: deku::ctx::Endian, indicators: u32, words_per_record: u16"
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SectorScanRecord {
+    #[deku(cond = "indicators & SECTOR_NUMBER_BIT != 0")]
+    sector_number: Option<u32>,
+    #[deku(cond = "indicators & F1_START_FREQ_BIT != 0")]
+    f1_start_freq: Option<i64>,
+    #[deku(cond = "indicators & F2_STOP_FREQ_BIT != 0")]
+    f2_stop_freq: Option<i64>,
+    #[deku(cond = "indicators & RESOLUTION_BW_BIT != 0")]
+    resolution_bw: Option<i64>,
+    #[deku(cond = "indicators & TUNE_STEP_SIZE_BIT != 0")]
+    tune_step_size: Option<i64>,
+    #[deku(cond = "indicators & NUM_POINTS_BIT != 0")]
+    num_points: Option<u32>,
+    #[deku(cond = "indicators & DEFAULT_GAIN_BIT != 0")]
+    default_gain: Option<Gain>,
+    #[deku(cond = "indicators & THRESHOLD_BIT != 0")]
+    threshold: Option<Threshold>,
+    #[deku(cond = "indicators & DWELL_TIME_BIT != 0")]
+    dwell_time: Option<TimeInterval>,
+    #[deku(cond = "indicators & START_TIME_INTEGER_BIT != 0")]
+    start_time_integer: Option<u32>,
+    #[deku(cond = "indicators & START_TIME_FRACTIONAL_BIT != 0")]
+    start_time_fractional: Option<u64>,
+    #[deku(count = "(words_per_record as usize).saturating_sub(known_words(indicators))")]
+    extra_words: Vec<u32>,
+}
+
+/// Generates a getter/setter pair for a 64-bit, radix-20 frequency subfield.
+macro_rules! freq_subfield {
+    ($field:ident, $get:ident, $set:ident, $desc:literal) => {
+        #[doc = concat!("Gets the ", $desc, " (Hz).")]
+        pub fn $get(&self) -> Option<f64> {
+            self.$field.map(|v| FixedI64::<U20>::from_bits(v).to_num())
+        }
+
+        #[doc = concat!("Sets the ", $desc, " (Hz). Passing `None` removes the subfield.")]
+        ///
+        /// # Errors
+        /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+        pub fn $set(&mut self, hz: Option<f64>) -> Result<(), VitaError> {
+            self.$field = match hz {
+                Some(v) => Some(
+                    FixedI64::<U20>::checked_from_num(v)
+                        .ok_or(VitaError::OutOfRange)?
+                        .to_bits(),
+                ),
+                None => None,
+            };
+            Ok(())
+        }
+    };
+}
+
+/// Generates a getter/setter pair for a plain subfield.
+macro_rules! plain_subfield {
+    ($field:ident, $set:ident, $ty:ty, $desc:literal) => {
+        #[doc = concat!("Gets the ", $desc, ".")]
+        pub fn $field(&self) -> Option<$ty> {
+            self.$field
+        }
+
+        #[doc = concat!("Sets the ", $desc, ". Passing `None` removes the subfield.")]
+        pub fn $set(&mut self, $field: Option<$ty>) {
+            self.$field = $field;
+        }
+    };
+}
+
+#[rustfmt::skip]
+impl SectorScanRecord {
+    /// Create a new, empty record.
+    pub fn new() -> SectorScanRecord {
+        SectorScanRecord::default()
+    }
+
+    /// Gets the size of the record in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (known_words(self.known_indicators()) + self.extra_words.len()) as u16
+    }
+
+    plain_subfield!(sector_number, set_sector_number, u32, "sector number");
+    freq_subfield!(f1_start_freq, f1_start_freq_hz, set_f1_start_freq_hz, "F1 start frequency");
+    freq_subfield!(f2_stop_freq, f2_stop_freq_hz, set_f2_stop_freq_hz, "F2 stop frequency");
+    freq_subfield!(resolution_bw, resolution_bw_hz, set_resolution_bw_hz, "resolution bandwidth");
+    freq_subfield!(tune_step_size, tune_step_size_hz, set_tune_step_size_hz, "tune step size");
+    plain_subfield!(num_points, set_num_points, u32, "number of points");
+    plain_subfield!(default_gain, set_default_gain, Gain, "default gain");
+    plain_subfield!(threshold, set_threshold, Threshold, "threshold");
+    plain_subfield!(dwell_time, set_dwell_time, TimeInterval, "dwell time");
+
+    /// Gets the start time, interpreted with the given TSI/TSF modes
+    /// (normally those of the packet carrying the field). Returns `None`
+    /// if the record carries neither start time subfield.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::TimestampModeMismatch`] if the subfields the
+    /// record carries don't match the modes (e.g. an integer start time
+    /// with [`Tsi::Null`]).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::SectorScanRecord;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let start = VitaTimestamp::new(Tsi::Utc, Some(1_700_000_000), Tsf::RealTimePs, Some(5))?;
+    /// let mut record = SectorScanRecord::new();
+    /// record.set_start_time(Some(start));
+    /// assert_eq!(record.start_time(Tsi::Utc, Tsf::RealTimePs)?, Some(start));
+    /// assert!(record.start_time(Tsi::Utc, Tsf::Null).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_time(&self, tsi: Tsi, tsf: Tsf) -> Result<Option<VitaTimestamp>, VitaError> {
+        if self.start_time_integer.is_none() && self.start_time_fractional.is_none() {
+            return Ok(None);
+        }
+        VitaTimestamp::new(tsi, self.start_time_integer, tsf, self.start_time_fractional).map(Some)
+    }
+
+    /// Sets the start time. Only the parts the timestamp's modes include
+    /// are carried (its TSI/TSF codes aren't); passing `None` removes
+    /// both subfields.
+    pub fn set_start_time(&mut self, start_time: Option<VitaTimestamp>) {
+        self.start_time_integer = start_time.and_then(|ts| ts.integer());
+        self.start_time_fractional = start_time.and_then(|ts| ts.fractional());
+    }
+
+    /// Gets any trailing record words this crate doesn't interpret.
+    pub fn extra_words(&self) -> &[u32] {
+        &self.extra_words
+    }
+}
+
+impl Record for SectorScanRecord {
+    fn known_indicators(&self) -> u32 {
+        [
+            (self.sector_number.is_some(), SECTOR_NUMBER_BIT),
+            (self.f1_start_freq.is_some(), F1_START_FREQ_BIT),
+            (self.f2_stop_freq.is_some(), F2_STOP_FREQ_BIT),
+            (self.resolution_bw.is_some(), RESOLUTION_BW_BIT),
+            (self.tune_step_size.is_some(), TUNE_STEP_SIZE_BIT),
+            (self.num_points.is_some(), NUM_POINTS_BIT),
+            (self.default_gain.is_some(), DEFAULT_GAIN_BIT),
+            (self.threshold.is_some(), THRESHOLD_BIT),
+            (self.dwell_time.is_some(), DWELL_TIME_BIT),
+            (self.start_time_integer.is_some(), START_TIME_INTEGER_BIT),
+            (
+                self.start_time_fractional.is_some(),
+                START_TIME_FRACTIONAL_BIT,
+            ),
+        ]
+        .into_iter()
+        .filter(|(present, _)| *present)
+        .fold(0, |acc, (_, bit)| acc | bit)
+    }
+    fn size_words(&self) -> u16 {
+        self.size_words()
+    }
+    fn extra_words(&self) -> &[u32] {
+        &self.extra_words
+    }
+}
+
+impl fmt::Display for SectorScanRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "Sector {:?}", self.sector_number)?;
+        if let (Some(f1), Some(f2)) = (self.f1_start_freq_hz(), self.f2_stop_freq_hz()) {
+            write!(f, ", {f1} Hz - {f2} Hz")?;
+        }
+        if let Some(rbw) = self.resolution_bw_hz() {
+            write!(f, ", RBW: {rbw} Hz")?;
+        }
+        if let Some(step) = self.tune_step_size_hz() {
+            write!(f, ", Step: {step} Hz")?;
+        }
+        if let Some(dwell) = self.dwell_time {
+            write!(f, ", Dwell: {dwell}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Base sector step/scan structure.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SectorScan {
+    #[deku(assert = "header.records_fit(known_words(header.indicators()))")]
+    header: ArrayOfRecordsHeader,
+    #[deku(
+        count = "header.num_records()",
+        ctx = "header.indicators(), header.words_per_record()"
+    )]
+    records: Vec<SectorScanRecord>,
+}
+
+impl SectorScan {
+    /// Create a builder for a sector step/scan structure.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{SectorScan, SectorScanRecord};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut records = Vec::new();
+    /// for i in 0..3 {
+    ///     let mut record = SectorScanRecord::new();
+    ///     record.set_sector_number(Some(i));
+    ///     record.set_f1_start_freq_hz(Some(1e9 + i as f64 * 100e6))?;
+    ///     record.set_f2_stop_freq_hz(Some(1.1e9 + i as f64 * 100e6))?;
+    ///     record.set_resolution_bw_hz(Some(10e3))?;
+    ///     records.push(record);
+    /// }
+    /// let scan = SectorScan::builder().records(records).build()?;
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_sector_scan(Some(scan));
+    /// packet.update_packet_size();
+    ///
+    /// let parsed = Vrt::try_from(&packet.to_bytes()?[..])?;
+    /// let scan = parsed.payload().context().unwrap().sector_scan().unwrap();
+    /// assert_eq!(scan.records().len(), 3);
+    /// assert_eq!(scan.records()[2].sector_number(), Some(2));
+    /// assert_eq!(scan.records()[2].f1_start_freq_hz(), Some(1.2e9));
+    /// assert_eq!(scan.records()[2].tune_step_size_hz(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> SectorScanBuilder {
+        SectorScanBuilder::default()
+    }
+
+    /// Gets the size of the structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        self.header.size_words() + self.records.iter().map(|r| r.size_words()).sum::<u16>()
+    }
+
+    /// Gets the array-of-records header.
+    pub fn header(&self) -> &ArrayOfRecordsHeader {
+        &self.header
+    }
+
+    /// Gets the sector records.
+    pub fn records(&self) -> &[SectorScanRecord] {
+        &self.records
+    }
+
+    /// Sets the sector records, updating the header to match.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::MismatchedRecords`] if the records don't all
+    /// carry the same subfields, or [`VitaError::OutOfRange`] if there
+    /// are more than 4095 records.
+    pub fn set_records(&mut self, records: Vec<SectorScanRecord>) -> Result<(), VitaError> {
+        let app_specific_header = self.header.app_specific_header().to_vec();
+        self.header = ArrayOfRecordsHeader::for_records(
+            &records,
+            KNOWN_BITS,
+            self.header.indicators(),
+            app_specific_header,
+        )?;
+        self.records = records;
+        Ok(())
+    }
+}
+
+impl fmt::Display for SectorScan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.header)?;
+        for record in &self.records {
+            write!(f, "\n  {record}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`SectorScan`].
+#[derive(Clone, Debug, Default)]
+pub struct SectorScanBuilder {
+    app_specific_header: Vec<u32>,
+    records: Vec<SectorScanRecord>,
+}
+
+impl SectorScanBuilder {
+    /// Sets the application-specific header words.
+    pub fn app_specific_header(mut self, words: Vec<u32>) -> Self {
+        self.app_specific_header = words;
+        self
+    }
+
+    /// Appends a record.
+    pub fn record(mut self, record: SectorScanRecord) -> Self {
+        self.records.push(record);
+        self
+    }
+
+    /// Appends several records.
+    pub fn records(mut self, records: impl IntoIterator<Item = SectorScanRecord>) -> Self {
+        self.records.extend(records);
+        self
+    }
+
+    /// Builds the structure.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::MismatchedRecords`] if the records don't all
+    /// carry the same subfields, or [`VitaError::OutOfRange`] if there
+    /// are more than 4095 records or 252 application-specific header words.
+    pub fn build(self) -> Result<SectorScan, VitaError> {
+        let header = ArrayOfRecordsHeader::for_records(
+            &self.records,
+            KNOWN_BITS,
+            0,
+            self.app_specific_header,
+        )?;
+        Ok(SectorScan {
+            header,
+            records: self.records,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn record_layout() {
+        let mut record = SectorScanRecord::new();
+        record.set_sector_number(Some(7));
+        record.set_f1_start_freq_hz(Some(2.4e9)).unwrap();
+        record.set_default_gain(Some(Gain::new(10.0, 0.0)));
+        let start = VitaTimestamp::new(Tsi::Null, None, Tsf::SampleCount, Some(500)).unwrap();
+        record.set_start_time(Some(start));
+        assert_eq!(
+            record.known_indicators(),
+            SECTOR_NUMBER_BIT | F1_START_FREQ_BIT | DEFAULT_GAIN_BIT | START_TIME_FRACTIONAL_BIT
+        );
+        assert_eq!(record.size_words(), 6);
+
+        let scan = SectorScan::builder().record(record).build().unwrap();
+        assert_eq!(scan.header().words_per_record(), 6);
+        assert_eq!(scan.size_words(), 9);
+        assert!(SectorScanRecord::new()
+            .set_tune_step_size_hz(Some(1e30))
+            .is_err());
+    }
+
+    #[test]
+    fn dwell_and_start_time() {
+        let start =
+            VitaTimestamp::new(Tsi::Gps, Some(1_000), Tsf::RealTimePs, Some(250_000)).unwrap();
+        let dwell = TimeInterval::try_from(core::time::Duration::from_millis(5)).unwrap();
+        let mut record = SectorScanRecord::new();
+        record.set_dwell_time(Some(dwell));
+        record.set_start_time(Some(start));
+        assert_eq!(
+            record.known_indicators(),
+            DWELL_TIME_BIT | START_TIME_INTEGER_BIT | START_TIME_FRACTIONAL_BIT
+        );
+        assert_eq!(record.size_words(), 5);
+
+        let mut packet = Vrt::new_context_packet();
+        let context = packet.payload_mut().context_mut().unwrap();
+        context.set_sector_scan(Some(SectorScan::builder().record(record).build().unwrap()));
+        packet.update_packet_size();
+        let parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+        let scan = parsed.payload().context().unwrap().sector_scan().unwrap();
+        let record = &scan.records()[0];
+        assert_eq!(record.dwell_time(), Some(dwell));
+        assert_eq!(
+            record.dwell_time().unwrap().to_duration(),
+            Some(core::time::Duration::from_millis(5))
+        );
+        assert_eq!(
+            record.start_time(Tsi::Gps, Tsf::RealTimePs).unwrap(),
+            Some(start)
+        );
+        // The record only carries the values, not the modes
+        assert!(matches!(
+            record.start_time(Tsi::Null, Tsf::RealTimePs),
+            Err(VitaError::TimestampModeMismatch)
+        ));
+
+        let mut record = record.clone();
+        record.set_start_time(None);
+        assert_eq!(record.start_time(Tsi::Gps, Tsf::RealTimePs).unwrap(), None);
+        assert_eq!(record.size_words(), 2);
+    }
+}
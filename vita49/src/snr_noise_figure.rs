@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the SNR and noise figure field
+(ANSI/VITA-49.2-2017 section 9.5.7).
+
+Either subfield may be left unspecified, in which case it carries
+the value 0x7FFF and is reported as `None`.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::optional_db::{decode, encode, UNSPECIFIED_PAIR};
+use crate::VitaError;
+
+/// Base SNR and noise figure data structure.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnrNoiseFigure(u32);
+
+impl Default for SnrNoiseFigure {
+    fn default() -> Self {
+        SnrNoiseFigure(UNSPECIFIED_PAIR)
+    }
+}
+
+impl SnrNoiseFigure {
+    /// Create a new `SnrNoiseFigure` object given SNR and noise figure in dB. Passing
+    /// `None` leaves the subfield unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if either value can't be
+    /// represented (-256 to just under 255.99 dB).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::SnrNoiseFigure;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_snr_figure(Some(SnrNoiseFigure::new(Some(9.5), None)?));
+    /// let snr = context.snr_figure().unwrap();
+    /// assert_eq!(snr.snr_db(), Some(9.5));
+    /// assert_eq!(snr.noise_figure_db(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        snr_db: Option<f32>,
+        noise_figure_db: Option<f32>,
+    ) -> Result<SnrNoiseFigure, VitaError> {
+        Ok(SnrNoiseFigure(
+            (encode(snr_db)? << 16) | encode(noise_figure_db)?,
+        ))
+    }
+
+    /// Gets the size of the SNR and noise figure structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the signal-to-noise ratio (dB), if specified.
+    pub fn snr_db(&self) -> Option<f32> {
+        decode((self.0 >> 16) as u16)
+    }
+
+    /// Sets the signal-to-noise ratio (dB). Passing `None`
+    /// leaves the subfield unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+    pub fn set_snr_db(&mut self, snr_db: Option<f32>) -> Result<(), VitaError> {
+        self.0 = (self.0 & 0x0000_FFFF) | (encode(snr_db)? << 16);
+        Ok(())
+    }
+
+    /// Gets the noise figure (dB), if specified.
+    pub fn noise_figure_db(&self) -> Option<f32> {
+        decode((self.0 & 0xFFFF) as u16)
+    }
+
+    /// Sets the noise figure (dB). Passing `None` leaves the subfield
+    /// unspecified.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value can't be represented.
+    pub fn set_noise_figure_db(&mut self, noise_figure_db: Option<f32>) -> Result<(), VitaError> {
+        self.0 = (self.0 & 0xFFFF_0000) | encode(noise_figure_db)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for SnrNoiseFigure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "SNR: ")?;
+        match self.snr_db() {
+            Some(v) => write!(f, "{v} dB")?,
+            None => write!(f, "unspecified")?,
+        }
+        write!(f, ", Noise figure: ")?;
+        match self.noise_figure_db() {
+            Some(v) => write!(f, "{v} dB"),
+            None => write!(f, "unspecified"),
+        }
+    }
+}
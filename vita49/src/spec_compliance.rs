@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the V49 spec compliance field
+(ANSI/VITA-49.2-2017 section 9.10.3).
+
+The field identifies which revision of the VITA 49 standard the
+emitting device complies with.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+/// VITA 49 standard revision the emitter complies with.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(id_type = "u32", endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "u32", into = "u32")
+)]
+pub enum V49SpecCompliance {
+    /// ANSI/VITA-49.0.
+    #[deku(id = 0x1)]
+    V49_0,
+    /// ANSI/VITA-49.1.
+    #[deku(id = 0x2)]
+    V49_1,
+    /// ANSI/VITA-49A.
+    #[deku(id = 0x3)]
+    V49A,
+    /// ANSI/VITA-49.2.
+    #[deku(id = 0x4)]
+    #[default]
+    V49_2,
+    /// Any other (reserved or later) value.
+    #[deku(id_pat = "_")]
+    Other(u32),
+}
+
+impl V49SpecCompliance {
+    /// Gets the size of the spec compliance field in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        1
+    }
+
+    /// Gets the raw 32-bit value of the field.
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            V49SpecCompliance::V49_0 => 0x1,
+            V49SpecCompliance::V49_1 => 0x2,
+            V49SpecCompliance::V49A => 0x3,
+            V49SpecCompliance::V49_2 => 0x4,
+            V49SpecCompliance::Other(v) => *v,
+        }
+    }
+}
+
+impl From<u32> for V49SpecCompliance {
+    fn from(value: u32) -> Self {
+        match value {
+            0x1 => V49SpecCompliance::V49_0,
+            0x2 => V49SpecCompliance::V49_1,
+            0x3 => V49SpecCompliance::V49A,
+            0x4 => V49SpecCompliance::V49_2,
+            v => V49SpecCompliance::Other(v),
+        }
+    }
+}
+
+impl From<V49SpecCompliance> for u32 {
+    fn from(value: V49SpecCompliance) -> Self {
+        value.as_u32()
+    }
+}
+
+impl fmt::Display for V49SpecCompliance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            V49SpecCompliance::V49_0 => write!(f, "V49.0"),
+            V49SpecCompliance::V49_1 => write!(f, "V49.1"),
+            V49SpecCompliance::V49A => write!(f, "V49A"),
+            V49SpecCompliance::V49_2 => write!(f, "V49.2"),
+            V49SpecCompliance::Other(v) => write!(f, "Unknown ({v:#x})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn round_trip_other() {
+        for value in [V49SpecCompliance::V49_2, V49SpecCompliance::Other(0x1234)] {
+            let mut packet = Vrt::new_context_packet();
+            let context = packet.payload_mut().context_mut().unwrap();
+            context.set_v49_spec_compliance(Some(value));
+            packet.update_packet_size();
+            let parsed = Vrt::try_from(&packet.to_bytes().unwrap()[..]).unwrap();
+            let context = parsed.payload().context().unwrap();
+            assert_eq!(context.v49_spec_compliance(), Some(&value));
+            assert_eq!(V49SpecCompliance::from(value.as_u32()), value);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the state and event
+indicator field (ANSI/VITA-49.2-2017 section 9.10.8).
+
+Each indicator is paired with an enable bit. If the enable bit
+is clear, the indicator carries no meaning and is reported as
+`None`.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+/// Base state and event indicator data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateEventIndicators(u32);
+
+/// Generates a getter/setter pair for an enable/indicator bit pair.
+macro_rules! indicator {
+    ($get:ident, $set:ident, $enable_bit:literal, $indicator_bit:literal, $desc:literal) => {
+        #[doc = concat!("Returns the ", $desc, " indicator status if enabled.")]
+        pub fn $get(&self) -> Option<bool> {
+            if self.0 & (1 << $enable_bit) > 0 {
+                Some(self.0 & (1 << $indicator_bit) > 0)
+            } else {
+                None
+            }
+        }
+        #[doc = concat!("Sets the ", $desc, " indicator status. If `None` is passed, the indicator is disabled.")]
+        pub fn $set(&mut self, indicator: Option<bool>) {
+            self.0 &= !((1 << $enable_bit) | (1 << $indicator_bit));
+            if let Some(v) = indicator {
+                self.0 |= (1 << $enable_bit) | ((v as u32) << $indicator_bit);
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+impl StateEventIndicators {
+    /// Gets the size of the state and event indicator structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the raw 32-bit value of the field.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    indicator!(cal_time_indicator, set_cal_time_indicator, 31, 19, "calibrated time");
+    indicator!(valid_data_indicator, set_valid_data_indicator, 30, 18, "valid data");
+    indicator!(reference_lock_indicator, set_reference_lock_indicator, 29, 17, "reference lock");
+    indicator!(agc_indicator, set_agc_indicator, 28, 16, "AGC/MGC");
+    indicator!(detected_signal_indicator, set_detected_signal_indicator, 27, 15, "detected signal");
+    indicator!(spectral_inversion_indicator, set_spectral_inversion_indicator, 26, 14, "spectral inversion");
+    indicator!(over_range_indicator, set_over_range_indicator, 25, 13, "over-range");
+    indicator!(sample_loss_indicator, set_sample_loss_indicator, 24, 12, "sample loss");
+
+    /// Gets the user-defined bits (7-0).
+    pub fn user_defined(&self) -> u8 {
+        (self.0 & 0xFF) as u8
+    }
+
+    /// Sets the user-defined bits (7-0).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::StateEventIndicators;
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// let mut sei = StateEventIndicators::default();
+    /// sei.set_reference_lock_indicator(Some(true));
+    /// sei.set_over_range_indicator(Some(false));
+    /// sei.set_user_defined(0xA5);
+    /// context.set_state_indicators(Some(sei));
+    ///
+    /// let sei = context.state_indicators().unwrap();
+    /// assert_eq!(sei.reference_lock_indicator(), Some(true));
+    /// assert_eq!(sei.over_range_indicator(), Some(false));
+    /// assert_eq!(sei.sample_loss_indicator(), None);
+    /// assert_eq!(sei.user_defined(), 0xA5);
+    /// ```
+    pub fn set_user_defined(&mut self, bits: u8) {
+        self.0 = (self.0 & !0xFF) | bits as u32;
+    }
+}
+
+impl fmt::Display for StateEventIndicators {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "State/event indicators:")?;
+        writeln!(f, "  Calibrated time: {:?}", self.cal_time_indicator())?;
+        writeln!(f, "  Valid data: {:?}", self.valid_data_indicator())?;
+        writeln!(f, "  Reference lock: {:?}", self.reference_lock_indicator())?;
+        writeln!(f, "  AGC/MGC: {:?}", self.agc_indicator())?;
+        writeln!(f, "  Detected signal: {:?}", self.detected_signal_indicator())?;
+        writeln!(f, "  Spectral inversion: {:?}", self.spectral_inversion_indicator())?;
+        writeln!(f, "  Over-range: {:?}", self.over_range_indicator())?;
+        writeln!(f, "  Sample loss: {:?}", self.sample_loss_indicator())?;
+        writeln!(f, "  User-defined: {:#04x}", self.user_defined())?;
+        Ok(())
+    }
+}
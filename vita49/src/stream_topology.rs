@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods for rebuilding stream relationships from
+the CIF2 bind, cited SID, sibling SID, parent SID, and child SID fields
+(ANSI/VITA-49.2-2017 section 9.8).
+
+Each of these fields names one other stream that the stream carrying
+the context packet relates to. [`StreamTopology`] collects them from
+received context packets into a directed graph that can be queried
+(e.g. "all children of stream X") or exported to Graphviz DOT.
+*/
+
+use core::fmt::{self, Write};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Cif2Manipulators, Vrt};
+
+/// Kind of relationship between two streams.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StreamRelation {
+    /// The stream is bound to the related stream.
+    Bind,
+    /// The stream cites the related stream.
+    Cited,
+    /// The related stream is a sibling of the stream.
+    Sibling,
+    /// The related stream is the parent of the stream.
+    Parent,
+    /// The related stream is a child of the stream.
+    Child,
+}
+
+impl fmt::Display for StreamRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let s = match self {
+            StreamRelation::Bind => "bind",
+            StreamRelation::Cited => "cited",
+            StreamRelation::Sibling => "sibling",
+            StreamRelation::Parent => "parent",
+            StreamRelation::Child => "child",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A single directed relationship from one stream to another.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamEdge {
+    /// Stream ID of the context packet carrying the field.
+    pub from: u32,
+    /// Related stream ID.
+    pub to: u32,
+    /// Which field the relationship came from.
+    pub relation: StreamRelation,
+}
+
+/// Directed graph of stream relationships built from the CIF2 stream
+/// ID fields of a set of context packets.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct StreamTopology {
+    edges: BTreeMap<u32, Vec<StreamEdge>>,
+}
+
+impl StreamTopology {
+    /// Create an empty topology.
+    pub fn new() -> StreamTopology {
+        StreamTopology::default()
+    }
+
+    /// Builds a topology from a set of received packets. See
+    /// [`StreamTopology::update()`] for how each packet is handled.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{StreamRelation, StreamTopology};
+    /// let mut channelizer = Vrt::new_context_packet();
+    /// channelizer.set_stream_id(Some(1));
+    /// let mut channel_a = Vrt::new_context_packet();
+    /// channel_a.set_stream_id(Some(2));
+    /// channel_a.payload_mut().context_mut().unwrap().set_parent_sid(Some(1));
+    /// let mut channel_b = Vrt::new_context_packet();
+    /// channel_b.set_stream_id(Some(3));
+    /// let context = channel_b.payload_mut().context_mut().unwrap();
+    /// context.set_parent_sid(Some(1));
+    /// context.set_sibling_sid(Some(2));
+    ///
+    /// let topology = StreamTopology::from_packets([&channelizer, &channel_a, &channel_b]);
+    /// assert_eq!(topology.children(1), vec![2, 3]);
+    /// assert_eq!(topology.parents(3), vec![1]);
+    /// assert_eq!(topology.siblings(2), vec![3]);
+    /// assert_eq!(topology.related(3, StreamRelation::Sibling), vec![2]);
+    /// assert!(topology.to_dot().contains("3 -> 2 [label=\"sibling\"];"));
+    /// ```
+    pub fn from_packets<'a>(packets: impl IntoIterator<Item = &'a Vrt>) -> StreamTopology {
+        let mut ret = StreamTopology::new();
+        for packet in packets {
+            ret.update(packet);
+        }
+        ret
+    }
+
+    /// Updates the topology from a received packet. Packets that aren't
+    /// context packets or have no stream ID are ignored. A context packet
+    /// replaces any relationships previously announced by the same
+    /// stream.
+    pub fn update(&mut self, packet: &Vrt) {
+        let (Some(sid), Ok(context)) = (packet.stream_id(), packet.payload().context()) else {
+            return;
+        };
+        let edges = [
+            (context.bind(), StreamRelation::Bind),
+            (context.cited_sid(), StreamRelation::Cited),
+            (context.sibling_sid(), StreamRelation::Sibling),
+            (context.parent_sid(), StreamRelation::Parent),
+            (context.child_sid(), StreamRelation::Child),
+        ]
+        .into_iter()
+        .filter_map(|(to, relation)| {
+            to.map(|to| StreamEdge {
+                from: sid,
+                to: *to,
+                relation,
+            })
+        })
+        .collect();
+        self.edges.insert(sid, edges);
+    }
+
+    /// Removes a stream and every relationship it announced.
+    pub fn remove(&mut self, stream_id: u32) {
+        self.edges.remove(&stream_id);
+    }
+
+    /// Gets every stream ID in the graph (both those with context
+    /// packets and those only referenced by other streams).
+    pub fn stream_ids(&self) -> Vec<u32> {
+        let mut ret: BTreeSet<u32> = self.edges.keys().copied().collect();
+        ret.extend(self.edges().map(|e| e.to));
+        ret.into_iter().collect()
+    }
+
+    /// Gets every relationship in the graph.
+    pub fn edges(&self) -> impl Iterator<Item = &StreamEdge> {
+        self.edges.values().flatten()
+    }
+
+    /// Gets the streams the given stream's context names with the given
+    /// relation.
+    pub fn related(&self, stream_id: u32, relation: StreamRelation) -> Vec<u32> {
+        self.collect(|e| e.from == stream_id && e.relation == relation, |e| e.to)
+    }
+
+    /// Gets the streams whose context names the given stream with the
+    /// given relation.
+    pub fn relating(&self, stream_id: u32, relation: StreamRelation) -> Vec<u32> {
+        self.collect(|e| e.to == stream_id && e.relation == relation, |e| e.from)
+    }
+
+    /// Gets the children of a stream, whether announced by the stream
+    /// itself (child SID) or by the child (parent SID).
+    pub fn children(&self, stream_id: u32) -> Vec<u32> {
+        self.union(stream_id, StreamRelation::Child, StreamRelation::Parent)
+    }
+
+    /// Gets the parents of a stream, whether announced by the stream
+    /// itself (parent SID) or by the parent (child SID).
+    pub fn parents(&self, stream_id: u32) -> Vec<u32> {
+        self.union(stream_id, StreamRelation::Parent, StreamRelation::Child)
+    }
+
+    /// Gets the siblings of a stream, announced by either side.
+    pub fn siblings(&self, stream_id: u32) -> Vec<u32> {
+        self.union(stream_id, StreamRelation::Sibling, StreamRelation::Sibling)
+    }
+
+    /// Gets the streams bound to a stream, announced by either side.
+    pub fn bound(&self, stream_id: u32) -> Vec<u32> {
+        self.union(stream_id, StreamRelation::Bind, StreamRelation::Bind)
+    }
+
+    /// Gets every descendant of a stream (children, grandchildren, ...).
+    pub fn descendants(&self, stream_id: u32) -> Vec<u32> {
+        let mut seen = BTreeSet::new();
+        let mut pending = self.children(stream_id);
+        while let Some(sid) = pending.pop() {
+            if sid != stream_id && seen.insert(sid) {
+                pending.extend(self.children(sid));
+            }
+        }
+        seen.into_iter().collect()
+    }
+
+    /// Gets the streams that have no parent (the tops of the hierarchy).
+    pub fn roots(&self) -> Vec<u32> {
+        self.stream_ids()
+            .into_iter()
+            .filter(|sid| self.parents(*sid).is_empty())
+            .collect()
+    }
+
+    /// Exports the graph in Graphviz DOT format. Each relationship is an
+    /// edge labeled with its relation.
+    pub fn to_dot(&self) -> String {
+        let mut ret = String::from("digraph streams {\n");
+        for sid in self.stream_ids() {
+            // Writing to a String can't fail
+            let _ = writeln!(ret, "    {sid} [label=\"{sid:#010x}\"];");
+        }
+        for edge in self.edges() {
+            let _ = writeln!(
+                ret,
+                "    {} -> {} [label=\"{}\"];",
+                edge.from, edge.to, edge.relation
+            );
+        }
+        ret.push_str("}\n");
+        ret
+    }
+
+    fn collect(
+        &self,
+        filter: impl Fn(&StreamEdge) -> bool,
+        map: impl Fn(&StreamEdge) -> u32,
+    ) -> Vec<u32> {
+        let set: BTreeSet<u32> = self.edges().filter(|e| filter(e)).map(map).collect();
+        set.into_iter().collect()
+    }
+
+    fn union(&self, stream_id: u32, forward: StreamRelation, reverse: StreamRelation) -> Vec<u32> {
+        let set: BTreeSet<u32> = self
+            .related(stream_id, forward)
+            .into_iter()
+            .chain(self.relating(stream_id, reverse))
+            .collect();
+        set.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn packet(sid: u32, f: impl FnOnce(&mut Context)) -> Vrt {
+        let mut packet = Vrt::new_context_packet();
+        packet.set_stream_id(Some(sid));
+        f(packet.payload_mut().context_mut().unwrap());
+        packet
+    }
+
+    #[test]
+    fn hierarchy() {
+        let root = packet(1, |c| c.set_child_sid(Some(2)));
+        let mid = packet(2, |c| c.set_bind(Some(10)));
+        let leaf = packet(3, |c| c.set_parent_sid(Some(2)));
+        let mut topology = StreamTopology::from_packets([&root, &mid, &leaf]);
+        assert_eq!(topology.children(2), vec![3]);
+        assert_eq!(topology.parents(2), vec![1]);
+        assert_eq!(topology.descendants(1), vec![2, 3]);
+        assert_eq!(topology.bound(10), vec![2]);
+        assert_eq!(topology.roots(), vec![1, 10]);
+        assert_eq!(topology.stream_ids(), vec![1, 2, 3, 10]);
+
+        // A newer context packet replaces what the stream announced
+        topology.update(&packet(3, |_| {}));
+        assert!(topology.children(2).is_empty());
+        topology.remove(1);
+        assert!(topology.parents(2).is_empty());
+    }
+}
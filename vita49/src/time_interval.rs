@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the CIF3 time interval fields
+(ANSI/VITA-49.2-2017 section 9.7): timestamp skew, rise time, fall time,
+offset time, pulse width, period, duration, dwell, and jitter.
+
+Each field is a 64-bit two's-complement count of femtoseconds (the radix
+point is to the right of bit 0).
+*/
+
+use core::fmt;
+use core::time::Duration;
+use deku::prelude::*;
+
+use crate::VitaError;
+
+const FS_PER_PS: i64 = 1_000;
+const FS_PER_NS: i128 = 1_000_000;
+
+/// Base time interval data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeInterval(i64);
+
+impl TimeInterval {
+    /// Create a new time interval from a count of femtoseconds.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::TimeInterval;
+    /// use std::time::Duration;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_pulse_width(Some(TimeInterval::try_from(Duration::from_micros(2))?));
+    /// context.set_rise_time(Some(TimeInterval::from_ps(12.5)?));
+    /// let pulse_width = context.pulse_width().unwrap();
+    /// assert_eq!(pulse_width.fs(), 2_000_000_000);
+    /// assert_eq!(pulse_width.to_duration(), Some(Duration::from_micros(2)));
+    /// assert_eq!(context.rise_time().unwrap().fs(), 12_500);
+    /// assert_eq!(TimeInterval::from_fs(-1).to_duration(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fs(fs: i64) -> TimeInterval {
+        TimeInterval(fs)
+    }
+
+    /// Create a new time interval from picoseconds (stored with
+    /// femtosecond resolution).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the value doesn't fit in the
+    /// 64-bit femtosecond field.
+    pub fn from_ps(ps: f64) -> Result<TimeInterval, VitaError> {
+        let fs = (ps * FS_PER_PS as f64).round();
+        if !(fs >= i64::MIN as f64 && fs < i64::MAX as f64) {
+            return Err(VitaError::OutOfRange);
+        }
+        Ok(TimeInterval(fs as i64))
+    }
+
+    /// Gets the size of the time interval structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the interval in femtoseconds.
+    pub fn fs(&self) -> i64 {
+        self.0
+    }
+
+    /// Gets the interval in picoseconds.
+    pub fn ps(&self) -> f64 {
+        self.0 as f64 / FS_PER_PS as f64
+    }
+
+    /// Gets the interval in seconds.
+    pub fn secs_f64(&self) -> f64 {
+        self.0 as f64 * 1e-15
+    }
+
+    /// Converts the interval to a [`Duration`], truncating to whole
+    /// nanoseconds. Returns `None` if the interval is negative.
+    pub fn to_duration(&self) -> Option<Duration> {
+        if self.0 < 0 {
+            return None;
+        }
+        Some(Duration::from_nanos((self.0 as i128 / FS_PER_NS) as u64))
+    }
+}
+
+impl TryFrom<Duration> for TimeInterval {
+    type Error = VitaError;
+
+    /// Converts a [`Duration`] to a time interval.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the duration is longer than
+    /// the field can hold (about 2.56 hours).
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let fs = value.as_nanos() as i128 * FS_PER_NS;
+        i64::try_from(fs)
+            .map(TimeInterval)
+            .map_err(|_| VitaError::OutOfRange)
+    }
+}
+
+impl fmt::Display for TimeInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{} ps", self.ps())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_limits() {
+        assert!(TimeInterval::try_from(Duration::from_secs(9_223)).is_ok());
+        assert!(matches!(
+            TimeInterval::try_from(Duration::from_secs(9_224)),
+            Err(VitaError::OutOfRange)
+        ));
+        assert_eq!(
+            TimeInterval::from_fs(1_999_999).to_duration(),
+            Some(Duration::from_nanos(1))
+        );
+        assert!(TimeInterval::from_ps(f64::MAX).is_err());
+    }
+}
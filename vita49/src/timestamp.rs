@@ -0,0 +1,174 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods for working with complete VRT
+timestamps (ANSI/VITA-49.2-2017 section 5.1.4).
+
+A VRT timestamp is made up of an optional integer-seconds part
+(interpreted according to the [`Tsi`] mode) and an optional
+fractional part (interpreted according to the [`Tsf`] mode).
+*/
+
+use crate::{Tsf, Tsi, VitaError};
+
+/// Number of picoseconds in one second.
+const PS_PER_SEC: i128 = 1_000_000_000_000;
+
+/// A complete VRT timestamp (integer + fractional parts along with
+/// their respective modes).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VitaTimestamp {
+    tsi: Tsi,
+    integer: Option<u32>,
+    tsf: Tsf,
+    fractional: Option<u64>,
+}
+
+impl Default for VitaTimestamp {
+    fn default() -> Self {
+        VitaTimestamp {
+            tsi: Tsi::Null,
+            integer: None,
+            tsf: Tsf::Null,
+            fractional: None,
+        }
+    }
+}
+
+impl VitaTimestamp {
+    /// Create a new timestamp.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::TimestampModeMismatch`] if a value is given
+    /// with a `Null` mode or a non-`Null` mode is given without a value.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let ts = VitaTimestamp::new(Tsi::Utc, Some(1_700_000_000), Tsf::RealTimePs, Some(5))?;
+    /// assert_eq!(ts.integer(), Some(1_700_000_000));
+    /// assert_eq!(ts.fractional(), Some(5));
+    /// assert!(VitaTimestamp::new(Tsi::Null, Some(1), Tsf::Null, None).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        tsi: Tsi,
+        integer: Option<u32>,
+        tsf: Tsf,
+        fractional: Option<u64>,
+    ) -> Result<VitaTimestamp, VitaError> {
+        if integer.is_some() == matches!(tsi, Tsi::Null)
+            || fractional.is_some() == matches!(tsf, Tsf::Null)
+        {
+            return Err(VitaError::TimestampModeMismatch);
+        }
+        Ok(VitaTimestamp {
+            tsi,
+            integer,
+            tsf,
+            fractional,
+        })
+    }
+
+    /// Gets the TimeStamp-Integer (TSI) mode.
+    pub fn tsi(&self) -> Tsi {
+        self.tsi
+    }
+    /// Gets the integer timestamp (seconds).
+    pub fn integer(&self) -> Option<u32> {
+        self.integer
+    }
+    /// Gets the TimeStamp-Fractional (TSF) mode.
+    pub fn tsf(&self) -> Tsf {
+        self.tsf
+    }
+    /// Gets the fractional timestamp.
+    pub fn fractional(&self) -> Option<u64> {
+        self.fractional
+    }
+
+    /// Returns a copy of this timestamp shifted by `adjustment_fs`
+    /// femtoseconds. The result is rounded to the nearest picosecond
+    /// and any overflow or underflow of the fractional part is carried
+    /// into the integer part.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::TimestampModeMismatch`] if the adjustment is
+    /// non-zero and the fractional part isn't real-time (picoseconds), and
+    /// [`VitaError::OutOfRange`] if the adjusted value can't be represented.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let ts = VitaTimestamp::new(Tsi::Gps, Some(100), Tsf::RealTimePs, Some(500_000))?;
+    /// // Move back by one microsecond, borrowing from the integer part
+    /// let adjusted = ts.adjusted_by_fs(-1_000_000_000)?;
+    /// assert_eq!(adjusted.integer(), Some(99));
+    /// assert_eq!(adjusted.fractional(), Some(999_999_500_000));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn adjusted_by_fs(&self, adjustment_fs: i64) -> Result<VitaTimestamp, VitaError> {
+        if adjustment_fs == 0 {
+            return Ok(*self);
+        }
+        let Some(fractional) = self.fractional.filter(|_| self.tsf == Tsf::RealTimePs) else {
+            return Err(VitaError::TimestampModeMismatch);
+        };
+        let adjustment_ps = (adjustment_fs as i128 + 500).div_euclid(1000);
+        let mut ret = *self;
+        match self.integer {
+            Some(integer) => {
+                let total = integer as i128 * PS_PER_SEC + fractional as i128 + adjustment_ps;
+                let integer = u32::try_from(total.div_euclid(PS_PER_SEC))
+                    .map_err(|_| VitaError::OutOfRange)?;
+                ret.integer = Some(integer);
+                ret.fractional = Some(total.rem_euclid(PS_PER_SEC) as u64);
+            }
+            None => {
+                let total = fractional as i128 + adjustment_ps;
+                ret.fractional = Some(u64::try_from(total).map_err(|_| VitaError::OutOfRange)?);
+            }
+        }
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn adjust_carries_into_integer() {
+        let ts =
+            VitaTimestamp::new(Tsi::Utc, Some(10), Tsf::RealTimePs, Some(999_999_999_999)).unwrap();
+        let adjusted = ts.adjusted_by_fs(2_000).unwrap();
+        assert_eq!(adjusted.integer(), Some(11));
+        assert_eq!(adjusted.fractional(), Some(1));
+        // Rounds to the nearest picosecond
+        assert_eq!(ts.adjusted_by_fs(-499).unwrap(), ts);
+        assert_eq!(
+            ts.adjusted_by_fs(-501).unwrap().fractional(),
+            Some(999_999_999_998)
+        );
+    }
+
+    #[test]
+    fn adjust_rejects_bad_modes_and_ranges() {
+        let ts = VitaTimestamp::new(Tsi::Utc, Some(0), Tsf::SampleCount, Some(10)).unwrap();
+        assert!(matches!(
+            ts.adjusted_by_fs(1_000),
+            Err(VitaError::TimestampModeMismatch)
+        ));
+        let ts = VitaTimestamp::new(Tsi::Utc, Some(0), Tsf::RealTimePs, Some(0)).unwrap();
+        assert!(matches!(
+            ts.adjusted_by_fs(-1_000),
+            Err(VitaError::OutOfRange)
+        ));
+    }
+}
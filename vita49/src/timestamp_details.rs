@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the timestamp details field
+(ANSI/VITA-49.2-2017 section 9.7.1).
+
+The field describes how the timestamps of the associated stream were
+produced. The first word carries:
+
+| Bits  | Subfield                                  |
+|-------|-------------------------------------------|
+| 31-24 | User-defined                              |
+| 20    | Global (1) or local (0) time              |
+| 19-18 | Epoch (see [`TimestampEpoch`])            |
+| 17    | Timestamps are aligned to a PPS signal    |
+| 16    | Leap seconds subfield is valid            |
+| 7-0   | Leap seconds (GPS-UTC offset)             |
+
+The second word is reserved. Unused bits are preserved as-is.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+const USER_DEFINED_SHIFT: u32 = 56;
+const GLOBAL_BIT: u64 = 1 << 52;
+const EPOCH_SHIFT: u32 = 50;
+const PPS_BIT: u64 = 1 << 49;
+const LEAP_SECONDS_VALID_BIT: u64 = 1 << 48;
+const LEAP_SECONDS_SHIFT: u32 = 32;
+
+/// Epoch the timestamps are counted from.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TimestampEpoch {
+    /// UTC epoch (1970-01-01).
+    Utc,
+    /// GPS epoch (1980-01-06).
+    Gps,
+    /// Any other (reserved) epoch code.
+    Other(u8),
+}
+
+impl From<u8> for TimestampEpoch {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => TimestampEpoch::Utc,
+            1 => TimestampEpoch::Gps,
+            v => TimestampEpoch::Other(v),
+        }
+    }
+}
+
+impl From<TimestampEpoch> for u8 {
+    fn from(value: TimestampEpoch) -> Self {
+        match value {
+            TimestampEpoch::Utc => 0,
+            TimestampEpoch::Gps => 1,
+            TimestampEpoch::Other(v) => v,
+        }
+    }
+}
+
+/// Base timestamp details data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampDetails(u64);
+
+impl TimestampDetails {
+    /// Create a new `TimestampDetails` with every subfield cleared (local
+    /// time, UTC epoch, no PPS alignment, no leap seconds).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::{TimestampDetails, TimestampEpoch};
+    /// let mut details = TimestampDetails::new();
+    /// details.set_global(true);
+    /// details.set_epoch(TimestampEpoch::Gps);
+    /// details.set_pps(true);
+    /// details.set_leap_seconds(Some(18));
+    ///
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_timestamp_details(Some(details));
+    /// let details = context.timestamp_details().unwrap();
+    /// assert!(details.global());
+    /// assert_eq!(details.epoch(), TimestampEpoch::Gps);
+    /// assert!(details.pps());
+    /// assert_eq!(details.leap_seconds(), Some(18));
+    /// assert_eq!(details.user_defined(), 0);
+    /// ```
+    pub fn new() -> TimestampDetails {
+        TimestampDetails::default()
+    }
+
+    /// Gets the size of the timestamp details structure in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the raw 64-bit value of the field.
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Gets the user-defined bits.
+    pub fn user_defined(&self) -> u8 {
+        (self.0 >> USER_DEFINED_SHIFT) as u8
+    }
+
+    /// Sets the user-defined bits.
+    pub fn set_user_defined(&mut self, bits: u8) {
+        self.0 = self.0 & !(0xFF << USER_DEFINED_SHIFT) | ((bits as u64) << USER_DEFINED_SHIFT);
+    }
+
+    /// Returns true if the timestamps are referenced to global time,
+    /// false if they're referenced to a local clock.
+    pub fn global(&self) -> bool {
+        self.0 & GLOBAL_BIT != 0
+    }
+
+    /// Sets whether the timestamps are referenced to global time.
+    pub fn set_global(&mut self, global: bool) {
+        self.set_bit(GLOBAL_BIT, global);
+    }
+
+    /// Gets the epoch the timestamps are counted from.
+    pub fn epoch(&self) -> TimestampEpoch {
+        TimestampEpoch::from(((self.0 >> EPOCH_SHIFT) & 0b11) as u8)
+    }
+
+    /// Sets the epoch the timestamps are counted from.
+    ///
+    /// Note: only the least significant 2 bits of an
+    /// [`TimestampEpoch::Other`] code are used.
+    pub fn set_epoch(&mut self, epoch: TimestampEpoch) {
+        let code = (u8::from(epoch) & 0b11) as u64;
+        self.0 = self.0 & !(0b11 << EPOCH_SHIFT) | (code << EPOCH_SHIFT);
+    }
+
+    /// Returns true if the timestamps are aligned to a pulse-per-second
+    /// signal.
+    pub fn pps(&self) -> bool {
+        self.0 & PPS_BIT != 0
+    }
+
+    /// Sets whether the timestamps are aligned to a pulse-per-second
+    /// signal.
+    pub fn set_pps(&mut self, pps: bool) {
+        self.set_bit(PPS_BIT, pps);
+    }
+
+    /// Gets the leap seconds (GPS-UTC offset). Returns `None` if the
+    /// subfield isn't marked valid.
+    pub fn leap_seconds(&self) -> Option<u8> {
+        if self.0 & LEAP_SECONDS_VALID_BIT == 0 {
+            return None;
+        }
+        Some((self.0 >> LEAP_SECONDS_SHIFT) as u8)
+    }
+
+    /// Sets the leap seconds (GPS-UTC offset). If `None` is passed, the
+    /// subfield is marked invalid.
+    pub fn set_leap_seconds(&mut self, leap_seconds: Option<u8>) {
+        self.0 &= !(0xFF << LEAP_SECONDS_SHIFT);
+        self.set_bit(LEAP_SECONDS_VALID_BIT, leap_seconds.is_some());
+        if let Some(secs) = leap_seconds {
+            self.0 |= (secs as u64) << LEAP_SECONDS_SHIFT;
+        }
+    }
+
+    fn set_bit(&mut self, bit: u64, value: bool) {
+        if value {
+            self.0 |= bit;
+        } else {
+            self.0 &= !bit;
+        }
+    }
+}
+
+impl fmt::Display for TimestampDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{}, Epoch: {:?}, PPS: {}",
+            if self.global() { "Global" } else { "Local" },
+            self.epoch(),
+            self.pps()
+        )?;
+        if let Some(secs) = self.leap_seconds() {
+            write!(f, ", Leap seconds: {secs}")?;
+        }
+        write!(f, ", User-defined: {:#04x}", self.user_defined())
+    }
+}
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+/*!
+Data structures and methods related to the version and build code
+field (ANSI/VITA-49.2-2017 section 9.10.4).
+
+The field packs the firmware/software build date (year and day of
+year), a revision number, and a user-defined value into one word.
+*/
+
+use core::fmt;
+use deku::prelude::*;
+
+use crate::VitaError;
+
+/// Base version and build code data structure.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite,
+)]
+#[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VersionBuildCode(u32);
+
+impl VersionBuildCode {
+    /// Create a new `VersionBuildCode` object.
+    ///
+    /// # Errors
+    /// Returns [`VitaError::OutOfRange`] if the year isn't within 2000 to
+    /// 2127, the day isn't within 1 to 366, the revision is over 63, or the
+    /// user-defined value is over 1023.
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// use vita49::VersionBuildCode;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_context_packet();
+    /// let context = packet.payload_mut().context_mut().unwrap();
+    /// context.set_version_and_build_code(Some(VersionBuildCode::new(2025, 190, 3, 42)?));
+    /// let code = context.version_and_build_code().unwrap();
+    /// assert_eq!(code.year(), 2025);
+    /// assert_eq!(code.day(), 190);
+    /// assert_eq!(code.revision(), 3);
+    /// assert_eq!(code.user_defined(), 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        year: u16,
+        day: u16,
+        revision: u8,
+        user_defined: u16,
+    ) -> Result<VersionBuildCode, VitaError> {
+        if !(2000..=2127).contains(&year)
+            || !(1..=366).contains(&day)
+            || revision > 0x3F
+            || user_defined > 0x3FF
+        {
+            return Err(VitaError::OutOfRange);
+        }
+        Ok(VersionBuildCode(
+            ((year as u32 - 2000) << 25)
+                | ((day as u32) << 16)
+                | ((revision as u32) << 10)
+                | user_defined as u32,
+        ))
+    }
+
+    /// Gets the size of the version and build code in 32-bit words.
+    pub fn size_words(&self) -> u16 {
+        (std::mem::size_of_val(&self.0) / std::mem::size_of::<u32>()) as u16
+    }
+
+    /// Gets the raw 32-bit value of the field.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the build year.
+    pub fn year(&self) -> u16 {
+        2000 + (self.0 >> 25) as u16
+    }
+
+    /// Gets the build day of the year (1-366).
+    pub fn day(&self) -> u16 {
+        ((self.0 >> 16) & 0x1FF) as u16
+    }
+
+    /// Gets the revision number.
+    pub fn revision(&self) -> u8 {
+        ((self.0 >> 10) & 0x3F) as u8
+    }
+
+    /// Gets the user-defined value.
+    pub fn user_defined(&self) -> u16 {
+        (self.0 & 0x3FF) as u16
+    }
+}
+
+impl fmt::Display for VersionBuildCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{}-{:03} rev {} (user: {})",
+            self.year(),
+            self.day(),
+            self.revision(),
+            self.user_defined()
+        )
+    }
+}
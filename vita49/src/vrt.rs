@@ -396,6 +396,45 @@ impl Vrt {
         Ok(())
     }
 
+    /// Gets the complete packet timestamp (integer and fractional parts).
+    ///
+    /// # Errors
+    /// Returns [`VitaError::TimestampModeMismatch`] if the header's
+    /// TSI/TSF modes don't match the timestamp fields (e.g. after
+    /// changing the modes through [`header_mut()`](Vrt::header_mut())).
+    ///
+    /// # Example
+    /// ```
+    /// use vita49::prelude::*;
+    /// # fn main() -> Result<(), VitaError> {
+    /// let mut packet = Vrt::new_signal_data_packet();
+    /// packet.set_integer_timestamp(Some(12345), Tsi::Utc)?;
+    /// let ts = packet.timestamp()?;
+    /// assert_eq!(ts.tsi(), Tsi::Utc);
+    /// assert_eq!(ts.integer(), Some(12345));
+    /// assert_eq!(ts.tsf(), Tsf::Null);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timestamp(&self) -> Result<VitaTimestamp, VitaError> {
+        VitaTimestamp::new(
+            self.header.tsi(),
+            self.integer_timestamp,
+            self.header.tsf(),
+            self.fractional_timestamp,
+        )
+    }
+
+    /// Sets the complete packet timestamp (integer and fractional parts).
+    ///
+    /// [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method.
+    pub fn set_timestamp(&mut self, timestamp: VitaTimestamp) {
+        self.integer_timestamp = timestamp.integer();
+        self.header.set_tsi(timestamp.tsi());
+        self.fractional_timestamp = timestamp.fractional();
+        self.header.set_tsf(timestamp.tsf());
+    }
+
     /// Gets a reference to the payload enumeration.
     pub fn payload(&self) -> &Payload {
         &self.payload
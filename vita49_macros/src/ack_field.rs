@@ -94,7 +94,7 @@ pub fn ack_field(input: TokenStream) -> TokenStream {
                             if clear_wif {
                                 *self.wif0_mut() = None;
                             }
-                            if clear_wif_fields {
+                            if clear_wif && clear_wif_fields {
                                 *self.wif0_fields_mut() = None;
                             }
                         }
@@ -127,7 +127,7 @@ pub fn ack_field(input: TokenStream) -> TokenStream {
                             if clear_wif {
                                 *self.eif0_mut() = None;
                             }
-                            if clear_wif_fields {
+                            if clear_wif && clear_wif_fields {
                                 *self.eif0_fields_mut() = None;
                             }
                         }
@@ -175,10 +175,11 @@ pub fn ack_field(input: TokenStream) -> TokenStream {
                                 *self.#wif_mut() = Some(#cif::default());
                                 if self.wif0().is_none() {
                                     *self.wif0_mut() = Some(Cif0::default());
-                                    self.wif0_mut().unwrap().#set_cif_enabled_fn();
+                                    *self.wif0_fields_mut() = Some(Cif0AckFields::default());
                                 }
+                                self.wif0_mut().as_mut().unwrap().#set_cif_enabled_fn();
                             }
-                            self.#wif_mut().unwrap().#set_ack_field_fn();
+                            self.#wif_mut().as_mut().unwrap().#set_ack_field_fn();
 
                             if self.#wif_fields().is_none() {
                                 *self.#wif_fields_mut() = Some(#cif_ack_fields::default());
@@ -200,6 +201,10 @@ pub fn ack_field(input: TokenStream) -> TokenStream {
                                 *self.#wif_fields_mut() = None;
                                 if let Some(w) = self.wif0_mut() {
                                     w.#unset_cif_enabled_fn();
+                                    if w.empty() {
+                                        *self.wif0_mut() = None;
+                                        *self.wif0_fields_mut() = None;
+                                    }
                                 }
                             }
                         }
@@ -210,10 +215,11 @@ pub fn ack_field(input: TokenStream) -> TokenStream {
                                 *self.#eif_mut() = Some(#cif::default());
                                 if self.eif0().is_none() {
                                     *self.eif0_mut() = Some(Cif0::default());
-                                    self.eif0_mut().unwrap().#set_cif_enabled_fn();
+                                    *self.eif0_fields_mut() = Some(Cif0AckFields::default());
                                 }
+                                self.eif0_mut().as_mut().unwrap().#set_cif_enabled_fn();
                             }
-                            self.#eif_mut().unwrap().#set_ack_field_fn();
+                            self.#eif_mut().as_mut().unwrap().#set_ack_field_fn();
 
                             if self.#eif_fields().is_none() {
                                 *self.#eif_fields_mut() = Some(#cif_ack_fields::default());
@@ -235,6 +241,10 @@ pub fn ack_field(input: TokenStream) -> TokenStream {
                                 *self.#eif_fields_mut() = None;
                                 if let Some(w) = self.eif0_mut() {
                                     w.#unset_cif_enabled_fn();
+                                    if w.empty() {
+                                        *self.eif0_mut() = None;
+                                        *self.eif0_fields_mut() = None;
+                                    }
                                 }
                             }
                         }
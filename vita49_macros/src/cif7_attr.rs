@@ -0,0 +1,236 @@
+// SPDX-FileCopyrightText: 2025 The vita49-rs Authors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Type};
+
+/// How a field's values are converted between their stored and
+/// user-facing forms.
+pub enum AttrConversion {
+    /// The stored type is the user-facing type. Getters return references
+    /// and every attribute (including probability and belief) is
+    /// exposed as-is.
+    Basic,
+    /// The stored type is a raw integer converted to the user-facing
+    /// type. `to_friendly` maps a `&stored` named `v` to the user-facing
+    /// value and `from_friendly` maps a user-facing `v` back. Probability
+    /// and belief get their own accessors.
+    Numeric {
+        to_friendly: TokenStream,
+        from_friendly: TokenStream,
+    },
+}
+
+/// Generates the code run after a field's main value is set, giving the
+/// field a default-valued entry for every enabled CIF7 attribute it's
+/// missing. `fields` evaluates to a mutable reference to the CIF fields
+/// structure.
+pub fn pad_attributes(fields: TokenStream, cif_attr_field: &Ident) -> TokenStream {
+    quote! {
+        #[cfg(feature = "cif7")]
+        {
+            let num_attrs = crate::cif7::Cif7Opts::from(self.cif7()).num_extra_attrs;
+            let fields = #fields;
+            if fields.#cif_attr_field.len() < num_attrs {
+                fields.#cif_attr_field.resize(num_attrs, Default::default());
+            }
+        }
+    }
+}
+
+/// Generates the typed CIF7 attribute accessors for a CIF field
+/// (e.g. `bandwidth_hz_attr()`/`set_bandwidth_hz_attr()`).
+pub fn cif7_attr_accessors(
+    cif: &Ident,
+    cif_field: &Ident,
+    cif_field_w_unit: &Ident,
+    friendly_type: &Type,
+    conversion: AttrConversion,
+    check: TokenStream,
+) -> TokenStream {
+    let cif_mut = format_ident!("{}_mut", cif);
+    let cif_fields = format_ident!("{}_fields", cif);
+    let cif_fields_mut = format_ident!("{}_fields_mut", cif);
+    let cif_attr_field = format_ident!("{}_attributes", cif_field);
+    let set_cif_field_fn = format_ident!("set_{}", cif_field);
+    let enable_cif_fn = format_ident!("set_{}_enabled", cif);
+    let get_fn = format_ident!("{}", cif_field_w_unit);
+
+    let cif_name_str = cif.to_string();
+    let mut cif_name_chars = cif_name_str.chars();
+    let cif_type_name = match cif_name_chars.next() {
+        None => String::new(),
+        Some(f) => f.to_uppercase().collect::<String>() + cif_name_chars.as_str(),
+    };
+    let cif_type_name = format_ident!("{}", cif_type_name);
+    let cif_fields_type_name = format_ident!("{}Fields", cif_type_name);
+
+    // CIF0 is always present; other CIFs are optional
+    let (fields_ref, ensure_present) = if cif == "cif0" {
+        (
+            quote! { self.#cif_fields() },
+            quote! {
+                self.#cif_mut().#set_cif_field_fn();
+                let fields = self.#cif_fields_mut();
+            },
+        )
+    } else {
+        (
+            quote! { self.#cif_fields()? },
+            quote! {
+                if self.#cif().is_none() {
+                    self.cif0_mut().#enable_cif_fn();
+                    *self.#cif_mut() = Some(#cif_type_name::default())
+                }
+                self.#cif_mut().as_mut().unwrap().#set_cif_field_fn();
+                if self.#cif_fields().is_none() {
+                    *self.#cif_fields_mut() = Some(#cif_fields_type_name::default());
+                }
+                let fields = self.#cif_fields_mut().as_mut().unwrap();
+            },
+        )
+    };
+
+    // Enables `attr`, makes sure the field holds a value for every enabled
+    // attribute, then stores `raw` in the slot for `attr`.
+    let store = |attr: TokenStream, raw: TokenStream| {
+        quote! {
+            self.set_cif7_attr(#attr, true)?;
+            let opts = crate::cif7::Cif7Opts::from(self.cif7());
+            let index = self.cif7().and_then(|c| c.attr_index(#attr));
+            #ensure_present
+            if fields.#cif_attr_field.len() < opts.num_extra_attrs {
+                fields.#cif_attr_field.resize(opts.num_extra_attrs, Default::default());
+            }
+            if opts.current_val && fields.#cif_field.is_none() {
+                fields.#cif_field = Some(Default::default());
+            }
+            match index {
+                Some(i) => fields.#cif_attr_field[i] = #raw,
+                None => fields.#cif_field = Some(#raw),
+            }
+            Ok(())
+        }
+    };
+
+    let get_attr_fn = format_ident!("{}_attr", cif_field_w_unit);
+    let set_attr_fn = format_ident!("set_{}_attr", cif_field_w_unit);
+    let get_attr_doc = format!(
+        "Get the {cif_field_w_unit} value for the given CIF7 attribute. If `None` is \
+        returned, the field or attribute is unset."
+    );
+    let mut set_attr_doc = format!(
+        "Set the {cif_field_w_unit} value for the given CIF7 attribute, enabling the \
+        attribute (and the field) if needed. Other fields gain a default-valued entry \
+        for a newly enabled attribute.\n\n\
+        [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method.\n\n\
+        # Errors\n\
+        Returns `VitaError::UnsupportedCif7Attr` if the packet can't carry the attribute"
+    );
+
+    match conversion {
+        AttrConversion::Basic => {
+            set_attr_doc.push('.');
+            let store_value = store(quote! { attr }, quote! { v });
+            quote! {
+                #[doc = #get_attr_doc]
+                #[cfg(feature = "cif7")]
+                fn #get_attr_fn(&self, attr: crate::Cif7Attr) -> Option<&#friendly_type> {
+                    if attr == crate::Cif7Attr::Current {
+                        return self.#get_fn();
+                    }
+                    let index = self.cif7()?.attr_index(attr)?;
+                    #fields_ref.#cif_attr_field.get(index)
+                }
+                #[doc = #set_attr_doc]
+                #[cfg(feature = "cif7")]
+                fn #set_attr_fn(&mut self, attr: crate::Cif7Attr, v: #friendly_type) -> Result<(), crate::VitaError> {
+                    #check
+                    #store_value
+                }
+            }
+        }
+        AttrConversion::Numeric {
+            to_friendly,
+            from_friendly,
+        } => {
+            set_attr_doc.push_str(
+                " or it's probability or belief (which have their own accessors). \
+                Out-of-range values are rejected in the same way as the main setter.",
+            );
+            let get_attr_doc = format!(
+                "{get_attr_doc} Always returns `None` for probability and belief (which \
+                have their own accessors)."
+            );
+            let store_value = store(quote! { attr }, from_friendly);
+
+            let special = [
+                ("probability", quote! { Probability }, quote! { Cif7Probability }),
+                ("belief", quote! { Belief }, quote! { Cif7Belief }),
+            ]
+            .into_iter()
+            .map(|(name, attr, ty)| {
+                let get_fn = format_ident!("{}_{}", cif_field_w_unit, name);
+                let set_fn = format_ident!("set_{}_{}", cif_field_w_unit, name);
+                let get_doc = format!(
+                    "Get the {cif_field_w_unit} {name} (CIF7 attribute). If `None` is returned, \
+                    the field or attribute is unset."
+                );
+                let set_doc = format!(
+                    "Set the {cif_field_w_unit} {name} (CIF7 attribute), enabling the attribute \
+                    (and the field) if needed.\n\n\
+                    [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method.\n\n\
+                    # Errors\n\
+                    Returns `VitaError::UnsupportedCif7Attr` if the packet can't carry the attribute."
+                );
+                let store_value = store(
+                    quote! { crate::Cif7Attr::#attr },
+                    quote! { v.as_u32() as _ },
+                );
+                quote! {
+                    #[doc = #get_doc]
+                    #[cfg(feature = "cif7")]
+                    fn #get_fn(&self) -> Option<crate::#ty> {
+                        let index = self.cif7()?.attr_index(crate::Cif7Attr::#attr)?;
+                        #fields_ref
+                            .#cif_attr_field
+                            .get(index)
+                            .map(|v| crate::#ty::from_u32(*v as u32))
+                    }
+                    #[doc = #set_doc]
+                    #[cfg(feature = "cif7")]
+                    fn #set_fn(&mut self, v: crate::#ty) -> Result<(), crate::VitaError> {
+                        #store_value
+                    }
+                }
+            });
+
+            quote! {
+                #[doc = #get_attr_doc]
+                #[cfg(feature = "cif7")]
+                fn #get_attr_fn(&self, attr: crate::Cif7Attr) -> Option<#friendly_type> {
+                    if attr == crate::Cif7Attr::Current {
+                        return self.#get_fn();
+                    }
+                    if !attr.is_numeric() {
+                        return None;
+                    }
+                    let index = self.cif7()?.attr_index(attr)?;
+                    #fields_ref.#cif_attr_field.get(index).map(#to_friendly)
+                }
+                #[doc = #set_attr_doc]
+                #[cfg(feature = "cif7")]
+                fn #set_attr_fn(&mut self, attr: crate::Cif7Attr, v: #friendly_type) -> Result<(), crate::VitaError> {
+                    if !attr.is_numeric() {
+                        return Err(crate::VitaError::UnsupportedCif7Attr);
+                    }
+                    #check
+                    #store_value
+                }
+                #(#special)*
+            }
+        }
+    }
+}
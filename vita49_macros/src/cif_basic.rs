@@ -2,6 +2,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::cif7_attr::{cif7_attr_accessors, pad_attributes, AttrConversion};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
@@ -90,7 +91,22 @@ pub fn cif_basic(input: TokenStream) -> TokenStream {
         [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method."
     );
 
-    if cif == "cif0" {
+    let cif7_accessors = cif7_attr_accessors(
+        &cif,
+        &cif_field,
+        &cif_field_w_unit,
+        &friendly_type,
+        AttrConversion::Basic,
+        quote! {},
+    );
+
+    let pad_cif0_attributes = pad_attributes(quote! { self.#cif_fields_mut() }, &cif_attr_field);
+    let pad_attributes = pad_attributes(
+        quote! { self.#cif_fields_mut().as_mut().unwrap() },
+        &cif_attr_field,
+    );
+
+    let accessors = if cif == "cif0" {
         quote! {
             #[doc = #get_fn_doc]
             fn #get_fn(&self) -> Option<&#friendly_type> {
@@ -106,8 +122,11 @@ pub fn cif_basic(input: TokenStream) -> TokenStream {
                 self.#cif_fields_mut().#cif_field = #cif_field_w_unit;
                 if self.#cif_fields().#cif_field.is_some() {
                     self.#cif_mut().#set_cif_field_fn();
+                    #pad_cif0_attributes
                 } else {
                     self.#cif_mut().#unset_cif_field_fn();
+                    #[cfg(feature = "cif7")]
+                    self.#cif_fields_mut().#cif_attr_field.clear();
                 }
             }
             #[doc = #set_attr_fn_doc]
@@ -153,7 +172,8 @@ pub fn cif_basic(input: TokenStream) -> TokenStream {
                     if self.#cif_fields().is_none() {
                         *self.#cif_fields_mut() = Some(#cif_fields_type_name::default());
                     }
-                    self.#cif_fields_mut().as_mut().unwrap().#cif_field = #cif_field_w_unit;
+                    self.#cif_fields_mut().as_mut().unwrap().#cif_field = Some(v);
+                    #pad_attributes
                 } else {
                     let mut clear_cif = false;
                     let mut clear_fields = false;
@@ -198,5 +218,10 @@ pub fn cif_basic(input: TokenStream) -> TokenStream {
                 }
             }
         }
+    };
+
+    quote! {
+        #accessors
+        #cif7_accessors
     }
 }
@@ -18,6 +18,9 @@ pub fn cif_fields(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut expanded_fields = Vec::new();
     let mut expanded_size_lines = Vec::new();
     let mut empty_check_lines = Vec::new();
+    let mut insert_slot_lines = Vec::new();
+    let mut remove_slot_lines = Vec::new();
+    let mut current_lines = Vec::new();
 
     for field in input.fields {
         let cif_field = field.clone().ident.unwrap();
@@ -90,6 +93,25 @@ pub fn cif_fields(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
         };
         empty_check_lines.push(expanded);
+
+        insert_slot_lines.push(quote! {
+            if self.#cif_field.is_some() || !self.#attr_field.is_empty() {
+                let index = index.min(self.#attr_field.len());
+                self.#attr_field.insert(index, Default::default());
+            }
+        });
+        remove_slot_lines.push(quote! {
+            if index < self.#attr_field.len() {
+                self.#attr_field.remove(index);
+            }
+        });
+        current_lines.push(quote! {
+            if !enabled {
+                self.#cif_field = None;
+            } else if self.#cif_field.is_none() && !self.#attr_field.is_empty() {
+                self.#cif_field = Some(Default::default());
+            }
+        });
     }
 
     let cif_name_str = cif_name.to_string();
@@ -108,6 +130,26 @@ pub fn cif_fields(attr: TokenStream, item: TokenStream) -> TokenStream {
     let size_doc = format!("Gets the size of all {cif_name} data fields in 32-bit words");
     let empty_doc = format!("Returns true if all {cif_name} data fields are empty, false if not");
 
+    // Attribute alignment only applies to data fields (not ACK fields)
+    let attribute_slots = if format!("{struct_name}").contains("Ack") {
+        quote! {}
+    } else {
+        quote! {
+            #[cfg(feature = "cif7")]
+            impl crate::cif7::AttributeSlots for #struct_name {
+                fn insert_attribute_slot(&mut self, index: usize) {
+                    #(#insert_slot_lines)*
+                }
+                fn remove_attribute_slot(&mut self, index: usize) {
+                    #(#remove_slot_lines)*
+                }
+                fn set_current_values(&mut self, enabled: bool) {
+                    #(#current_lines)*
+                }
+            }
+        }
+    };
+
     let expanded = quote! {
         #[doc = #struct_doc]
         #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DekuRead, DekuWrite)]
@@ -134,6 +176,8 @@ pub fn cif_fields(attr: TokenStream, item: TokenStream) -> TokenStream {
                 true
             }
         }
+
+        #attribute_slots
     };
 
     TokenStream::from(expanded)
@@ -2,6 +2,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::cif7_attr::{cif7_attr_accessors, pad_attributes, AttrConversion};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
@@ -97,7 +98,25 @@ pub fn cif_radix(input: TokenStream) -> TokenStream {
         [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method."
     );
 
-    if cif == "cif0" {
+    let cif7_accessors = cif7_attr_accessors(
+        &cif,
+        &cif_field,
+        &cif_field_w_unit,
+        &friendly_type,
+        AttrConversion::Numeric {
+            to_friendly: quote! { |v| #fixed_type::from_bits(*v).to_num() },
+            from_friendly: quote! { #fixed_type::from_num(v).to_bits() },
+        },
+        quote! {},
+    );
+
+    let pad_cif0_attributes = pad_attributes(quote! { self.#cif_fields_mut() }, &cif_attr_field);
+    let pad_attributes = pad_attributes(
+        quote! { self.#cif_fields_mut().as_mut().unwrap() },
+        &cif_attr_field,
+    );
+
+    let accessors = if cif == "cif0" {
         quote! {
             #[doc = #get_fn_doc]
             fn #get_fn(&self) -> Option<#friendly_type> {
@@ -117,9 +136,12 @@ pub fn cif_radix(input: TokenStream) -> TokenStream {
                 if let Some(v) = #cif_field_w_unit {
                     self.#cif_fields_mut().#cif_field = Some(#fixed_type::from_num(v).to_bits());
                     self.#cif_mut().#set_cif_field_fn();
+                    #pad_cif0_attributes
                 } else {
                     self.#cif_fields_mut().#cif_field = None;
                     self.#cif_mut().#unset_cif_field_fn();
+                    #[cfg(feature = "cif7")]
+                    self.#cif_fields_mut().#cif_attr_field.clear();
                 }
             }
             #[doc = #set_attr_fn_doc]
@@ -164,7 +186,6 @@ pub fn cif_radix(input: TokenStream) -> TokenStream {
             #[doc = #set_fn_doc]
             fn #set_fn(&mut self, #cif_field_w_unit: Option<#friendly_type>) {
                 if let Some(v) = #cif_field_w_unit {
-                    self.cif0_mut().#set_cif7_field_fn();
                     if self.#cif().is_none() {
                         self.cif0_mut().#enable_cif_fn();
                         *self.#cif_mut() = Some(#cif_type_name::default())
@@ -177,6 +198,7 @@ pub fn cif_radix(input: TokenStream) -> TokenStream {
                     self.#cif_fields_mut().as_mut().unwrap().#cif_field = Some(
                         #fixed_type::from_num(v).to_bits()
                     );
+                    #pad_attributes
 
                 } else {
                     let mut clear_cif = false;
@@ -204,6 +226,7 @@ pub fn cif_radix(input: TokenStream) -> TokenStream {
             #[cfg(feature = "cif7")]
             fn #set_attr_fn(&mut self, #cif_attr_field_w_unit: Option<Vec<#friendly_type>>) {
                 if let Some(vec) = #cif_attr_field_w_unit {
+                    self.cif0_mut().#set_cif7_field_fn();
                     if self.#cif().is_none() {
                         self.cif0_mut().#enable_cif_fn();
                         *self.#cif_mut() = Some(#cif_type_name::default())
@@ -224,5 +247,10 @@ pub fn cif_radix(input: TokenStream) -> TokenStream {
                 }
             }
         }
+    };
+
+    quote! {
+        #accessors
+        #cif7_accessors
     }
 }
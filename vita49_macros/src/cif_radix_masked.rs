@@ -2,10 +2,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::cif7_attr::{cif7_attr_accessors, pad_attributes, AttrConversion};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
-use syn::{parse2, Ident, Token, Type};
+use syn::{parse2, Expr, Ident, Token, Type};
 
 struct CifRadixMaskedArgs {
     cif_name: Ident,
@@ -21,6 +22,7 @@ struct CifRadixMaskedArgs {
     base_type: Type,
     _comma5: Token![,],
     masked_base_type: Type,
+    range: Option<Expr>,
 }
 
 impl Parse for CifRadixMaskedArgs {
@@ -38,6 +40,13 @@ impl Parse for CifRadixMaskedArgs {
         let base_type = input.parse()?;
         let _comma5: Token![,] = input.parse()?;
         let masked_base_type = input.parse()?;
+        // Optional valid range for the friendly value. When given, the
+        // setters are range-checked and return a `Result`.
+        let range = if input.parse::<Option<Token![,]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
         Ok(CifRadixMaskedArgs {
             cif_name,
             _comma0,
@@ -52,6 +61,7 @@ impl Parse for CifRadixMaskedArgs {
             base_type,
             _comma5,
             masked_base_type,
+            range,
         })
     }
 }
@@ -65,6 +75,7 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
         fixed_type,
         base_type,
         masked_base_type,
+        range,
         ..
     } = parse2(input).expect("failed to parse macro input");
 
@@ -99,14 +110,14 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
 
     let get_fn_doc =
         format!("Get the current {cif_field_w_unit}. If `None` is returned, the field is unset.");
-    let set_fn_doc = format!(
+    let mut set_fn_doc = format!(
         "Set the {cif_field_w_unit}. If `None` is passed, the field will be unset.\n\n\
         [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method."
     );
     let get_attr_fn_doc = format!(
         "Get the current {cif_attr_field_w_unit} (CIF7 attributes). If `None` is returned, the field is unset."
     );
-    let set_attr_fn_doc = format!(
+    let mut set_attr_fn_doc = format!(
         "Set the {cif_attr_field_w_unit} (CIF7 attributes). If `None` is passed, the field will be unset.\n\n\
         [`update_packet_size()`](Vrt::update_packet_size()) should be executed after running this method."
     );
@@ -121,7 +132,57 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
         .expect("failed to parse masked type");
     let mask: usize = (1 << masked_size) - 1;
 
-    if cif == "cif0" {
+    // Range checking (only when a range is given)
+    if let Some(range) = &range {
+        let errors_doc = format!(
+            "\n\n# Errors\nReturns `VitaError::OutOfRange` if any value is outside of `{}`.",
+            range.to_token_stream()
+        );
+        set_fn_doc.push_str(&errors_doc);
+        set_attr_fn_doc.push_str(&errors_doc);
+    }
+    let (set_ret, set_ok, check_v, check_vec) = match &range {
+        Some(range) => (
+            quote! { -> Result<(), crate::VitaError> },
+            quote! { Ok(()) },
+            quote! {
+                if !(#range).contains(&v) {
+                    return Err(crate::VitaError::OutOfRange);
+                }
+            },
+            quote! {
+                if vec.iter().any(|v| !(#range).contains(v)) {
+                    return Err(crate::VitaError::OutOfRange);
+                }
+            },
+        ),
+        None => (quote! {}, quote! {}, quote! {}, quote! {}),
+    };
+
+    let cif7_accessors = cif7_attr_accessors(
+        &cif,
+        &cif_field,
+        &cif_field_w_unit,
+        &friendly_type,
+        AttrConversion::Numeric {
+            to_friendly: quote! {
+                |v| {
+                    let v_masked = (v & (#mask as #base_type)) as #masked_base_type;
+                    #fixed_type::from_bits(v_masked).to_num()
+                }
+            },
+            from_friendly: quote! { #fixed_type::from_num(v).to_bits() as #base_type },
+        },
+        check_v.clone(),
+    );
+
+    let pad_cif0_attributes = pad_attributes(quote! { self.#cif_fields_mut() }, &cif_attr_field);
+    let pad_attributes = pad_attributes(
+        quote! { self.#cif_fields_mut().as_mut().unwrap() },
+        &cif_attr_field,
+    );
+
+    let accessors = if cif == "cif0" {
         quote! {
             #[doc = #get_fn_doc]
             fn #get_fn(&self) -> Option<#friendly_type> {
@@ -139,19 +200,25 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
                 }).collect()
             }
             #[doc = #set_fn_doc]
-            fn #set_fn(&mut self, #cif_field_w_unit: Option<#friendly_type>) {
+            fn #set_fn(&mut self, #cif_field_w_unit: Option<#friendly_type>) #set_ret {
                 if let Some(v) = #cif_field_w_unit {
+                    #check_v
                     self.#cif_fields_mut().#cif_field = Some(#fixed_type::from_num(v).to_bits() as #base_type);
                     self.#cif_mut().#set_cif_field_fn();
+                    #pad_cif0_attributes
                 } else {
                     self.#cif_fields_mut().#cif_field = None;
                     self.#cif_mut().#unset_cif_field_fn();
+                    #[cfg(feature = "cif7")]
+                    self.#cif_fields_mut().#cif_attr_field.clear();
                 }
+                #set_ok
             }
             #[doc = #set_attr_fn_doc]
             #[cfg(feature = "cif7")]
-            fn #set_attr_fn(&mut self, #cif_attr_field_w_unit: Option<Vec<#friendly_type>>) {
+            fn #set_attr_fn(&mut self, #cif_attr_field_w_unit: Option<Vec<#friendly_type>>) #set_ret {
                 if let Some(vec) = #cif_attr_field_w_unit {
+                    #check_vec
                     self.cif0_mut().#set_cif7_field_fn();
                     self.#cif_fields_mut().#cif_attr_field = vec.iter()
                         .map(|v| #fixed_type::from_num(*v).to_bits() as #base_type)
@@ -161,6 +228,7 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
                     #[cfg(feature = "cif7")]
                     self.#cif_fields_mut().#cif_attr_field.clear();
                 }
+                #set_ok
             }
         }
     } else {
@@ -191,8 +259,9 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
                 }
             }
             #[doc = #set_fn_doc]
-            fn #set_fn(&mut self, #cif_field_w_unit: Option<#friendly_type>) {
+            fn #set_fn(&mut self, #cif_field_w_unit: Option<#friendly_type>) #set_ret {
                 if let Some(v) = #cif_field_w_unit {
+                    #check_v
                     if self.#cif().is_none() {
                         self.cif0_mut().#enable_cif_fn();
                         *self.#cif_mut() = Some(#cif_type_name::default())
@@ -205,6 +274,7 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
                     self.#cif_fields_mut().as_mut().unwrap().#cif_field = Some(
                         #fixed_type::from_num(v).to_bits() as #base_type
                     );
+                    #pad_attributes
                 } else {
                     let mut clear_cif = false;
                     let mut clear_fields = false;
@@ -226,11 +296,13 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
                         *self.#cif_fields_mut() = None;
                     }
                 }
+                #set_ok
             }
             #[doc = #set_attr_fn_doc]
             #[cfg(feature = "cif7")]
-            fn #set_attr_fn(&mut self, #cif_attr_field_w_unit: Option<Vec<#friendly_type>>) {
+            fn #set_attr_fn(&mut self, #cif_attr_field_w_unit: Option<Vec<#friendly_type>>) #set_ret {
                 if let Some(vec) = #cif_attr_field_w_unit {
+                    #check_vec
                     self.cif0_mut().#set_cif7_field_fn();
                     if self.#cif().is_none() {
                         self.cif0_mut().#enable_cif_fn();
@@ -250,7 +322,13 @@ pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
                         f.#cif_attr_field.clear();
                     }
                 }
+                #set_ok
             }
         }
+    };
+
+    quote! {
+        #accessors
+        #cif7_accessors
     }
 }
@@ -5,6 +5,7 @@
 use proc_macro::TokenStream;
 
 mod ack_field;
+mod cif7_attr;
 mod cif_basic;
 mod cif_field;
 mod cif_fields;
@@ -69,6 +70,9 @@ pub fn cif_radix(input: TokenStream) -> TokenStream {
 /// some portion of the field is reserve/irrelevant. For example,
 /// the field may be a 32-bit int, but only the lower 16 bits are
 /// used to represent a fixed point number.
+///
+/// An optional trailing range expression (e.g. `-10.0..=10.0`)
+/// makes the generated setters range-checked and fallible.
 #[proc_macro]
 pub fn cif_radix_masked(input: TokenStream) -> TokenStream {
     cif_radix_masked::cif_radix_masked(input.into()).into()